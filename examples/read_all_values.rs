@@ -28,7 +28,7 @@ fn read_all_values<R: IonDataSource>(cursor: &mut BinaryIonCursor<R>) -> IonResu
     let mut count: usize = 0;
     loop {
         match cursor.next()? {
-            Some(VersionMarker) => {}
+            Some(VersionMarker(_major, _minor)) => {}
             Some(StreamItem::Value(ion_type, is_null)) => {
                 count += 1;
                 if is_null {
@@ -43,7 +43,7 @@ fn read_all_values<R: IonDataSource>(cursor: &mut BinaryIonCursor<R>) -> IonResu
                         let _symbol_id = cursor.read_symbol_id()?.unwrap();
                     }
                     Integer => {
-                        let _int = cursor.read_i64()?.unwrap();
+                        let _int = cursor.read_integer()?.unwrap();
                     }
                     Float => {
                         let _float = cursor.read_f64()?.unwrap();
@@ -52,7 +52,7 @@ fn read_all_values<R: IonDataSource>(cursor: &mut BinaryIonCursor<R>) -> IonResu
                         let _decimal = cursor.read_big_decimal()?.unwrap();
                     }
                     Timestamp => {
-                        let _timestamp = cursor.read_datetime()?.unwrap();
+                        let _timestamp = cursor.read_timestamp()?.unwrap();
                     }
                     Boolean => {
                         let _boolean = cursor.read_bool()?.unwrap();
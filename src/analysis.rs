@@ -0,0 +1,565 @@
+//! Reports which types, fields and symbols account for the size of a binary Ion stream.
+//!
+//! ```
+//! use ion_rs::analysis::analyze;
+//! use ion_rs::binary::writer::BinaryWriter;
+//! use ion_rs::IonType;
+//! # use ion_rs::result::IonResult;
+//! # fn main() -> IonResult<()> {
+//! let mut input = Vec::new();
+//! let mut writer = BinaryWriter::new(&mut input);
+//! writer.step_in(IonType::Struct)?;
+//! writer.set_field_name("color");
+//! writer.write_symbol("red")?;
+//! writer.step_out()?;
+//! writer.flush()?;
+//! drop(writer);
+//!
+//! let statistics = analyze(input.as_slice())?;
+//! assert_eq!(statistics.count_of(IonType::Symbol), 1);
+//! assert_eq!(statistics.field_path_bytes()["color"], 3);
+//! assert_eq!(statistics.symbol_counts()["red"], 1);
+//! println!("{}", statistics);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::result::IonResult;
+use crate::types::{IonType, SymbolToken};
+use crate::{BinaryIonCursor, IonDataSource, Reader, SymbolTable, SymbolTableEventHandler};
+
+/// The number of values reported by [StreamStatistics::largest_values].
+pub const LARGEST_VALUES_LIMIT: usize = 10;
+
+// The number of entries shown in each ranked section of the `Display` report.
+const REPORT_LIMIT: usize = 10;
+
+/// The size and location of a top-level value.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ValueSize {
+    offset: usize,
+    length: usize,
+    ion_type: IonType,
+}
+
+impl ValueSize {
+    /// Returns the position of the value's first byte (including its annotations, if any)
+    /// within the stream.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of bytes used to encode the value, including its annotations.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+}
+
+/// Statistics about the user values in a binary Ion stream and the bytes used to encode them.
+/// See [analyze].
+///
+/// Field paths are made up of the field names leading to a value, separated by `.`. Values
+/// inside of a list or s-expression are represented by `[]`, so the `price` field of each struct
+/// in an `orders` list has the path `orders[].price`. Symbols whose text is unknown are shown
+/// as `$<id>`.
+#[derive(Debug, Clone, Default)]
+pub struct StreamStatistics {
+    total_bytes: usize,
+    value_bytes: usize,
+    type_counts: HashMap<IonType, usize>,
+    depth_counts: Vec<usize>,
+    header_bytes: usize,
+    field_id_bytes: usize,
+    annotations_bytes: usize,
+    body_bytes: usize,
+    largest_values: Vec<ValueSize>,
+    symbol_counts: HashMap<String, usize>,
+    symbol_table_resets: usize,
+    symbol_table_appends: usize,
+    field_path_bytes: HashMap<String, usize>,
+}
+
+impl StreamStatistics {
+    /// Returns the length of the stream.
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Returns the number of bytes that were not part of a user value, such as Ion version
+    /// markers, local symbol tables, and NOP padding.
+    pub fn system_bytes(&self) -> usize {
+        self.total_bytes - self.value_bytes
+    }
+
+    /// Returns the number of user values in the stream, including nested values.
+    pub fn value_count(&self) -> usize {
+        self.depth_counts.iter().sum()
+    }
+
+    /// Returns the number of user values of the specified type, including nested values and
+    /// nulls of that type.
+    pub fn count_of(&self, ion_type: IonType) -> usize {
+        self.type_counts.get(&ion_type).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of user values found at each depth. The first entry is the number of
+    /// top-level values.
+    pub fn counts_by_depth(&self) -> &[usize] {
+        &self.depth_counts
+    }
+
+    /// Returns the number of bytes used to encode the type descriptors and lengths of values.
+    pub fn header_bytes(&self) -> usize {
+        self.header_bytes
+    }
+
+    /// Returns the number of bytes used to encode the symbol IDs of struct field names.
+    pub fn field_id_bytes(&self) -> usize {
+        self.field_id_bytes
+    }
+
+    /// Returns the number of bytes used to encode annotations wrappers.
+    pub fn annotations_bytes(&self) -> usize {
+        self.annotations_bytes
+    }
+
+    /// Returns the number of bytes used to encode the bodies of scalar values. The body of a
+    /// container is made up of its child values.
+    pub fn body_bytes(&self) -> usize {
+        self.body_bytes
+    }
+
+    /// Returns the largest top-level values in the stream, from largest to smallest. At most
+    /// [LARGEST_VALUES_LIMIT] values are included.
+    pub fn largest_values(&self) -> &[ValueSize] {
+        &self.largest_values
+    }
+
+    /// Returns the number of times each symbol was used as a symbol value, a field name, or an
+    /// annotation.
+    pub fn symbol_counts(&self) -> &HashMap<String, usize> {
+        &self.symbol_counts
+    }
+
+    /// Returns the number of local symbol tables that replaced the active symbol table.
+    pub fn symbol_table_resets(&self) -> usize {
+        self.symbol_table_resets
+    }
+
+    /// Returns the number of local symbol tables that appended to the active symbol table.
+    pub fn symbol_table_appends(&self) -> usize {
+        self.symbol_table_appends
+    }
+
+    /// Returns the total number of bytes used to encode the values at each field path, including
+    /// their field IDs, annotations, and any nested values.
+    pub fn field_path_bytes(&self) -> &HashMap<String, usize> {
+        &self.field_path_bytes
+    }
+
+    fn add_largest_value(&mut self, value: ValueSize) {
+        let values = &mut self.largest_values;
+        if values.len() == LARGEST_VALUES_LIMIT
+            && values.last().map(|v| v.length >= value.length) == Some(true)
+        {
+            return;
+        }
+        // Values of the same size are kept in the order they appear in the stream.
+        let index = values.partition_point(|v| v.length >= value.length);
+        values.insert(index, value);
+        values.truncate(LARGEST_VALUES_LIMIT);
+    }
+}
+
+/// Writes a human-readable report of the statistics.
+impl fmt::Display for StreamStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "bytes: {}", self.total_bytes)?;
+        writeln!(f, "  headers: {}", self.header_bytes)?;
+        writeln!(f, "  field IDs: {}", self.field_id_bytes)?;
+        writeln!(f, "  annotations: {}", self.annotations_bytes)?;
+        writeln!(f, "  bodies: {}", self.body_bytes)?;
+        writeln!(f, "  system values and padding: {}", self.system_bytes())?;
+        writeln!(f, "values: {}", self.value_count())?;
+        let mut type_counts: Vec<_> = self.type_counts.iter().collect();
+        type_counts.sort_by(|(t1, c1), (t2, c2)| c2.cmp(c1).then(t1.partial_cmp(t2).unwrap()));
+        for (ion_type, count) in type_counts {
+            writeln!(f, "  {}: {}", ion_type, count)?;
+        }
+        for (depth, count) in self.depth_counts.iter().enumerate() {
+            writeln!(f, "  at depth {}: {}", depth, count)?;
+        }
+        writeln!(
+            f,
+            "symbol tables: {} resets, {} appends",
+            self.symbol_table_resets, self.symbol_table_appends
+        )?;
+        writeln!(f, "largest top-level values:")?;
+        for value in &self.largest_values {
+            writeln!(
+                f,
+                "  {} bytes: {} at offset {}",
+                value.length, value.ion_type, value.offset
+            )?;
+        }
+        writeln!(f, "most used symbols:")?;
+        for (symbol, count) in ranked(&self.symbol_counts) {
+            writeln!(f, "  {}: {}", symbol, count)?;
+        }
+        writeln!(f, "bytes per field path:")?;
+        for (path, bytes) in ranked(&self.field_path_bytes) {
+            writeln!(f, "  {}: {}", path, bytes)?;
+        }
+        Ok(())
+    }
+}
+
+// Returns the REPORT_LIMIT entries with the highest counts, breaking ties alphabetically.
+fn ranked(counts: &HashMap<String, usize>) -> Vec<(&String, &usize)> {
+    let mut entries: Vec<_> = counts.iter().collect();
+    entries.sort_by(|(k1, v1), (k2, v2)| v2.cmp(v1).then(k1.cmp(k2)));
+    entries.truncate(REPORT_LIMIT);
+    entries
+}
+
+// Counts the symbol table events reported by the Reader. The counts are shared with the
+// analysis, since the Reader takes ownership of its handler.
+struct SymbolTableEventCounter {
+    resets: Rc<Cell<usize>>,
+    appends: Rc<Cell<usize>>,
+}
+
+impl SymbolTableEventHandler for SymbolTableEventCounter {
+    fn on_append<'a>(&'a mut self, _symbol_table: &'a SymbolTable, _starting_id: usize) {
+        self.appends.set(self.appends.get() + 1);
+    }
+
+    fn on_reset<'a>(&'a mut self, _symbol_table: &'a SymbolTable) {
+        self.resets.set(self.resets.get() + 1);
+    }
+}
+
+/// Reads every value in the provided binary Ion stream and gathers [StreamStatistics] about it.
+/// The stream is read one value at a time, so it does not need to fit in memory. Shared symbol
+/// tables imported by the stream are not resolved, so their symbols are counted as having unknown
+/// text.
+pub fn analyze<R: IonDataSource>(input: R) -> IonResult<StreamStatistics> {
+    let resets = Rc::new(Cell::new(0));
+    let appends = Rc::new(Cell::new(0));
+    let mut reader = Reader::new(BinaryIonCursor::new(input));
+    reader.set_symtab_event_handler(SymbolTableEventCounter {
+        resets: Rc::clone(&resets),
+        appends: Rc::clone(&appends),
+    });
+
+    let mut statistics = StreamStatistics::default();
+    analyze_all(&mut reader, &mut statistics)?;
+    statistics.total_bytes = reader.bytes_read();
+    statistics.symbol_table_resets = resets.get();
+    statistics.symbol_table_appends = appends.get();
+    Ok(statistics)
+}
+
+// Gathers statistics about every remaining value in the stream. Containers are traversed using
+// an explicit stack rather than recursion, so deeply nested input cannot overflow the call stack.
+fn analyze_all<R: IonDataSource>(
+    reader: &mut Reader<BinaryIonCursor<R>>,
+    statistics: &mut StreamStatistics,
+) -> IonResult<()> {
+    // The field path of the current value. Each container the Reader has stepped into pushes the
+    // length of its parent's path, which is restored when the Reader steps out.
+    let mut path = String::new();
+    let mut parent_path_lengths: Vec<usize> = Vec::new();
+    loop {
+        let (ion_type, is_null) = match reader.next()? {
+            Some(value) => value,
+            None => match parent_path_lengths.pop() {
+                Some(parent_path_length) => {
+                    reader.step_out()?;
+                    path.truncate(parent_path_length);
+                    continue;
+                }
+                None => return Ok(()),
+            },
+        };
+        let depth = reader.depth();
+        *statistics.type_counts.entry(ion_type).or_insert(0) += 1;
+        if statistics.depth_counts.len() <= depth {
+            statistics.depth_counts.resize(depth + 1, 0);
+        }
+        statistics.depth_counts[depth] += 1;
+
+        let cursor = reader.cursor();
+        let field_id_range = cursor.field_id_range();
+        let annotations_range = cursor.annotations_range();
+        let header_range = cursor.header_range();
+        let value_range = cursor.value_range();
+        statistics.header_bytes += header_range.len();
+        statistics.field_id_bytes += field_id_range.as_ref().map_or(0, |r| r.len());
+        statistics.annotations_bytes += annotations_range.as_ref().map_or(0, |r| r.len());
+
+        let value_start = annotations_range.map_or(header_range.start, |r| r.start);
+        let value_length = value_range.end - value_start;
+        if depth == 0 {
+            statistics.value_bytes += value_length;
+            statistics.add_largest_value(ValueSize {
+                offset: value_start,
+                length: value_length,
+                ion_type,
+            });
+        }
+
+        for annotation in reader.annotation_tokens()? {
+            count_symbol(statistics, &annotation);
+        }
+        let parent_path_length = path.len();
+        match reader.field_name_token()? {
+            Some(field_name) => {
+                count_symbol(statistics, &field_name);
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(&symbol_text(&field_name));
+                let field_length = field_id_range.map_or(0, |r| r.len()) + value_length;
+                match statistics.field_path_bytes.get_mut(&path) {
+                    Some(bytes) => *bytes += field_length,
+                    None => {
+                        statistics
+                            .field_path_bytes
+                            .insert(path.clone(), field_length);
+                    }
+                }
+            }
+            None if depth > 0 => path.push_str("[]"),
+            None => {}
+        }
+
+        let is_container = matches!(
+            ion_type,
+            IonType::List | IonType::SExpression | IonType::Struct
+        );
+        if is_container && !is_null {
+            reader.step_in()?;
+            parent_path_lengths.push(parent_path_length);
+            continue;
+        }
+        path.truncate(parent_path_length);
+        if is_null {
+            continue;
+        }
+        match ion_type {
+            IonType::Symbol => {
+                statistics.body_bytes += value_range.len();
+                if let Some(symbol) = reader.read_symbol()? {
+                    count_symbol(statistics, &symbol);
+                }
+            }
+            _ => statistics.body_bytes += value_range.len(),
+        }
+    }
+}
+
+fn count_symbol(statistics: &mut StreamStatistics, symbol: &SymbolToken) {
+    *statistics
+        .symbol_counts
+        .entry(symbol_text(symbol))
+        .or_insert(0) += 1;
+}
+
+fn symbol_text(symbol: &SymbolToken) -> String {
+    match (symbol.text(), symbol.local_sid()) {
+        (Some(text), _) => text.to_string(),
+        (None, Some(sid)) => format!("${}", sid),
+        (None, None) => "$?".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::writer::BinaryWriter;
+    use crate::result::IonResult;
+    use crate::types::IonType;
+
+    use super::*;
+
+    // Writes {name: "widget", sizes::orders: [{price: 5}, {price: 300}]} followed by the
+    // symbol `widget` and the integer 7.
+    fn write_stream() -> IonResult<Vec<u8>> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("name");
+        writer.write_string("widget")?;
+        writer.set_field_name("orders");
+        writer.set_annotations(&["sizes"]);
+        writer.step_in(IonType::List)?;
+        for price in &[5, 300] {
+            writer.step_in(IonType::Struct)?;
+            writer.set_field_name("price");
+            writer.write_i64(*price)?;
+            writer.step_out()?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.write_symbol("widget")?;
+        writer.write_i64(7)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(output)
+    }
+
+    #[test]
+    fn counts_values_by_type_and_depth() -> IonResult<()> {
+        let statistics = analyze(write_stream()?.as_slice())?;
+        assert_eq!(statistics.value_count(), 9);
+        assert_eq!(statistics.count_of(IonType::Struct), 3);
+        assert_eq!(statistics.count_of(IonType::Integer), 3);
+        assert_eq!(statistics.count_of(IonType::String), 1);
+        assert_eq!(statistics.count_of(IonType::Symbol), 1);
+        assert_eq!(statistics.count_of(IonType::List), 1);
+        assert_eq!(statistics.count_of(IonType::Blob), 0);
+        assert_eq!(statistics.counts_by_depth(), &[3, 2, 2, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn attributes_every_byte() -> IonResult<()> {
+        let input = write_stream()?;
+        let statistics = analyze(input.as_slice())?;
+        assert_eq!(statistics.total_bytes(), input.len());
+        assert_eq!(statistics.field_id_bytes(), 4);
+        assert_eq!(statistics.annotations_bytes(), 3);
+        // The outer struct's 22 byte body requires a two-byte header.
+        assert_eq!(statistics.header_bytes(), 10);
+        // "widget", 5, 300, `widget`, and 7
+        assert_eq!(statistics.body_bytes(), 6 + 1 + 2 + 1 + 1);
+        assert_eq!(
+            statistics.system_bytes()
+                + statistics.header_bytes()
+                + statistics.field_id_bytes()
+                + statistics.annotations_bytes()
+                + statistics.body_bytes(),
+            input.len()
+        );
+        assert_eq!(statistics.symbol_table_resets(), 1);
+        assert_eq!(statistics.symbol_table_appends(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn ranks_largest_values_and_attributes_field_paths() -> IonResult<()> {
+        let statistics = analyze(write_stream()?.as_slice())?;
+        let largest: Vec<_> = statistics
+            .largest_values()
+            .iter()
+            .map(|v| (v.ion_type(), v.length()))
+            .collect();
+        assert_eq!(
+            largest,
+            vec![
+                (IonType::Struct, 24),
+                (IonType::Symbol, 2),
+                (IonType::Integer, 2)
+            ]
+        );
+        // The symbol follows the struct directly in the stream.
+        let struct_value = statistics.largest_values()[0];
+        assert_eq!(
+            struct_value.offset() + struct_value.length(),
+            statistics.largest_values()[1].offset()
+        );
+
+        let paths = statistics.field_path_bytes();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths["name"], 8);
+        assert_eq!(paths["orders[].price"], 3 + 4);
+        assert_eq!(paths["orders"], 1 + 3 + 1 + paths["orders[].price"] + 2);
+
+        let symbols = statistics.symbol_counts();
+        assert_eq!(symbols["widget"], 1);
+        assert_eq!(symbols["price"], 2);
+        assert_eq!(symbols["sizes"], 1);
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_only_the_largest_values() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        for length in 0..(LARGEST_VALUES_LIMIT + 5) {
+            writer.write_string("x".repeat(length))?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let statistics = analyze(output.as_slice())?;
+        let lengths: Vec<_> = statistics
+            .largest_values()
+            .iter()
+            .map(|v| v.length())
+            .collect();
+        // Strings of 14 bytes or more use a two-byte header.
+        let expected: Vec<_> = (5..(LARGEST_VALUES_LIMIT + 5))
+            .rev()
+            .map(|l| if l < 14 { l + 1 } else { l + 2 })
+            .collect();
+        assert_eq!(lengths, expected);
+        Ok(())
+    }
+
+    // Encodes a VarUInt with no leading zero bytes.
+    fn var_uint(mut value: usize) -> Vec<u8> {
+        let mut bytes = vec![0x80 | (value & 0x7F) as u8];
+        value >>= 7;
+        while value > 0 {
+            bytes.push((value & 0x7F) as u8);
+            value >>= 7;
+        }
+        bytes.reverse();
+        bytes
+    }
+
+    #[test]
+    fn analyzes_deeply_nested_containers() -> IonResult<()> {
+        // Containers are traversed iteratively, so the nesting depth is not limited by the size
+        // of the stack.
+        let depth = 200_000;
+        // The body length of each list, from the innermost to the outermost.
+        let mut body_lengths = vec![0usize];
+        for _ in 1..depth {
+            let inner = *body_lengths.last().unwrap();
+            let header_length = if inner < 14 {
+                1
+            } else {
+                1 + var_uint(inner).len()
+            };
+            body_lengths.push(header_length + inner);
+        }
+        let mut input = vec![0xE0, 0x01, 0x00, 0xEA];
+        for &length in body_lengths.iter().rev() {
+            if length < 14 {
+                input.push(0xB0 | length as u8);
+            } else {
+                input.push(0xBE);
+                input.extend(var_uint(length));
+            }
+        }
+
+        let statistics = analyze(input.as_slice())?;
+        assert_eq!(statistics.total_bytes(), input.len());
+        assert_eq!(statistics.value_count(), depth);
+        assert_eq!(statistics.count_of(IonType::List), depth);
+        assert_eq!(statistics.counts_by_depth(), vec![1; depth].as_slice());
+        assert_eq!(statistics.header_bytes(), input.len() - 4);
+        Ok(())
+    }
+}
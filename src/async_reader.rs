@@ -0,0 +1,168 @@
+use bigdecimal::BigDecimal;
+use futures::io::AsyncBufRead;
+use num_bigint::BigInt;
+
+use crate::binary::async_cursor::read_chunk;
+use crate::result::{IonError, IonResult};
+use crate::types::{Decimal, Integer, SymbolId, SymbolToken, Timestamp};
+use crate::{
+    Catalog, IncrementalBinaryCursor, IonType, Reader, SymbolTable, SymbolTableEventHandler,
+};
+
+// Generates async methods that forward to the synchronous method of the same name on the
+// wrapped Reader. Each top-level value is fully buffered before `next()` returns it, so these
+// methods never need to wait for more data.
+macro_rules! forward_to_reader {
+    ($($name:ident -> $return_type:ty;)*) => {
+        $(
+            pub async fn $name(&mut self) -> IonResult<$return_type> {
+                self.reader.$name()
+            }
+        )*
+    };
+}
+
+/// An asynchronous counterpart to [Reader] that reads binary Ion from an [AsyncBufRead] data
+/// source, resolving symbol IDs into text using the stream's symbol tables.
+///
+/// Like [AsyncBinaryIonCursor](crate::binary::async_cursor::AsyncBinaryIonCursor), the
+/// AsyncReader buffers each top-level value in its entirety before `next()` returns it.
+pub struct AsyncReader<R: AsyncBufRead + Unpin> {
+    reader: Reader<IncrementalBinaryCursor>,
+    data_source: R,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncReader<R> {
+    pub fn new(data_source: R) -> Self {
+        AsyncReader {
+            reader: Reader::new(IncrementalBinaryCursor::new()),
+            data_source,
+        }
+    }
+
+    /// Sets the Catalog that will be used to resolve shared symbol table imports.
+    pub fn set_catalog<K>(&mut self, catalog: K)
+    where
+        K: 'static + Catalog,
+    {
+        self.reader.set_catalog(catalog);
+    }
+
+    /// See [Reader::set_symtab_event_handler].
+    pub fn set_symtab_event_handler<H>(&mut self, handler: H)
+    where
+        H: 'static + SymbolTableEventHandler,
+    {
+        self.reader.set_symtab_event_handler(handler);
+    }
+
+    /// Advances to the next user-level Ion value, reading more data from the data source as
+    /// needed and processing any system-level directives encountered along the way.
+    pub async fn next(&mut self) -> IonResult<Option<(IonType, bool)>> {
+        loop {
+            match self.reader.next() {
+                Err(IonError::Incomplete { .. }) => {
+                    let reader = &mut self.reader;
+                    if !read_chunk(&mut self.data_source, |bytes| reader.push(bytes)).await? {
+                        reader.end_of_stream();
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub fn ion_version(&self) -> (u8, u8) {
+        self.reader.ion_version()
+    }
+
+    pub fn ion_type(&self) -> Option<IonType> {
+        self.reader.ion_type()
+    }
+
+    pub fn field_id(&self) -> Option<SymbolId> {
+        self.reader.field_id()
+    }
+
+    pub fn field_name(&self) -> Option<&str> {
+        self.reader.field_name()
+    }
+
+    pub fn field_name_token(&self) -> IonResult<Option<SymbolToken>> {
+        self.reader.field_name_token()
+    }
+
+    pub fn annotations(&self) -> impl Iterator<Item = &str> {
+        self.reader.annotations()
+    }
+
+    pub fn annotation_tokens(&self) -> IonResult<Vec<SymbolToken>> {
+        self.reader.annotation_tokens()
+    }
+
+    pub fn symbol_table(&self) -> &SymbolTable {
+        self.reader.symbol_table()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.reader.depth()
+    }
+
+    forward_to_reader! {
+        read_null -> Option<IonType>;
+        read_bool -> Option<bool>;
+        read_i64 -> Option<i64>;
+        read_integer -> Option<Integer>;
+        read_big_int -> Option<BigInt>;
+        read_f32 -> Option<f32>;
+        read_f64 -> Option<f64>;
+        read_decimal -> Option<Decimal>;
+        read_big_decimal -> Option<BigDecimal>;
+        read_string -> Option<String>;
+        read_symbol -> Option<SymbolToken>;
+        read_symbol_id -> Option<SymbolId>;
+        read_blob_bytes -> Option<Vec<u8>>;
+        read_clob_bytes -> Option<Vec<u8>>;
+        read_timestamp -> Option<Timestamp>;
+        step_in -> ();
+        step_out -> ();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::io::{BufReader, Cursor};
+
+    use crate::binary::constants::v1_0::IVM;
+    use crate::result::IonResult;
+    use crate::types::{IonType, SymbolToken};
+    use crate::AsyncReader;
+
+    #[test]
+    fn test_resolve_symbols_across_chunks() -> IonResult<()> {
+        let mut data = IVM.to_vec();
+        // $ion_symbol_table::{symbols: ["foo"]}
+        data.extend_from_slice(&[0xE9, 0x81, 0x83, 0xD6, 0x87, 0xB4, 0x83, b'f', b'o', b'o']);
+        // foo::{foo: foo}
+        data.extend_from_slice(&[0xE6, 0x81, 0x8A, 0xD3, 0x8A, 0x71, 0x0A]);
+        let data_source = BufReader::with_capacity(5, Cursor::new(data));
+        let mut reader = AsyncReader::new(data_source);
+
+        block_on(async {
+            assert_eq!(reader.next().await?, Some((IonType::Struct, false)));
+            assert_eq!(reader.annotations().collect::<Vec<_>>(), vec!["foo"]);
+            reader.step_in().await?;
+            assert_eq!(reader.next().await?, Some((IonType::Symbol, false)));
+            assert_eq!(reader.field_name(), Some("foo"));
+            assert_eq!(
+                reader.read_symbol().await?,
+                Some(SymbolToken::with_text("foo"))
+            );
+            assert_eq!(reader.next().await?, None);
+            reader.step_out().await?;
+            assert_eq!(reader.next().await?, None);
+            Ok(())
+        })
+    }
+}
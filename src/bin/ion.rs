@@ -0,0 +1,389 @@
+//! A command-line tool for inspecting and converting Ion streams. Run `ion --help` for usage.
+
+use std::fs;
+use std::io::{self, BufReader, Read, Write};
+use std::process::exit;
+
+use ion_rs::analysis::analyze;
+use ion_rs::binary::inspect::render;
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::result::{IonError, IonResult};
+use ion_rs::text::writer::{TextLayout, TextWriterBuilder};
+use ion_rs::{transcode, BinaryIonCursor, Cursor, IonType, Reader, TextIonCursor, Writer};
+
+const USAGE: &str = "\
+USAGE:
+    ion dump [--pretty | --lines | --compact] [FILE...]
+    ion convert --to <binary | text | pretty | json> [FILE...]
+    ion count [FILE...]
+    ion inspect [FILE...]
+    ion stats [FILE...]
+
+COMMANDS:
+    dump       Writes each value as Ion text. The default layout is --pretty.
+    convert    Writes each value in the requested format.
+    count      Reports the number of top-level values of each type.
+    inspect    Shows what each byte of a binary Ion stream encodes.
+    stats      Reports which types, fields and symbols account for the size of a binary Ion
+               stream.
+
+Each FILE may contain binary or text Ion. If no FILE is given, or if FILE is `-`, standard input
+is read instead.";
+
+// Binary Ion streams begin with an Ion Version Marker; anything else is read as text.
+const IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
+// The order in which `count` reports each type.
+const ION_TYPES: [IonType; 13] = [
+    IonType::Null,
+    IonType::Boolean,
+    IonType::Integer,
+    IonType::Float,
+    IonType::Decimal,
+    IonType::Timestamp,
+    IonType::Symbol,
+    IonType::String,
+    IonType::Clob,
+    IonType::Blob,
+    IonType::List,
+    IonType::SExpression,
+    IonType::Struct,
+];
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Convert(OutputFormat),
+    Count,
+    Inspect,
+    Stats,
+}
+
+#[derive(Debug, PartialEq)]
+enum OutputFormat {
+    Binary,
+    Text(TextLayout),
+    Json,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    let (command, mut paths) = parse_args(&args).unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+        exit(2);
+    });
+    if paths.is_empty() {
+        paths.push("-".to_string());
+    }
+    let stdout = io::stdout();
+    if let Err(message) = run(&command, &paths, stdout.lock()) {
+        eprintln!("ion: {}", message);
+        exit(1);
+    }
+}
+
+// Returns the command described by `args` along with the paths of the files it should read.
+fn parse_args(args: &[String]) -> Result<(Command, Vec<String>), String> {
+    let (name, args) = match args.split_first() {
+        Some((name, args)) => (name.as_str(), args),
+        None => return Err("No command was specified.".to_string()),
+    };
+    let mut command = match name {
+        "dump" => Command::Convert(OutputFormat::Text(TextLayout::Pretty)),
+        "convert" => Command::Convert(OutputFormat::Text(TextLayout::Lines)),
+        "count" => Command::Count,
+        "inspect" => Command::Inspect,
+        "stats" => Command::Stats,
+        _ => return Err(format!("Unknown command '{}'.", name)),
+    };
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        command = match (command, arg.as_str()) {
+            (Command::Convert(OutputFormat::Text(_)), "--pretty") if name == "dump" => {
+                Command::Convert(OutputFormat::Text(TextLayout::Pretty))
+            }
+            (Command::Convert(OutputFormat::Text(_)), "--lines") if name == "dump" => {
+                Command::Convert(OutputFormat::Text(TextLayout::Lines))
+            }
+            (Command::Convert(OutputFormat::Text(_)), "--compact") if name == "dump" => {
+                Command::Convert(OutputFormat::Text(TextLayout::Compact))
+            }
+            (Command::Convert(_), "--to") if name == "convert" => {
+                let format = match args.next().map(String::as_str) {
+                    Some("binary") => OutputFormat::Binary,
+                    Some("text") => OutputFormat::Text(TextLayout::Lines),
+                    Some("pretty") => OutputFormat::Text(TextLayout::Pretty),
+                    Some("json") => OutputFormat::Json,
+                    Some(format) => return Err(format!("Unknown output format '{}'.", format)),
+                    None => return Err("--to requires an output format.".to_string()),
+                };
+                Command::Convert(format)
+            }
+            (_, option) if option.starts_with("--") => {
+                return Err(format!("Unknown option '{}' for '{}'.", option, name));
+            }
+            (command, path) => {
+                paths.push(path.to_string());
+                command
+            }
+        };
+    }
+    Ok((command, paths))
+}
+
+// Runs `command` over each of the files in `paths`, writing the results to `output`.
+fn run<O: Write>(command: &Command, paths: &[String], output: O) -> Result<(), String> {
+    match command {
+        Command::Convert(OutputFormat::Binary) => {
+            read_all(paths, &mut Transcoder(BinaryWriter::new(output)))
+        }
+        Command::Convert(OutputFormat::Text(layout)) => {
+            let writer = TextWriterBuilder::new().layout(*layout).build(output);
+            read_all(paths, &mut Transcoder(writer))
+        }
+        Command::Convert(OutputFormat::Json) => {
+            let writer = TextWriterBuilder::new().json(true).build(output);
+            read_all(paths, &mut Transcoder(writer))
+        }
+        Command::Count => {
+            let mut counter = Counter([0; ION_TYPES.len()]);
+            read_all(paths, &mut counter)?;
+            counter.write_to(output).map_err(|error| error.to_string())
+        }
+        Command::Inspect => inspect_all(paths, output),
+        Command::Stats => stats_all(paths, output),
+    }
+}
+
+// An operation that can be performed using a Reader over either binary or text Ion.
+trait Visitor {
+    fn visit<C: Cursor>(&mut self, reader: &mut Reader<C>) -> IonResult<()>;
+}
+
+// Writes every value it visits to the wrapped Writer.
+struct Transcoder<W: Writer>(W);
+
+impl<W: Writer> Visitor for Transcoder<W> {
+    fn visit<C: Cursor>(&mut self, reader: &mut Reader<C>) -> IonResult<()> {
+        transcode(reader, &mut self.0)
+    }
+}
+
+// Counts the top-level values of each type, indexed in the same order as ION_TYPES.
+struct Counter([usize; ION_TYPES.len()]);
+
+impl Visitor for Counter {
+    fn visit<C: Cursor>(&mut self, reader: &mut Reader<C>) -> IonResult<()> {
+        while let Some((ion_type, _is_null)) = reader.next()? {
+            let index = ION_TYPES.iter().position(|t| *t == ion_type).unwrap();
+            self.0[index] += 1;
+        }
+        Ok(())
+    }
+}
+
+impl Counter {
+    fn write_to<O: Write>(&self, mut output: O) -> io::Result<()> {
+        for (ion_type, count) in ION_TYPES.iter().zip(self.0.iter()) {
+            if *count > 0 {
+                writeln!(output, "{:<10} {}", ion_type, count)?;
+            }
+        }
+        writeln!(output, "{:<10} {}", "total", self.0.iter().sum::<usize>())
+    }
+}
+
+// Returns a name for the file at `path` along with a source of its contents. A path of `-`
+// refers to standard input.
+fn open_input(path: &str) -> Result<(&str, Box<dyn Read>), String> {
+    if path == "-" {
+        return Ok(("<stdin>", Box::new(io::stdin())));
+    }
+    let file = fs::File::open(path).map_err(|error| format!("{}: {}", path, error))?;
+    Ok((path, Box::new(file)))
+}
+
+// An input whose first bytes have been read to find out whether it is binary Ion.
+type SniffedInput<R> = BufReader<io::Chain<io::Cursor<Vec<u8>>, R>>;
+
+// Reads enough of `input` to find out whether it begins with a binary Ion version marker. Returns
+// the answer along with a buffered reader over the complete input.
+fn sniff<R: Read>(name: &str, mut input: R) -> Result<(bool, SniffedInput<R>), String> {
+    let mut prefix = Vec::with_capacity(IVM.len());
+    input
+        .by_ref()
+        .take(IVM.len() as u64)
+        .read_to_end(&mut prefix)
+        .map_err(|error| format!("{}: {}", name, error))?;
+    let is_binary = prefix == IVM;
+    Ok((
+        is_binary,
+        BufReader::new(io::Cursor::new(prefix).chain(input)),
+    ))
+}
+
+// Returns a name for the file at `path` along with its contents. A path of `-` refers to
+// standard input.
+fn read_input(path: &str) -> Result<(&str, Vec<u8>), String> {
+    let (name, mut input) = open_input(path)?;
+    let mut bytes = Vec::new();
+    input
+        .read_to_end(&mut bytes)
+        .map_err(|error| format!("{}: {}", name, error))?;
+    Ok((name, bytes))
+}
+
+// Passes a Reader over each of the files in `paths` to `visitor` in turn. Files are streamed
+// rather than read into memory.
+fn read_all<V: Visitor>(paths: &[String], visitor: &mut V) -> Result<(), String> {
+    for path in paths {
+        let (name, input) = open_input(path)?;
+        visit(name, input, visitor)?;
+    }
+    Ok(())
+}
+
+// Writes a hex view of each of the binary Ion files in `paths` to `output`.
+fn inspect_all<O: Write>(paths: &[String], mut output: O) -> Result<(), String> {
+    for path in paths {
+        let (name, bytes) = read_input(path)?;
+        if !bytes.starts_with(&IVM) {
+            return Err(format!("{}: only binary Ion can be inspected", name));
+        }
+        render(&bytes, &mut output).map_err(|error| format!("{}: {}", name, error))?;
+    }
+    Ok(())
+}
+
+// Writes a report of the statistics for each of the binary Ion files in `paths` to `output`.
+// Files are streamed rather than read into memory.
+fn stats_all<O: Write>(paths: &[String], mut output: O) -> Result<(), String> {
+    for path in paths {
+        let (name, input) = open_input(path)?;
+        let (is_binary, input) = sniff(name, input)?;
+        if !is_binary {
+            return Err(format!("{}: only binary Ion can be analyzed", name));
+        }
+        let statistics = analyze(input).map_err(|error| format!("{}: {}", name, error))?;
+        if paths.len() > 1 {
+            writeln!(output, "{}:", name).map_err(|error| error.to_string())?;
+        }
+        write!(output, "{}", statistics).map_err(|error| error.to_string())?;
+    }
+    Ok(())
+}
+
+// Passes a Reader over `input` to `visitor`. If the visitor fails, returns a description of the
+// error that includes the number of bytes that had been read when it occurred.
+fn visit<R: Read, V: Visitor>(name: &str, input: R, visitor: &mut V) -> Result<(), String> {
+    let describe = |error: IonError, offset: usize| {
+        format!("{}: error at byte offset {}: {}", name, offset, error)
+    };
+    let (is_binary, input) = sniff(name, input)?;
+    if is_binary {
+        let mut reader = Reader::new(BinaryIonCursor::new(input));
+        visitor
+            .visit(&mut reader)
+            .map_err(|error| describe(error, reader.bytes_read()))
+    } else {
+        let mut reader = Reader::new(TextIonCursor::new(input));
+        visitor
+            .visit(&mut reader)
+            .map_err(|error| describe(error, reader.bytes_read()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    // Converts `text` to binary Ion using the `convert` command's Transcoder.
+    fn to_binary(text: &str) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut transcoder = Transcoder(BinaryWriter::new(&mut output));
+        visit("test", text.as_bytes(), &mut transcoder).unwrap();
+        drop(transcoder);
+        output
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(
+            parse_args(&args("dump a.10n b.10n")),
+            Ok((
+                Command::Convert(OutputFormat::Text(TextLayout::Pretty)),
+                vec!["a.10n".to_string(), "b.10n".to_string()]
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("dump --compact")),
+            Ok((
+                Command::Convert(OutputFormat::Text(TextLayout::Compact)),
+                vec![]
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("convert --to json -")),
+            Ok((Command::Convert(OutputFormat::Json), vec!["-".to_string()]))
+        );
+        assert_eq!(parse_args(&args("count")), Ok((Command::Count, vec![])));
+        assert_eq!(
+            parse_args(&args("inspect a.10n")),
+            Ok((Command::Inspect, vec!["a.10n".to_string()]))
+        );
+        assert_eq!(parse_args(&args("stats")), Ok((Command::Stats, vec![])));
+    }
+
+    #[test]
+    fn parse_invalid_commands() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("frob")).is_err());
+        assert!(parse_args(&args("convert --to yaml")).is_err());
+        assert!(parse_args(&args("convert --to")).is_err());
+        assert!(parse_args(&args("convert --compact")).is_err());
+        assert!(parse_args(&args("count --to json")).is_err());
+    }
+
+    #[test]
+    fn dump_binary_as_text() {
+        let binary = to_binary("foo::{bar: [1, null.int]} 2.50");
+        let mut output = Vec::new();
+        let writer = TextWriterBuilder::new()
+            .layout(TextLayout::Compact)
+            .build(&mut output);
+        visit("test", binary.as_slice(), &mut Transcoder(writer)).unwrap();
+        assert_eq!(output, b"foo::{bar:[1,null.int,],} 2.50 ");
+    }
+
+    #[test]
+    fn count_top_level_values() {
+        let mut counter = Counter([0; ION_TYPES.len()]);
+        visit("text", &b"1 2 null.int [3, 4] foo"[..], &mut counter).unwrap();
+        visit("binary", to_binary("{a: 1}").as_slice(), &mut counter).unwrap();
+        let mut output = Vec::new();
+        counter.write_to(&mut output).unwrap();
+        let expected = "int        3\nsymbol     1\nlist       1\nstruct     1\ntotal      6\n";
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn errors_include_the_byte_offset() {
+        let mut binary = to_binary("\"hello\"");
+        binary.truncate(binary.len() - 2);
+        let mut counter = Counter([0; ION_TYPES.len()]);
+        let error = visit("truncated.10n", binary.as_slice(), &mut counter).unwrap_err();
+        assert!(
+            error.starts_with("truncated.10n: error at byte offset"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use bigdecimal::BigDecimal;
+use futures::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::binary::incremental::IncrementalBinaryCursor;
+use crate::cursor::{Cursor, StreamItem};
+use crate::result::{IonError, IonResult};
+use crate::types::{Decimal, Integer, IonType, SymbolId, Timestamp};
+
+/// Reads the next chunk of available data from `source` and passes it to `push`. Returns false
+/// if the source has no more data.
+pub(crate) async fn read_chunk<R, F>(source: &mut R, push: F) -> IonResult<bool>
+where
+    R: AsyncBufRead + Unpin,
+    F: FnOnce(&[u8]),
+{
+    let buffer = source.fill_buf().await?;
+    let number_of_bytes = buffer.len();
+    if number_of_bytes == 0 {
+        return Ok(false);
+    }
+    push(buffer);
+    source.consume_unpin(number_of_bytes);
+    Ok(true)
+}
+
+// Generates async methods that forward to the synchronous method of the same name on the
+// wrapped cursor. Each top-level value is fully buffered before `next()` returns it, so these
+// methods never need to wait for more data.
+macro_rules! forward_to_cursor {
+    ($($name:ident -> $return_type:ty;)*) => {
+        $(
+            pub async fn $name(&mut self) -> IonResult<$return_type> {
+                self.cursor.$name()
+            }
+        )*
+    };
+}
+
+/// An asynchronous counterpart to [BinaryIonCursor](crate::BinaryIonCursor) that reads binary
+/// Ion from an [AsyncBufRead] data source.
+///
+/// The cursor reads data from its source as needed and hands it to an
+/// [IncrementalBinaryCursor], so each top-level value is buffered in its entirety before
+/// `next()` returns it. Reading a value or stepping into a container therefore never waits for
+/// more data.
+pub struct AsyncBinaryIonCursor<R: AsyncBufRead + Unpin> {
+    cursor: IncrementalBinaryCursor,
+    data_source: R,
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncBinaryIonCursor<R> {
+    pub fn new(data_source: R) -> Self {
+        AsyncBinaryIonCursor {
+            cursor: IncrementalBinaryCursor::new(),
+            data_source,
+        }
+    }
+
+    pub async fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        loop {
+            match self.cursor.next() {
+                Err(IonError::Incomplete { .. }) => {
+                    let cursor = &mut self.cursor;
+                    if !read_chunk(&mut self.data_source, |bytes| cursor.push(bytes)).await? {
+                        cursor.end_of_stream();
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub fn ion_version(&self) -> (u8, u8) {
+        self.cursor.ion_version()
+    }
+
+    pub fn ion_type(&self) -> Option<IonType> {
+        self.cursor.ion_type()
+    }
+
+    pub fn annotation_ids(&self) -> &[SymbolId] {
+        self.cursor.annotation_ids()
+    }
+
+    pub fn field_id(&self) -> Option<SymbolId> {
+        self.cursor.field_id()
+    }
+
+    pub fn depth(&self) -> usize {
+        self.cursor.depth()
+    }
+
+    forward_to_cursor! {
+        read_null -> Option<IonType>;
+        read_bool -> Option<bool>;
+        read_i64 -> Option<i64>;
+        read_integer -> Option<Integer>;
+        read_f32 -> Option<f32>;
+        read_f64 -> Option<f64>;
+        read_decimal -> Option<Decimal>;
+        read_big_decimal -> Option<BigDecimal>;
+        read_string -> Option<String>;
+        read_symbol_id -> Option<SymbolId>;
+        read_blob_bytes -> Option<Vec<u8>>;
+        read_clob_bytes -> Option<Vec<u8>>;
+        read_timestamp -> Option<Timestamp>;
+        step_in -> ();
+        step_out -> ();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::io::{BufReader, Cursor};
+
+    use crate::binary::async_cursor::AsyncBinaryIonCursor;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::cursor::StreamItem::*;
+    use crate::result::IonResult;
+    use crate::types::IonType;
+
+    #[test]
+    fn test_read_in_small_chunks() -> IonResult<()> {
+        let mut data = IVM.to_vec();
+        // "hello" [1, {$10: true}] 7
+        data.extend_from_slice(&[0x85, b'h', b'e', b'l', b'l', b'o']);
+        data.extend_from_slice(&[0xB5, 0x21, 0x01, 0xD2, 0x8A, 0x11]);
+        data.extend_from_slice(&[0x21, 0x07]);
+        // Only expose 3 bytes of the input at a time
+        let data_source = BufReader::with_capacity(3, Cursor::new(data));
+        let mut cursor = AsyncBinaryIonCursor::new(data_source);
+
+        block_on(async {
+            assert_eq!(cursor.next().await?, Some(VersionMarker(1, 0)));
+            assert_eq!(cursor.next().await?, Some(Value(IonType::String, false)));
+            assert_eq!(cursor.read_string().await?, Some("hello".to_string()));
+            assert_eq!(cursor.next().await?, Some(Value(IonType::List, false)));
+            cursor.step_in().await?;
+            assert_eq!(cursor.next().await?, Some(Value(IonType::Integer, false)));
+            assert_eq!(cursor.read_i64().await?, Some(1));
+            assert_eq!(cursor.next().await?, Some(Value(IonType::Struct, false)));
+            cursor.step_in().await?;
+            assert_eq!(cursor.next().await?, Some(Value(IonType::Boolean, false)));
+            assert_eq!(cursor.field_id(), Some(10));
+            assert_eq!(cursor.read_bool().await?, Some(true));
+            cursor.step_out().await?;
+            cursor.step_out().await?;
+            assert_eq!(cursor.next().await?, Some(Value(IonType::Integer, false)));
+            assert_eq!(cursor.read_i64().await?, Some(7));
+            assert_eq!(cursor.next().await?, None);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(&[0x85, b'h', b'e']);
+        let mut cursor = AsyncBinaryIonCursor::new(Cursor::new(data));
+        block_on(async {
+            assert_eq!(cursor.next().await, Ok(Some(VersionMarker(1, 0))));
+            assert!(cursor.next().await.is_err());
+        });
+    }
+}
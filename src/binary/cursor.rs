@@ -1,8 +1,8 @@
-use bigdecimal::BigDecimal;
 use bytes::BigEndian;
 use bytes::ByteOrder;
-use chrono::offset::FixedOffset;
 use chrono::prelude::*;
+use chrono::Duration;
+use num_bigint::{BigInt, BigUint, Sign};
 
 use crate::binary::constants::v1_0::IVM;
 use crate::cursor::{Cursor, StreamItem};
@@ -10,20 +10,24 @@ use crate::{
     binary::{
         constants::v1_0::length_codes,
         header::{create_header_byte_jump_table, Header},
-        int::Int,
-        uint::UInt,
+        int::{Int, MAX_INT_SIZE_IN_BYTES},
+        uint::{UInt, MAX_UINT_SIZE_IN_BYTES},
         var_int::VarInt,
         var_uint::VarUInt,
         IonTypeCode,
     },
     data_source::IonDataSource,
-    result::{decoding_error, illegal_operation, illegal_operation_raw, IonResult},
-    types::{IonType, SymbolId},
+    result::{decoding_error, illegal_operation, illegal_operation_raw, IonError, IonResult},
+    types::{Decimal, Integer, IonType, SymbolId, Timestamp},
 };
 use std::io;
 
+use bigdecimal::BigDecimal;
 use std::ops::Range;
 
+// The most digits after the decimal point that a binary timestamp's fractional seconds can have.
+const MAX_FRACTIONAL_SECONDS_DIGITS: u32 = 4096;
+
 /// Information about the value over which the Cursor is currently positioned.
 #[derive(Clone, Debug)]
 struct EncodedValue {
@@ -242,6 +246,29 @@ pub struct CursorState {
     annotations: Vec<SymbolId>
 }
 
+/// A snapshot of a BinaryIonCursor's position in its stream. A Checkpoint can be passed to
+/// [BinaryIonCursor::seek_to] to return the cursor to that position later on, even if it has
+/// since stepped into or out of containers.
+///
+/// A Checkpoint should only be used with the cursor that created it.
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    state: CursorState,
+}
+
+impl Checkpoint {
+    /// Returns the number of bytes the cursor had read from its data source when the checkpoint
+    /// was created.
+    pub fn offset(&self) -> usize {
+        self.state.bytes_read
+    }
+
+    /// Returns the depth the cursor was at when the checkpoint was created.
+    pub fn depth(&self) -> usize {
+        self.state.depth
+    }
+}
+
 /// Verifies that the current value is of the expected type and that the bytes representing that
 /// value have not yet been consumed from the data source. This macro is called by the
 /// BinaryCursor#read_{typename} methods.
@@ -264,6 +291,18 @@ macro_rules! read_safety_checks {
     };
 }
 
+// Timestamp's constructors report invalid fields as illegal operations; when the fields came
+// from the stream, they are decoding errors instead.
+fn decoded_timestamp(timestamp: IonResult<Timestamp>) -> IonResult<Option<Timestamp>> {
+    match timestamp {
+        Ok(timestamp) => Ok(Some(timestamp)),
+        Err(IonError::IllegalOperation { description }) => {
+            decoding_error(format!("Found an invalid timestamp: {}", description))
+        }
+        Err(error) => Err(error),
+    }
+}
+
 impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
     type DataSource = R;
 
@@ -276,49 +315,85 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         // Skip the remaining bytes of the current value, if any.
         let _ = self.skip_current_value()?;
 
-        if let Some(ref parent) = self.cursor.parents.last() {
-            // If the cursor is nested inside a parent object, don't attempt to read beyond the end of
-            // the parent. Users can call '.step_out()' to progress beyond the container.
-            if self.cursor.bytes_read >= parent.value_end_exclusive() {
-                return Ok(None);
+        let mut header = loop {
+            if let Some(ref parent) = self.cursor.parents.last() {
+                // If the cursor is nested inside a parent object, don't attempt to read beyond the
+                // end of the parent. Users can call '.step_out()' to progress beyond the container.
+                if self.cursor.bytes_read >= parent.value_end_exclusive() {
+                    return Ok(None);
+                }
             }
-        }
 
-        // If we're in a struct, read the field id that must precede each value.
-        self.cursor.value.field_id = if self.cursor.is_in_struct {
-            Some(self.read_field_id()?)
-        } else {
-            None
-        };
+            // If we're in a struct, read the field id that must precede each value.
+            self.cursor.value.field_id = if self.cursor.is_in_struct {
+                Some(self.read_field_id()?)
+            } else {
+                None
+            };
+
+            // Pull the next byte from the data source and interpret it as a value header
+            let header = match self.read_next_value_header()? {
+                Some(header) => header,
+                None if self.cursor.depth > 0 => {
+                    return decoding_error("The stream ended partway through a container.")
+                }
+                None => return Ok(None),
+            };
+            self.cursor.value.header = header;
 
-        // Pull the next byte from the data source and interpret it as a value header
-        let mut header = match self.read_next_value_header()? {
-            Some(header) => header,
-            None => return Ok(None),
+            // NOP padding (along with any field ID that preceded it) is skipped transparently and
+            // does not count toward the number of values at this depth.
+            if header.is_nop_pad() {
+                self.skip_nop_pad()?;
+                continue;
+            }
+            break header;
         };
-        self.cursor.value.header = header;
 
         self.clear_annotations();
         if header.ion_type_code == IonTypeCode::Annotation {
             if header.length_code == 0 {
                 // This is actually the first byte in an Ion Version Marker
-                // TODO: actually parse the IVM instead of assuming 1.0 and skipping it
-                self.cursor.ion_version = (1, 0);
-                self.skip_bytes(IVM.len() - 1)?;
-                return Ok(Some(StreamItem::VersionMarker));
+                let (major, minor) = self.read_ivm()?;
+                return Ok(Some(StreamItem::VersionMarker(major, minor)));
             }
             // We've found an annotated value. Read all of the annotation symbols leading
             // up to the value.
-            let _ = self.read_annotations()?;
+            let wrapper_end = self.read_annotations()?;
             // Now read the next header representing the value itself.
+            // Running out of data here is reported like any other truncated read, which allows
+            // incremental cursors to wait for the rest of the value.
             header = match self.read_next_value_header()? {
                 Some(header) => header,
-                None => return Ok(None),
+                None => {
+                    let error = io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Found an annotation wrapper with no value.",
+                    );
+                    return Err(error.into());
+                }
             };
             self.cursor.value.header = header;
+            if header.is_nop_pad() {
+                return decoding_error("Found an annotation wrapping a NOP pad.");
+            }
+            let _ = self.process_header_by_type_code(header)?;
+            if self.cursor.value.value_end_exclusive() != wrapper_end {
+                return decoding_error(
+                    "The length of an annotation wrapper does not match the value it wraps.",
+                );
+            }
+        } else {
+            let _ = self.process_header_by_type_code(header)?;
         }
 
-        let _ = self.process_header_by_type_code(header)?;
+        if let Some(parent) = self.cursor.parents.last() {
+            if self.cursor.value.value_end_exclusive() > parent.value_end_exclusive() {
+                return decoding_error(
+                    "Found a value that extends beyond the end of its container.",
+                );
+            }
+        }
 
         self.cursor.index_at_depth += 1;
         self.cursor.value.index_at_depth = self.cursor.index_at_depth;
@@ -370,21 +445,41 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         }
     }
 
-    // TODO:
-    // - Detect overflow and return an Error
-    // - Add an integer_size() method that indicates whether the current value will fit in an i64
-    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
         read_safety_checks!(self, IonType::Integer);
 
-        let magnitude = self.read_value_as_uint()?.value();
-
         use self::IonTypeCode::*;
-        let value = match self.cursor.value.header.ion_type_code {
-            PositiveInteger => magnitude as i64,
-            NegativeInteger => -(magnitude as i64),
+        let is_negative = match self.cursor.value.header.ion_type_code {
+            PositiveInteger => false,
+            NegativeInteger => true,
             itc @ _ => unreachable!("Unexpected IonTypeCode: {:?}", itc),
         };
 
+        let number_of_bytes = self.cursor.value.value_length;
+        if number_of_bytes > MAX_UINT_SIZE_IN_BYTES {
+            // The magnitude is too large to read as a u64.
+            let magnitude =
+                self.read_slice(number_of_bytes, |buffer| Ok(BigUint::from_bytes_be(buffer)))?;
+            if is_negative && magnitude.bits() == 0 {
+                return decoding_error("Found a negative integer with a magnitude of zero.");
+            }
+            let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+            // Integer::from will store the value as an i64 if it fits (e.g. if it was padded).
+            return Ok(Some(Integer::from(BigInt::from_biguint(sign, magnitude))));
+        }
+
+        let magnitude = self.read_value_as_uint()?.value();
+        if is_negative && magnitude == 0 {
+            return decoding_error("Found a negative integer with a magnitude of zero.");
+        }
+        let value = match (is_negative, magnitude) {
+            (false, m) if m <= i64::MAX as u64 => Integer::I64(m as i64),
+            // The magnitude of i64::MIN is one larger than i64::MAX; wrapping_neg() handles it.
+            (true, m) if m <= i64::MAX as u64 + 1 => Integer::I64((m as i64).wrapping_neg()),
+            (false, m) => Integer::BigInt(BigInt::from(m)),
+            (true, m) => Integer::BigInt(-BigInt::from(m)),
+        };
+
         Ok(Some(value))
     }
 
@@ -417,23 +512,15 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         })
     }
 
-    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
         read_safety_checks!(self, IonType::Decimal);
 
         if self.cursor.value.value_length == 0 {
-            return Ok(Some(BigDecimal::new(0i64.into(), 0)));
+            return Ok(Some(Decimal::new(0, 0)));
         }
 
-        let exponent_var_int = self.read_var_int()?;
-        let coefficient_size_in_bytes =
-            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
-
-        let exponent = exponent_var_int.value() as i64;
-        let coefficient = self.read_int(coefficient_size_in_bytes)?.value();
-
-        // BigDecimal uses 'scale' rather than 'exponent' in its API, which is a count of the
-        // number of decimal places. It's effectively `exponent * -1`.
-        Ok(Some(BigDecimal::new(coefficient.into(), -exponent)))
+        let value_length = self.cursor.value.value_length;
+        Ok(Some(self.read_decimal_representation(value_length)?))
     }
 
     fn read_string(&mut self) -> IonResult<Option<String>> {
@@ -489,60 +576,87 @@ impl<R: IonDataSource> Cursor for BinaryIonCursor<R> {
         self.clob_ref_map(|c| c.into())
     }
 
-    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
         read_safety_checks!(self, IonType::Timestamp);
 
-        let offset_minutes = self.read_var_int()?.value();
-        let year = self.read_var_uint()?.value();
-
-        let mut month = 0;
-        let mut day = 0;
-        let mut hour = 0;
-        let mut minute = 0;
-        let mut second = 0;
-
-        loop {
-            if self.finished_reading_value() {
-                break;
-            }
-
-            month = self.read_var_uint()?.value();
-            if self.finished_reading_value() {
-                break;
-            }
-
-            day = self.read_var_uint()?.value();
-            if self.finished_reading_value() {
-                break;
-            }
+        if self.cursor.value.value_length == 0 {
+            return decoding_error("Found a timestamp with a length of zero.");
+        }
 
-            hour = self.read_var_uint()?.value();
-            if self.finished_reading_value() {
-                break;
-            }
+        // An offset of negative zero indicates that the offset is unknown.
+        let offset = self.read_var_int()?;
+        let offset_minutes = if offset.is_negative_zero() {
+            None
+        } else if offset.value().abs() < 24 * 60 {
+            Some(offset.value() as i32)
+        } else {
+            return decoding_error(format!(
+                "Found a timestamp with an offset of {} minutes.",
+                offset.value()
+            ));
+        };
+        let year = self.read_timestamp_field("year", 9999)?;
+        if year == 0 {
+            return decoding_error("Found a timestamp with a year of 0.");
+        }
 
-            minute = self.read_var_uint()?.value();
-            if self.finished_reading_value() {
-                break;
+        // Each of the remaining fields is optional; the timestamp's precision is determined by
+        // which of them are present. Timestamps with Year, Month, or Day precision are not
+        // affected by their offset, so it is ignored.
+        if self.finished_reading_value() {
+            return decoded_timestamp(Timestamp::with_year(year));
+        }
+        let month = self.read_timestamp_field("month", 12)?;
+        if self.finished_reading_value() {
+            return decoded_timestamp(Timestamp::with_year_and_month(year, month));
+        }
+        let day = self.read_timestamp_field("day", 31)?;
+        if self.finished_reading_value() {
+            return decoded_timestamp(Timestamp::with_ymd(year, month, day));
+        }
+        let hour = self.read_timestamp_field("hour", 23)?;
+        if self.finished_reading_value() {
+            return decoding_error("Found a timestamp with an hour but no minute.");
+        }
+        let minute = self.read_timestamp_field("minute", 59)?;
+        let mut second = None;
+        let mut fractional_seconds = None;
+        if !self.finished_reading_value() {
+            second = Some(self.read_timestamp_field("second", 59)?);
+            if !self.finished_reading_value() {
+                let remaining_bytes =
+                    self.cursor.value.value_end_exclusive() - self.cursor.bytes_read;
+                fractional_seconds = self.read_fractional_seconds(remaining_bytes)?;
             }
-
-            second = self.read_var_uint()?.value();
-            break;
-
-            // TODO:
-            //   https://github.com/amzn/ion-rust/issues/17
-            //   Read fractional seconds decimal value.
-            //   See DateTime#timestamp_subsec_nanos() to set it on the returned DateTime.
         }
 
-        let naive_datetime = NaiveDate::from_ymd(year as i32, month as u32, day as u32).and_hms(
-            hour as u32,
-            minute as u32,
-            second as u32,
-        );
-        let offset = FixedOffset::west(offset_minutes as i32 * 60i32);
-        let datetime = offset.from_utc_datetime(&naive_datetime);
-        Ok(Some(datetime))
+        // Binary timestamps store their fields in UTC; Timestamp stores them in the local offset.
+        let utc = NaiveDate::from_ymd_opt(year as i32, month, day)
+            .and_then(|date| date.and_hms_opt(hour, minute, second.unwrap_or(0)));
+        let utc = match utc {
+            Some(utc) => utc,
+            None => return decoding_error("Found a timestamp with invalid fields."),
+        };
+        let offset = Duration::minutes(offset_minutes.unwrap_or(0) as i64);
+        let local = match utc.checked_add_signed(offset) {
+            Some(local) => local,
+            None => return decoding_error("Found a timestamp that is out of range."),
+        };
+        let (year, month, day) = (local.year() as u32, local.month(), local.day());
+        let (hour, minute) = (local.hour(), local.minute());
+        let timestamp = match second {
+            Some(second) => Timestamp::with_ymd_hms(year, month, day, hour, minute, second),
+            None => Timestamp::with_ymd_hm(year, month, day, hour, minute),
+        }
+        .and_then(|timestamp| match offset_minutes {
+            Some(offset_minutes) => timestamp.with_offset(offset_minutes),
+            None => Ok(timestamp),
+        })
+        .and_then(|timestamp| match fractional_seconds {
+            Some(fractional_seconds) => timestamp.with_fractional_seconds(fractional_seconds),
+            None => Ok(timestamp),
+        });
+        decoded_timestamp(timestamp)
     }
 
     #[inline]
@@ -690,6 +804,115 @@ where
     }
 }
 
+/// A BinaryIonCursor that reads from a byte slice held in memory. In addition to the methods
+/// available on every BinaryIonCursor, it can return strings, blobs and clobs as references into
+/// the input slice rather than copying them. See [BinaryIonCursor::read_str].
+pub type BinaryIonSliceCursor<'a> = BinaryIonCursor<io::Cursor<&'a [u8]>>;
+
+/// A handle to a container that a [BinaryIonSliceCursor] has already visited. Passing the handle
+/// to [BinaryIonCursor::step_into] steps into the container, even if the cursor has since moved
+/// on to other values.
+#[derive(Clone, Debug)]
+pub struct LazyContainer {
+    ion_type: IonType,
+    checkpoint: Checkpoint,
+}
+
+impl LazyContainer {
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+}
+
+/// Functionality that is only available if the data source is a byte slice. Values read using
+/// these methods borrow from the slice, so they remain valid after the cursor has moved on.
+impl<'a> BinaryIonCursor<io::Cursor<&'a [u8]>> {
+    pub fn from_slice(bytes: &'a [u8]) -> Self {
+        BinaryIonCursor::new(io::Cursor::new(bytes))
+    }
+
+    /// Returns the current string value as a reference into the input slice.
+    pub fn read_str(&mut self) -> IonResult<Option<&'a str>> {
+        read_safety_checks!(self, IonType::String);
+
+        let bytes = self.borrow_value_bytes()?;
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(Some(text)),
+            Err(utf8_error) => decoding_error(format!(
+                "The requested string was not valid UTF-8: {:?}",
+                utf8_error
+            )),
+        }
+    }
+
+    /// Returns the current blob value as a reference into the input slice.
+    pub fn read_blob(&mut self) -> IonResult<Option<&'a [u8]>> {
+        read_safety_checks!(self, IonType::Blob);
+        self.borrow_value_bytes().map(Some)
+    }
+
+    /// Returns the current clob value as a reference into the input slice.
+    pub fn read_clob(&mut self) -> IonResult<Option<&'a [u8]>> {
+        read_safety_checks!(self, IonType::Clob);
+        self.borrow_value_bytes().map(Some)
+    }
+
+    /// If the cursor is positioned over a non-null list, s-expression or struct, returns a handle
+    /// that can be used to step into it later on. Otherwise, returns None.
+    pub fn lazy_container(&self) -> Option<LazyContainer> {
+        match self.ion_type() {
+            Some(ion_type @ IonType::List)
+            | Some(ion_type @ IonType::SExpression)
+            | Some(ion_type @ IonType::Struct)
+                if !self.is_null() =>
+            {
+                Some(LazyContainer {
+                    ion_type,
+                    checkpoint: self.checkpoint(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the cursor to the container represented by `container` and steps into it.
+    pub fn step_into(&mut self, container: &LazyContainer) -> IonResult<()> {
+        self.seek_to(&container.checkpoint)?;
+        self.step_in()
+    }
+
+    // Returns the current value's bytes as a slice of the input and advances the cursor beyond
+    // them.
+    fn borrow_value_bytes(&mut self) -> IonResult<&'a [u8]> {
+        let input: &'a [u8] = self.data_source.get_ref();
+        let bytes = match input.get(self.cursor.value.value_range()) {
+            Some(bytes) => bytes,
+            None => return decoding_error("The current value extends beyond the end of the input."),
+        };
+        self.skip_bytes(bytes.len())?;
+        Ok(bytes)
+    }
+}
+
+/// Functionality that is only available if the data source supports random access, like a File
+/// or an io::Cursor.
+impl<R> BinaryIonCursor<R>
+where
+    R: IonDataSource + io::Seek,
+{
+    /// Moves the cursor back (or forward) to the position captured by `checkpoint`. Afterwards,
+    /// the cursor will be in the same state it was in when the checkpoint was created: positioned
+    /// over the same value, at the same depth, within the same containers.
+    pub fn seek_to(&mut self, checkpoint: &Checkpoint) -> IonResult<()> {
+        // The data source may not have started at offset 0, so we seek relative to the current
+        // position rather than to an absolute offset.
+        let distance = checkpoint.state.bytes_read as i64 - self.cursor.bytes_read as i64;
+        self.data_source.seek(io::SeekFrom::Current(distance))?;
+        self.restore(checkpoint);
+        Ok(())
+    }
+}
+
 impl<R> BinaryIonCursor<R>
 where
     R: IonDataSource,
@@ -716,6 +939,55 @@ where
         self.cursor.value.is_null
     }
 
+    /// Captures the cursor's current position and state. If the data source implements
+    /// `io::Seek`, the cursor can later return to this position using [BinaryIonCursor::seek_to].
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            state: self.cursor.clone(),
+        }
+    }
+
+    // Returns the cursor to the state captured by `checkpoint` without moving the data source.
+    // The caller is responsible for positioning the data source at `checkpoint.offset()`.
+    pub(crate) fn restore(&mut self, checkpoint: &Checkpoint) {
+        self.cursor = checkpoint.state.clone();
+    }
+
+    pub(crate) fn data_source(&self) -> &R {
+        &self.data_source
+    }
+
+    pub(crate) fn data_source_mut(&mut self) -> &mut R {
+        &mut self.data_source
+    }
+
+    /// Returns the number of bytes that have been consumed from the data source.
+    pub fn bytes_read(&self) -> usize {
+        self.cursor.bytes_read
+    }
+
+    // The offset Ranges of the current value's encoded components. See [EncodedValue] for details.
+    pub(crate) fn field_id_range(&self) -> Option<Range<usize>> {
+        self.cursor.value.field_id_range()
+    }
+
+    pub(crate) fn annotations_range(&self) -> Option<Range<usize>> {
+        self.cursor.value.annotations_range()
+    }
+
+    pub(crate) fn header_range(&self) -> Range<usize> {
+        self.cursor.value.header_range()
+    }
+
+    pub(crate) fn value_range(&self) -> Range<usize> {
+        self.cursor.value.value_range()
+    }
+
+    // The offset of the first byte after the end of the current value.
+    pub(crate) fn value_end_exclusive(&self) -> usize {
+        self.cursor.value.value_end_exclusive()
+    }
+
     fn finished_reading_value(&mut self) -> bool {
         self.cursor.value.value_length > 0 && self.cursor.bytes_read >= self.cursor.value.value_end_exclusive()
     }
@@ -728,6 +1000,9 @@ where
             self.cursor.annotations.truncate(new_annotations_len);
             self.cursor.value.number_of_annotations = 0;
         }
+        // The field ID's offset is computed relative to the annotations wrapper, so a stale
+        // length would misplace the field ID of an unannotated value.
+        self.cursor.value.annotations_length = 0;
     }
 
     #[inline(always)]
@@ -737,6 +1012,44 @@ where
         Ok(var_uint)
     }
 
+    // Reads one of the VarUInt fields of a timestamp, rejecting values larger than `max` before
+    // they can be truncated or used in date arithmetic.
+    fn read_timestamp_field(&mut self, name: &str, max: u32) -> IonResult<u32> {
+        let value = self.read_var_uint()?.value();
+        if value > max as usize {
+            return decoding_error(format!(
+                "Found a timestamp with an invalid {}: {}",
+                name, value
+            ));
+        }
+        Ok(value as u32)
+    }
+
+    // Reads the fractional seconds of a timestamp, returning None if they are a zero with no
+    // digits after the decimal point. The exponent is untrusted, so the fraction is validated
+    // before any BigDecimal arithmetic, which would rescale it to as many digits as the exponent.
+    fn read_fractional_seconds(&mut self, number_of_bytes: usize) -> IonResult<Option<BigDecimal>> {
+        let fraction = self.read_decimal_representation(number_of_bytes)?;
+        let exponent = fraction.exponent();
+        if exponent >= 0 && fraction.is_zero() {
+            return Ok(None);
+        }
+        if exponent >= 0 || (fraction.is_negative() && !fraction.is_zero()) {
+            return decoding_error("Found a timestamp with fractional seconds outside of [0, 1).");
+        }
+        if exponent < -(MAX_FRACTIONAL_SECONDS_DIGITS as i64) {
+            return decoding_error(format!(
+                "Found a timestamp with more than {} digits of fractional seconds.",
+                MAX_FRACTIONAL_SECONDS_DIGITS
+            ));
+        }
+        // The coefficient must have fewer digits than there are places after the decimal point.
+        if *fraction.magnitude() >= BigUint::from(10u32).pow(-exponent as u32) {
+            return decoding_error("Found a timestamp with fractional seconds outside of [0, 1).");
+        }
+        Ok(Some(fraction.into()))
+    }
+
     #[inline(always)]
     fn read_var_int(&mut self) -> IonResult<VarInt> {
         let var_int = VarInt::read(&mut self.data_source)?;
@@ -766,6 +1079,37 @@ where
         Ok(int)
     }
 
+    // Reads a decimal (an exponent VarInt followed by a coefficient Int) that is encoded in the
+    // next `number_of_bytes` bytes. Used for decimal values and timestamps' fractional seconds.
+    fn read_decimal_representation(&mut self, number_of_bytes: usize) -> IonResult<Decimal> {
+        let exponent_var_int = self.read_var_int()?;
+        if exponent_var_int.size_in_bytes() > number_of_bytes {
+            return decoding_error("Found a decimal whose exponent extends beyond its length.");
+        }
+        let coefficient_size_in_bytes = number_of_bytes - exponent_var_int.size_in_bytes();
+
+        let exponent = exponent_var_int.value() as i64;
+        // Unlike BigInt, the coefficient's sign-and-magnitude encoding can represent negative
+        // zero, so we keep track of it separately.
+        let (coefficient, is_negative_zero): (BigInt, bool) =
+            if coefficient_size_in_bytes > MAX_INT_SIZE_IN_BYTES {
+                self.read_slice(coefficient_size_in_bytes, |buffer| {
+                    let coefficient = Int::big_int_from_bytes(buffer);
+                    let is_negative = buffer[0] & 0b1000_0000 != 0;
+                    let is_negative_zero = is_negative && coefficient.bits() == 0;
+                    Ok((coefficient, is_negative_zero))
+                })?
+            } else {
+                let int = self.read_int(coefficient_size_in_bytes)?;
+                (int.value().into(), int.is_negative_zero())
+            };
+
+        if is_negative_zero {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        Ok(Decimal::new(coefficient, exponent))
+    }
+
     fn process_header_by_type_code(&mut self, header: Header) -> IonResult<()> {
         self.cursor.value.ion_type = header.ion_type.unwrap(); // TODO: Is cursor.value.ion_type redundant?
         self.cursor.value.header = header;
@@ -820,7 +1164,13 @@ where
     fn read_struct_length(&mut self) -> IonResult<usize> {
         let length = match self.cursor.value.header.length_code {
             length_codes::NULL => 0,
-            1 | length_codes::VAR_UINT => self.read_var_uint()?.value(),
+            // A length code of 1 indicates a struct whose fields are sorted by symbol ID. Such a
+            // struct cannot be empty.
+            1 => match self.read_var_uint()?.value() {
+                0 => return decoding_error("Found an empty struct with sorted fields."),
+                length => length,
+            },
+            length_codes::VAR_UINT => self.read_var_uint()?.value(),
             magnitude => magnitude as usize,
         };
 
@@ -839,9 +1189,11 @@ where
     }
 
     fn next_byte(&mut self) -> IonResult<Option<u8>> {
-        let byte = self.data_source.next_byte();
-        self.cursor.bytes_read += 1;
-        byte
+        let byte = self.data_source.next_byte()?;
+        if byte.is_some() {
+            self.cursor.bytes_read += 1;
+        }
+        Ok(byte)
     }
 
     fn skip_bytes(&mut self, number_of_bytes: usize) -> IonResult<()> {
@@ -858,11 +1210,55 @@ where
         if self.cursor.index_at_depth == 0 {
             Ok(())
         } else {
-            let bytes_to_skip = self.cursor.value.value_end_exclusive() - self.cursor.bytes_read;
+            // If the current value was followed by NOP padding, the cursor may already be beyond
+            // the end of the value.
+            let bytes_to_skip = self
+                .cursor
+                .value
+                .value_end_exclusive()
+                .saturating_sub(self.cursor.bytes_read);
             self.skip_bytes(bytes_to_skip)
         }
     }
 
+    // Reads the remainder of an Ion Version Marker whose first byte (0xE0) has already been read,
+    // then updates the cursor's Ion version.
+    fn read_ivm(&mut self) -> IonResult<(u8, u8)> {
+        if self.cursor.depth > 0 {
+            return decoding_error("Found an Ion Version Marker inside a container.");
+        }
+        let (major, minor, end) = self.read_slice(IVM.len() - 1, |bytes| {
+            Ok((bytes[0], bytes[1], bytes[2]))
+        })?;
+        if end != IVM[IVM.len() - 1] {
+            return decoding_error(format!(
+                "Found a malformed Ion Version Marker: {:02X} {:02X} {:02X} {:02X}",
+                IVM[0], major, minor, end
+            ));
+        }
+        if (major, minor) != (1, 0) {
+            return decoding_error(format!(
+                "Found an Ion Version Marker for an unsupported Ion version: {}.{}",
+                major, minor
+            ));
+        }
+        self.cursor.ion_version = (major, minor);
+        Ok((major, minor))
+    }
+
+    // Skips the body of the NOP pad whose header is in `self.cursor.value.header`.
+    fn skip_nop_pad(&mut self) -> IonResult<()> {
+        let pad_length = self.read_standard_length()?;
+        if let Some(parent) = self.cursor.parents.last() {
+            if self.cursor.bytes_read + pad_length > parent.value_end_exclusive() {
+                return decoding_error(
+                    "Found a NOP pad that extends beyond the end of its container.",
+                );
+            }
+        }
+        self.skip_bytes(pad_length)
+    }
+
     fn read_field_id(&mut self) -> IonResult<SymbolId> {
         let var_uint = self.read_var_uint()?;
         let field_id = var_uint.value();
@@ -870,15 +1266,21 @@ where
         Ok(field_id)
     }
 
-    fn read_annotations(&mut self) -> IonResult<()> {
+    // Reads the annotations of an annotation wrapper whose type descriptor has already been read.
+    // Returns the offset of the first byte after the end of the wrapper.
+    fn read_annotations(&mut self) -> IonResult<usize> {
         let num_annotations_before = self.cursor.annotations.len();
         // The first byte of the annotations envelope is now behind the cursor
         let annotations_offset = self.cursor.bytes_read - 1;
         // The encoding allows us to skip over the annotations list and the value, but in practice
         // we won't know if we want to skip this value until we've read the type descriptor byte.
         // That means we need to read the length even though we have no intent to use it.
-        let _annotations_and_value_length = self.read_standard_length()?;
+        let annotations_and_value_length = self.read_standard_length()?;
+        let wrapper_end = self.cursor.bytes_read + annotations_and_value_length;
         let annotations_length = self.read_var_uint()?;
+        if annotations_length.value() == 0 {
+            return decoding_error("Found an annotation wrapper with no annotations.");
+        }
         let mut bytes_read: usize = 0;
         while bytes_read < annotations_length.value() {
             let var_uint = self.read_var_uint()?;
@@ -889,9 +1291,15 @@ where
         let new_annotations_count = self.cursor.annotations.len() - num_annotations_before;
         self.cursor.value.number_of_annotations = new_annotations_count as u8;
 
+        if bytes_read != annotations_length.value() {
+            return decoding_error(
+                "Found an annotation that extends beyond the wrapper's annotations length.",
+            );
+        }
+
         // The annotations type descriptor byte + the length of the annotations sequence
         self.cursor.value.annotations_length = (self.cursor.bytes_read - annotations_offset) as u8;
-        Ok(())
+        Ok(wrapper_end)
     }
 
     fn read_exact(&mut self, number_of_bytes: usize) -> IonResult<()> {
@@ -957,11 +1365,13 @@ mod tests {
     use chrono::{FixedOffset, NaiveDate, TimeZone};
 
     use crate::binary::constants::v1_0::IVM;
-    use crate::binary::cursor::BinaryIonCursor;
+    use crate::binary::cursor::{BinaryIonCursor, BinaryIonSliceCursor};
     use crate::cursor::{Cursor, StreamItem, StreamItem::*};
-    use crate::result::IonResult;
-    use crate::types::IonType;
+    use crate::result::{IonError, IonResult};
+    use crate::types::{Decimal, Integer, IonType, Timestamp};
+    use num_bigint::BigInt;
     use std::convert::TryInto;
+    use std::str::FromStr;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
 
@@ -983,11 +1393,41 @@ mod tests {
     fn ion_cursor_for(bytes: &[u8]) -> BinaryIonCursor<TestDataSource> {
         let mut binary_cursor = BinaryIonCursor::new(data_source_for(bytes));
         assert_eq!(binary_cursor.ion_type(), None);
-        assert_eq!(binary_cursor.next(), Ok(Some(VersionMarker)));
+        assert_eq!(binary_cursor.next(), Ok(Some(VersionMarker(1, 0))));
         assert_eq!(binary_cursor.ion_version(), (1u8, 0u8));
         binary_cursor
     }
 
+    #[test]
+    fn test_version_markers() -> IonResult<()> {
+        // A second IVM partway through the stream
+        let mut cursor = ion_cursor_for(&[0x20, 0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.ion_version(), (1, 0));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_version_marker() {
+        let mut cursor = BinaryIonCursor::new(io::Cursor::new(vec![0xE0, 0x02, 0x00, 0xEA]));
+        assert!(cursor.next().is_err());
+        assert_eq!(cursor.ion_version(), (1, 0));
+    }
+
+    #[test]
+    fn test_malformed_version_markers() {
+        let mut cursor = BinaryIonCursor::new(io::Cursor::new(vec![0xE0, 0x01, 0x00, 0xEB]));
+        assert!(cursor.next().is_err());
+        // An IVM cannot appear inside of a container
+        let mut cursor = ion_cursor_for(&[0xB4, 0xE0, 0x01, 0x00, 0xEA]);
+        assert_eq!(cursor.next(), Ok(Some(Value(IonType::List, false))));
+        cursor.step_in().unwrap();
+        assert!(cursor.next().is_err());
+    }
+
     #[test]
     fn test_read_null_null() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x0F]);
@@ -1046,6 +1486,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_i64_min() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(i64::MIN));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_overflow() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.read_i64().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_integer_u64_magnitude() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let expected = BigInt::from_str("9223372036854775808").unwrap();
+        assert_eq!(cursor.read_integer()?, Some(Integer::BigInt(expected)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_big_int_nine_bytes() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x39, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let expected = BigInt::from_str("-18446744073709551616").unwrap();
+        assert_eq!(cursor.read_big_int()?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_padded_integer() -> IonResult<()> {
+        // 1, padded with leading zeros to nine bytes
+        let mut cursor = ion_cursor_for(&[
+            0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        Ok(())
+    }
+
     #[test]
     fn test_read_f64_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x40]);
@@ -1081,6 +1568,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_big_decimal_large_coefficient() -> IonResult<()> {
+        // A nine-byte negative coefficient (-2^64) with an exponent of -1
+        let mut cursor = ion_cursor_for(&[
+            0x5A, 0xC1, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        let coefficient = BigInt::from_str("-18446744073709551616").unwrap();
+        assert_eq!(
+            cursor.read_big_decimal()?,
+            Some(BigDecimal::new(coefficient, 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_decimal_preserves_sign_and_exponent() -> IonResult<()> {
+        // -0d0, 0.00, -0d-1
+        let mut cursor = ion_cursor_for(&[0x52, 0x80, 0x80, 0x51, 0xC2, 0x52, 0xC1, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::negative_zero()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::new(0, -2)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(
+            cursor.read_decimal()?,
+            Some(Decimal::negative_zero_with_exponent(-1))
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_read_timestamp() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x68, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80]);
@@ -1093,6 +1611,114 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_timestamp_year_precision() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x63, 0xC0, 0x0F, 0xDB]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(cursor.read_timestamp()?, Some(Timestamp::with_year(2011)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_with_offset_and_fraction() -> IonResult<()> {
+        // 2020-06-15T10:30:45.250-07:00, which is stored as 17:30:45 UTC
+        let mut cursor = ion_cursor_for(&[
+            0x6C, 0x43, 0xA4, 0x0F, 0xE4, 0x86, 0x8F, 0x91, 0x9E, 0xAD, 0xC3, 0x00, 0xFA,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let expected = Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+            .with_offset(-420)?
+            .with_fractional_seconds(BigDecimal::new(250.into(), 3))?;
+        let timestamp = cursor.read_timestamp()?.unwrap();
+        assert_eq!(timestamp, expected);
+        assert_eq!(timestamp.to_string(), "2020-06-15T10:30:45.250-07:00");
+        let naive_datetime = NaiveDate::from_ymd(2020, 6, 15).and_hms_milli(17, 30, 45, 250);
+        let datetime = FixedOffset::west(7 * 3600).from_utc_datetime(&naive_datetime);
+        assert_eq!(timestamp.to_datetime(), datetime);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_unknown_offset() -> IonResult<()> {
+        // 2020-01-01T00:00-00:00
+        let mut cursor = ion_cursor_for(&[0x67, 0xC0, 0x0F, 0xE4, 0x81, 0x81, 0x80, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let timestamp = cursor.read_timestamp()?.unwrap();
+        assert_eq!(timestamp, Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?);
+        assert_eq!(timestamp.offset(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_invalid_timestamp() -> IonResult<()> {
+        // Month 13
+        let mut cursor = ion_cursor_for(&[0x64, 0xC0, 0x0F, 0xE4, 0x8D]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_out_of_range_timestamps() -> IonResult<()> {
+        let invalid_timestamps: &[&[u8]] = &[
+            // Year 262142 with an offset of +23:59, which once overflowed while being converted
+            // from UTC
+            &[0x69, 0x0B, 0x9F, 0x0F, 0x7F, 0xFE, 0x8C, 0x9F, 0x97, 0xBB],
+            // 2020-01-01T00:00 with an offset of +24:00
+            &[0x68, 0x0B, 0xA0, 0x0F, 0xE4, 0x81, 0x81, 0x80, 0x80],
+            // Year 0
+            &[0x62, 0xC0, 0x80],
+            // A month of 2^32 + 1, which would be truncated to 1 as a u32
+            &[0x68, 0xC0, 0x0F, 0xE4, 0x10, 0x00, 0x00, 0x00, 0x81],
+            // 9999-12-31T23:59Z with an offset of +00:01, which is in the year 10000 locally
+            &[0x67, 0x81, 0x4E, 0x8F, 0x8C, 0x9F, 0x97, 0xBB],
+        ];
+        for bytes in invalid_timestamps {
+            let mut cursor = ion_cursor_for(bytes);
+            assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+            match cursor.read_timestamp() {
+                Err(IonError::DecodingError { .. }) => {}
+                other => panic!("Expected a decoding error for {:x?}, found {:?}", bytes, other),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_fraction_with_huge_exponent() -> IonResult<()> {
+        // 2000-01-01T00:00:00Z followed by the fractional seconds
+        let prefix = [0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80];
+        let invalid_fractions: &[&[u8]] = &[
+            // 5d-1152921504606846976, which has too many digits to be checked cheaply
+            &[0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x05],
+            // 1d1152921504606846976, which is greater than one
+            &[0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x01],
+            // 10d-1, which is equal to one
+            &[0xC1, 0x0A],
+        ];
+        for fraction in invalid_fractions {
+            let mut bytes = vec![0x6E, 0x80 | (prefix.len() + fraction.len()) as u8];
+            bytes.extend_from_slice(&prefix);
+            bytes.extend_from_slice(fraction);
+            let mut cursor = ion_cursor_for(&bytes);
+            assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+            match cursor.read_timestamp() {
+                Err(IonError::DecodingError { .. }) => {}
+                other => panic!("Expected a decoding error for {:x?}, found {:?}", bytes, other),
+            }
+        }
+
+        // 0d1152921504606846976 is a zero with no digits after the decimal point.
+        let mut bytes = vec![0x6E, 0x91];
+        bytes.extend_from_slice(&prefix);
+        bytes.extend_from_slice(&[0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80]);
+        let mut cursor = ion_cursor_for(&bytes);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let timestamp = cursor.read_timestamp()?.unwrap();
+        assert_eq!(timestamp.to_string(), "2000-01-01T00:00:00Z");
+        Ok(())
+    }
+
     #[test]
     fn test_read_symbol_10() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x71, 0x0A]);
@@ -1210,6 +1836,142 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_nop_pads_at_top_level() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x00, // One-byte NOP pad
+            0x21, 0x01, // Integer 1
+            0x03, 0xFF, 0xFF, 0xFF, // Four-byte NOP pad
+            0x0E, 0x82, 0x00, 0x00, // NOP pad with a VarUInt length
+            0x21, 0x02, // Integer 2
+            0x01, 0x00, // Two-byte NOP pad
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.cursor.index_at_depth, 1);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(2));
+        assert_eq!(cursor.cursor.index_at_depth, 2);
+        assert_eq!(cursor.next()?, None);
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_every_length_code() -> IonResult<()> {
+        // Like the typecodes files in ion-tests: NOP pads, positive and negative integers, lists
+        // and s-expressions using each length code, including the VarUInt length (0xE).
+        let mut bytes = vec![];
+        for type_code in &[0x00u8, 0x20, 0x30, 0xB0, 0xC0] {
+            // Containers are filled with one-byte NOP pads; everything else with 0xFF bytes.
+            let body = if *type_code >= 0xB0 { 0x00 } else { 0xFF };
+            // Negative zero is not a legal integer, so integers with length 0 use 0x20.
+            let first_length = if *type_code == 0x30 { 1 } else { 0 };
+            for length in first_length..14u8 {
+                bytes.push(type_code | length);
+                bytes.extend(std::iter::repeat(body).take(length as usize));
+            }
+            bytes.extend_from_slice(&[type_code | 0x0E, 0x8E]);
+            bytes.extend(std::iter::repeat(body).take(14));
+            // Null
+            bytes.push(type_code | 0x0F);
+        }
+        let mut cursor = ion_cursor_for(&bytes);
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Null, true)));
+        for (type_code, ion_type) in &[(0x20, IonType::Integer), (0x30, IonType::Integer)] {
+            let first_length = if *type_code == 0x30 { 1 } else { 0 };
+            for length in first_length..=14 {
+                let magnitude: BigInt = (BigInt::from(1) << (8 * length)) - 1;
+                let expected = if *type_code == 0x30 {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                assert_eq!(cursor.next()?, Some(Value(*ion_type, false)));
+                assert_eq!(cursor.read_integer()?, Some(Integer::from(expected)));
+            }
+            assert_eq!(cursor.next()?, Some(Value(*ion_type, true)));
+        }
+        for ion_type in &[IonType::List, IonType::SExpression] {
+            for _length in 0..=14 {
+                assert_eq!(cursor.next()?, Some(Value(*ion_type, false)));
+                cursor.step_in()?;
+                assert_eq!(cursor.next()?, None);
+                cursor.step_out()?;
+            }
+            assert_eq!(cursor.next()?, Some(Value(*ion_type, true)));
+        }
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nop_pads_in_list() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0xB5, // 5-byte list
+            0x00, // One-byte NOP pad
+            0x21, 0x01, // Integer 1
+            0x01, 0x00, // Two-byte NOP pad
+            0x20, // Integer 0
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.cursor.index_at_depth, 1);
+        assert_eq!(cursor.next()?, None);
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nop_pads_in_struct() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0xD8, // 8-byte struct
+            0x80, 0x01, 0xAA, // Field ID 0, two-byte NOP pad
+            0x84, 0x21, 0x07, // Field ID 4, Integer 7
+            0x85, 0x00, // Field ID 5, one-byte NOP pad
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.field_id(), Some(4usize));
+        assert_eq!(cursor.read_i64()?, Some(7));
+        assert_eq!(cursor.cursor.index_at_depth, 1);
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nop_pad_in_empty_struct() -> IonResult<()> {
+        // A struct containing only a field ID and a NOP pad
+        let mut cursor = ion_cursor_for(&[0xD3, 0x8A, 0x01, 0x00]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_nop_pads() {
+        // An annotation wrapper cannot contain a NOP pad
+        let mut cursor = ion_cursor_for(&[0xE3, 0x81, 0x84, 0x00]);
+        assert!(cursor.next().is_err());
+        // A NOP pad cannot extend beyond the end of its container
+        let mut cursor = ion_cursor_for(&[0xB2, 0x02, 0x00, 0x00, 0x00]);
+        assert_eq!(cursor.next(), Ok(Some(Value(IonType::List, false))));
+        cursor.step_in().unwrap();
+        assert!(cursor.next().is_err());
+    }
+
     #[test]
     fn test_read_struct_empty() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0xD0]);
@@ -1397,4 +2159,108 @@ mod tests {
         assert_eq!(cursor.raw_value_bytes(), None);
         Ok(())
     }
+
+    #[test]
+    fn test_seek_to_checkpoint() -> IonResult<()> {
+        // [1, {$10: 2}], 3
+        let mut cursor = ion_cursor_for(&[0xB6, 0x21, 0x01, 0xD3, 0x8A, 0x21, 0x02, 0x21, 0x03]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        let list = cursor.checkpoint();
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let field = cursor.checkpoint();
+        assert_eq!(field.depth(), 2);
+        assert_eq!(cursor.read_i64()?, Some(2));
+
+        // Jump to the end of the stream
+        cursor.step_out()?;
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(3));
+        assert_eq!(cursor.next()?, None);
+
+        // Return to the struct field and read it again
+        cursor.seek_to(&field)?;
+        assert_eq!(cursor.depth(), 2);
+        assert_eq!(cursor.field_id(), Some(10));
+        assert_eq!(cursor.read_i64()?, Some(2));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+
+        // Return to the top-level list and step into it
+        cursor.seek_to(&list)?;
+        // The list's one-byte header has already been read
+        assert_eq!(list.offset(), IVM.len() + 1);
+        assert_eq!(cursor.depth(), 0);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_borrowed_values() -> IonResult<()> {
+        // "foo" {{AQI=}} {{"bar"}} null.string
+        let data = ion_data(&[
+            0x83, b'f', b'o', b'o', 0xA2, 0x01, 0x02, 0x93, b'b', b'a', b'r', 0x8F,
+        ]);
+        let (text, blob, clob) = {
+            let mut cursor = BinaryIonSliceCursor::from_slice(&data);
+            assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+            assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+            let text = cursor.read_str()?;
+            assert!(cursor.read_str().is_err());
+            assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+            assert_eq!(cursor.read_str()?, None);
+            let blob = cursor.read_blob()?;
+            assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+            let clob = cursor.read_clob()?;
+            assert_eq!(cursor.next()?, Some(Value(IonType::String, true)));
+            assert_eq!(cursor.read_str()?, None);
+            (text, blob, clob)
+        };
+        // The values outlive the cursor that read them
+        assert_eq!(text, Some("foo"));
+        assert_eq!(blob, Some(&[0x01u8, 0x02][..]));
+        assert_eq!(clob, Some(&b"bar"[..]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_into_lazy_container() -> IonResult<()> {
+        // ["a", "b"] {$10: "c"} 5
+        let data = ion_data(&[
+            0xB4, 0x81, b'a', 0x81, b'b', 0xD3, 0x8A, 0x81, b'c', 0x21, 0x05,
+        ]);
+        let mut cursor = BinaryIonSliceCursor::from_slice(&data);
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        let list = cursor.lazy_container().unwrap();
+        assert_eq!(list.ion_type(), IonType::List);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        let strukt = cursor.lazy_container().unwrap();
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.lazy_container().is_none());
+
+        cursor.step_into(&strukt)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.field_id(), Some(10));
+        assert_eq!(cursor.read_str()?, Some("c"));
+
+        cursor.step_into(&list)?;
+        let mut values = vec![];
+        while let Some(Value(IonType::String, false)) = cursor.next()? {
+            values.push(cursor.read_str()?.unwrap());
+        }
+        assert_eq!(values, vec!["a", "b"]);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        Ok(())
+    }
 }
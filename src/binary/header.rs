@@ -1,6 +1,6 @@
 use crate::{
-    binary::{nibbles::nibbles_from_byte, IonTypeCode},
-    result::IonResult,
+    binary::{constants::v1_0::length_codes, nibbles::nibbles_from_byte, IonTypeCode},
+    result::{decoding_error, IonResult},
     types::IonType,
 };
 
@@ -22,6 +22,12 @@ impl Header {
     pub fn from_byte(byte: u8) -> IonResult<Header> {
         let (type_code, length_code) = nibbles_from_byte(byte);
         let ion_type_code = IonTypeCode::from(type_code)?;
+        if !is_legal_length_code(ion_type_code, length_code) {
+            return decoding_error(format!(
+                "Found an illegal type descriptor: 0x{:02X} ({:?} with length code {})",
+                byte, ion_type_code, length_code
+            ));
+        }
         let ion_type = ion_type_code.into_ion_type().ok();
         Ok(Header {
             ion_type,
@@ -29,6 +35,34 @@ impl Header {
             length_code,
         })
     }
+
+    /// Returns true if this header begins NOP padding rather than a value. NOP pads share a type
+    /// code with nulls, but use any length code other than the one that indicates null.
+    pub fn is_nop_pad(&self) -> bool {
+        self.ion_type_code == IonTypeCode::NullOrWhitespace
+            && self.length_code != length_codes::NULL
+    }
+}
+
+// Returns false if the binary Ion spec does not allow `length_code` to be used with
+// `ion_type_code`. Lengths that are stored outside of the type descriptor are validated as the
+// value is read.
+fn is_legal_length_code(ion_type_code: IonTypeCode, length_code: u8) -> bool {
+    use IonTypeCode::*;
+    match ion_type_code {
+        // `false`, `true` and `null.bool` are the only booleans.
+        Boolean => matches!(length_code, 0 | 1 | length_codes::NULL),
+        // A zero-length negative integer would be negative zero, which is not an Ion int.
+        NegativeInteger => length_code != 0,
+        Float => matches!(length_code, 0 | 4 | 8 | length_codes::NULL),
+        // A timestamp requires at least an offset and a year, each of which takes a byte.
+        Timestamp => length_code >= 2,
+        // 0xE0 begins an Ion version marker. Otherwise, an annotation wrapper requires at least
+        // one byte each for the annotations length, an annotation and the wrapped value.
+        Annotation => length_code == 0 || (3..=length_codes::VAR_UINT).contains(&length_code),
+        Reserved => false,
+        _ => true,
+    }
 }
 
 /// Parses all possible values of a single byte and stores them in a newly allocated Vec.
@@ -55,3 +89,32 @@ pub(crate) fn create_header_byte_jump_table() -> Vec<IonResult<Option<Header>>>
     }
     header_jump_table
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::header::Header;
+
+    #[test]
+    fn test_illegal_type_descriptors() {
+        let illegal = [
+            0x12, 0x1E, 0x30, 0x41, 0x43, 0x4E, 0x60, 0x61, 0xE1, 0xE2, 0xEF, 0xF0,
+        ];
+        for byte in &illegal {
+            assert!(
+                Header::from_byte(*byte).is_err(),
+                "0x{:02X} was accepted",
+                byte
+            );
+        }
+        let legal = [
+            0x0E, 0x10, 0x11, 0x1F, 0x31, 0x3F, 0x40, 0x44, 0x48, 0x62, 0xD1, 0xE0, 0xE3,
+        ];
+        for byte in &legal {
+            assert!(
+                Header::from_byte(*byte).is_ok(),
+                "0x{:02X} was rejected",
+                byte
+            );
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use std::io;
+use std::io::{BufRead, Read, Seek, SeekFrom};
+
+use bigdecimal::BigDecimal;
+use delegate::delegate;
+
+use crate::binary::cursor::BinaryIonCursor;
+use crate::cursor::{Cursor, StreamItem};
+use crate::result::{decoding_error, incomplete_data_error, IonError, IonResult};
+use crate::types::{Decimal, Integer, IonType, SymbolId, Timestamp};
+
+/// An in-memory data source that grows as chunks of data are pushed into it. Bytes that precede
+/// the cursor's current top-level value are discarded once they make up most of the buffer.
+#[derive(Debug, Default)]
+pub struct IncrementalBuffer {
+    data: Vec<u8>,
+    // The index of the next byte to be read from `data`
+    position: usize,
+    // How many bytes have been discarded from the front of `data`. Seek offsets are relative to
+    // the beginning of the stream rather than the beginning of `data`.
+    bytes_discarded: usize,
+}
+
+impl IncrementalBuffer {
+    fn push(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    // The number of bytes that have been pushed but not yet read.
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    // Drops the bytes that have already been read. To avoid shifting the rest of the buffer each
+    // time a value is read, this only happens once they make up more than half of the buffer, so
+    // the cost of moving the unread bytes is proportional to the number of bytes discarded.
+    fn discard_consumed_bytes(&mut self) {
+        if self.position <= self.data.len() / 2 {
+            return;
+        }
+        self.data.drain(..self.position);
+        self.bytes_discarded += self.position;
+        self.position = 0;
+    }
+}
+
+impl Read for IncrementalBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let number_of_bytes = buf.len().min(self.remaining());
+        buf[..number_of_bytes]
+            .copy_from_slice(&self.data[self.position..self.position + number_of_bytes]);
+        self.position += number_of_bytes;
+        Ok(number_of_bytes)
+    }
+}
+
+impl BufRead for IncrementalBuffer {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(&self.data[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position = (self.position + amount).min(self.data.len());
+    }
+}
+
+impl Seek for IncrementalBuffer {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let start = self.bytes_discarded as i64;
+        let end = start + self.data.len() as i64;
+        let target = match position {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(distance) => end + distance,
+            SeekFrom::Current(distance) => start + self.position as i64 + distance,
+        };
+        if target < start || target > end {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Cannot seek outside of the buffered data.",
+            ));
+        }
+        self.position = (target - start) as usize;
+        Ok(target as u64)
+    }
+}
+
+/// A binary Ion cursor for input that arrives in chunks, such as data read from a non-blocking
+/// socket. Rather than pulling data from a blocking data source, the cursor reads from bytes
+/// that the caller has provided using [IncrementalBinaryCursor::push].
+///
+/// Top-level values are only returned by `next()` once they have been completely buffered. If
+/// the buffered data ends partway through the next value, `next()` returns an
+/// [IonError::Incomplete] and leaves the cursor where it was; once more data has been pushed,
+/// calling `next()` again will pick up where it left off. Because every top-level value is
+/// buffered in full, reading a value or stepping into a container never needs more data.
+///
+/// Until [IncrementalBinaryCursor::end_of_stream] is called, running out of data between
+/// top-level values is also reported as Incomplete, since more values may still arrive.
+pub struct IncrementalBinaryCursor {
+    cursor: BinaryIonCursor<IncrementalBuffer>,
+    is_end_of_stream: bool,
+}
+
+impl IncrementalBinaryCursor {
+    pub fn new() -> Self {
+        IncrementalBinaryCursor {
+            cursor: BinaryIonCursor::new(IncrementalBuffer::default()),
+            is_end_of_stream: false,
+        }
+    }
+
+    /// Appends `bytes` to the data available to the cursor.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.cursor.data_source_mut().push(bytes);
+    }
+
+    /// Indicates that no more data will be pushed. Afterwards, `next()` will return `Ok(None)`
+    /// when it reaches the end of the buffered data and an error if the data ends partway
+    /// through a value.
+    pub fn end_of_stream(&mut self) {
+        self.is_end_of_stream = true;
+    }
+
+    /// Returns true if the buffered data contains the entirety of the current value.
+    fn value_is_buffered(&self) -> bool {
+        let bytes_available = self.cursor.bytes_read() + self.cursor.data_source().remaining();
+        self.cursor.value_end_exclusive() <= bytes_available
+    }
+}
+
+impl Default for IncrementalBinaryCursor {
+    fn default() -> Self {
+        IncrementalBinaryCursor::new()
+    }
+}
+
+impl Cursor for IncrementalBinaryCursor {
+    type DataSource = IncrementalBuffer;
+
+    fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        // Values inside of a container are always buffered in full.
+        if self.cursor.depth() > 0 {
+            return self.cursor.next();
+        }
+
+        // Everything before the current top-level value has been read and can be dropped.
+        self.cursor.data_source_mut().discard_consumed_bytes();
+        let checkpoint = self.cursor.checkpoint();
+        match self.cursor.next() {
+            Ok(Some(StreamItem::Value(..))) if !self.value_is_buffered() => {
+                if self.is_end_of_stream {
+                    return decoding_error("The stream ended partway through a value.");
+                }
+            }
+            // Running out of data while reading a header means that we need more data.
+            Ok(None) | Err(IonError::IoError { .. })
+                if !self.is_end_of_stream && self.cursor.data_source().remaining() == 0 => {}
+            result => return result,
+        }
+
+        // Return to the previous value so that the next call to `next()` can try again. A failed
+        // read may have consumed bytes without the cursor counting them, so we seek to the
+        // checkpoint's absolute offset rather than seeking relative to the cursor's position.
+        self.cursor
+            .data_source_mut()
+            .seek(SeekFrom::Start(checkpoint.offset() as u64))?;
+        self.cursor.restore(&checkpoint);
+        incomplete_data_error(format!(
+            "the buffered data ends before the value following offset {}",
+            checkpoint.offset()
+        ))
+    }
+
+    delegate! {
+        to self.cursor {
+            fn ion_version(&self) -> (u8, u8);
+            fn ion_type(&self) -> Option<IonType>;
+            fn annotation_ids(&self) -> &[SymbolId];
+            fn field_id(&self) -> Option<SymbolId>;
+            fn read_null(&mut self) -> IonResult<Option<IonType>>;
+            fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+            fn read_f32(&mut self) -> IonResult<Option<f32>>;
+            fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+            fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+            fn read_string(&mut self) -> IonResult<Option<String>>;
+            fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&str) -> T;
+            fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&[u8]) -> T;
+            fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>>;
+            fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+            fn step_in(&mut self) -> IonResult<()>;
+            fn step_out(&mut self) -> IonResult<()>;
+            fn depth(&self) -> usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::constants::v1_0::IVM;
+    use crate::binary::incremental::IncrementalBinaryCursor;
+    use crate::cursor::{Cursor, StreamItem::*};
+    use crate::result::{IonError, IonResult};
+    use crate::types::IonType;
+
+    fn is_incomplete<T>(result: IonResult<T>) -> bool {
+        matches!(result, Err(IonError::Incomplete { .. }))
+    }
+
+    #[test]
+    fn test_value_split_across_chunks() -> IonResult<()> {
+        let mut cursor = IncrementalBinaryCursor::new();
+        assert!(is_incomplete(cursor.next()));
+        cursor.push(&IVM[..2]);
+        assert!(is_incomplete(cursor.next()));
+        cursor.push(&IVM[2..]);
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+
+        // A two-byte integer whose second byte hasn't arrived yet
+        cursor.push(&[0x21]);
+        assert!(is_incomplete(cursor.next()));
+        assert!(is_incomplete(cursor.next()));
+        assert_eq!(cursor.ion_type(), None);
+        cursor.push(&[0x05]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+
+        // A list whose header is complete but whose contents aren't
+        cursor.push(&[0xB4, 0x21, 0x01]);
+        assert!(is_incomplete(cursor.next()));
+        // The cursor is still positioned on the integer
+        assert_eq!(cursor.ion_type(), Some(IonType::Integer));
+        cursor.push(&[0x21, 0x02]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(2));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+
+        assert!(is_incomplete(cursor.next()));
+        cursor.end_of_stream();
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_one_byte_at_a_time() -> IonResult<()> {
+        let mut data = IVM.to_vec();
+        // "hello" {$10: [true]} 7
+        data.extend_from_slice(&[0x85, b'h', b'e', b'l', b'l', b'o']);
+        data.extend_from_slice(&[0xD3, 0x8A, 0xB1, 0x11]);
+        data.extend_from_slice(&[0x21, 0x07]);
+
+        let mut cursor = IncrementalBinaryCursor::new();
+        let mut items = vec![];
+        for byte in data {
+            cursor.push(&[byte]);
+            loop {
+                match cursor.next() {
+                    Ok(Some(item)) => items.push(item),
+                    Err(IonError::Incomplete { .. }) => break,
+                    other => panic!("unexpected result: {:?}", other),
+                }
+            }
+        }
+        assert_eq!(
+            items,
+            vec![
+                VersionMarker(1, 0),
+                Value(IonType::String, false),
+                Value(IonType::Struct, false),
+                Value(IonType::Integer, false),
+            ]
+        );
+        assert_eq!(cursor.read_i64()?, Some(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotations_split_from_value() -> IonResult<()> {
+        let mut cursor = IncrementalBinaryCursor::new();
+        cursor.push(&IVM);
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        // $10::1, split right after its annotations
+        cursor.push(&[0xE4, 0x81, 0x8A]);
+        assert!(is_incomplete(cursor.next()));
+        cursor.push(&[0x21, 0x01]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.annotation_ids(), &[10]);
+        assert_eq!(cursor.read_i64()?, Some(1));
+
+        // At the end of the stream, a missing value is an error.
+        cursor.push(&[0xE4, 0x81, 0x8A]);
+        cursor.end_of_stream();
+        let result = cursor.next();
+        assert!(result.is_err());
+        assert!(!is_incomplete(result));
+        Ok(())
+    }
+
+    #[test]
+    fn test_consumed_bytes_are_discarded_in_bulk() -> IonResult<()> {
+        let mut cursor = IncrementalBinaryCursor::new();
+        let mut data = IVM.to_vec();
+        for value in 0..100u8 {
+            data.extend_from_slice(&[0x21, value]);
+        }
+        cursor.push(&data);
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        for value in 0..100 {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+            assert_eq!(cursor.read_i64()?, Some(value));
+            // Less than half of the buffer is read, so nothing has been discarded yet.
+            if value < 50 {
+                assert_eq!(cursor.cursor.data_source().data.len(), data.len());
+            }
+        }
+        assert!(cursor.cursor.data_source().data.len() < data.len() / 2);
+        assert!(is_incomplete(cursor.next()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncated_stream() -> IonResult<()> {
+        let mut cursor = IncrementalBinaryCursor::new();
+        cursor.push(&IVM);
+        cursor.push(&[0x83, b'a']);
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert!(is_incomplete(cursor.next()));
+        cursor.end_of_stream();
+        let result = cursor.next();
+        assert!(result.is_err());
+        assert!(!is_incomplete(result));
+        Ok(())
+    }
+}
@@ -0,0 +1,589 @@
+//! Describes the encoding of a binary Ion stream byte by byte. This is useful when debugging
+//! an encoder, since it shows exactly which bytes were written for each part of each value.
+//!
+//! An [Inspector] walks a stream and produces a [Component] for each field ID, annotations
+//! wrapper, header, and body that it encounters, as well as for Ion version markers, NOP padding,
+//! and the contents of local symbol tables. The [render] function writes those components out as
+//! a side-by-side hex and text view:
+//!
+//! ```
+//! use ion_rs::binary::inspect::render;
+//! # use ion_rs::result::IonResult;
+//! # fn main() -> IonResult<()> {
+//! let mut output = Vec::new();
+//! render(&[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x07], &mut output)?;
+//! let expected = [
+//!     "       0  e0 01 00 ea              Ion 1.0 version marker",
+//!     "       4  21                       int header, 1 byte body",
+//!     "       5  07                       7",
+//! ];
+//! assert_eq!(String::from_utf8(output).unwrap(), expected.join("\n") + "\n");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::io;
+use std::ops::Range;
+
+use crate::binary::constants::v1_0::IVM;
+use crate::binary::cursor::BinaryIonSliceCursor;
+use crate::constants::v1_0::system_symbol_ids;
+use crate::cursor::{Cursor, StreamItem};
+use crate::element::{non_null, Element, Value};
+use crate::result::IonResult;
+use crate::symbol_table::SymbolTable;
+use crate::text::writer::TextWriter;
+use crate::types::{IonType, SymbolId};
+
+// The number of bytes shown on each line of the hex view.
+const BYTES_PER_LINE: usize = 8;
+
+/// The part of a binary Ion stream that a [Component] describes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ComponentKind {
+    /// An Ion version marker (`E0 01 00 EA`).
+    VersionMarker,
+    /// NOP padding, including the field ID of any padding found inside a struct.
+    Padding,
+    /// The symbol ID of a struct field's name.
+    FieldId,
+    /// An annotations wrapper's type descriptor, lengths, and annotation symbol IDs.
+    Annotations,
+    /// A value's type descriptor byte and length, if the length is not in the type descriptor.
+    Header,
+    /// The bytes following a scalar value's header. The body of a container is made up of the
+    /// components of its child values.
+    Body,
+}
+
+/// A contiguous range of bytes in a binary Ion stream, along with a description of what it
+/// encodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component<'a> {
+    kind: ComponentKind,
+    offset: usize,
+    bytes: &'a [u8],
+    depth: usize,
+    description: String,
+}
+
+impl<'a> Component<'a> {
+    pub fn kind(&self) -> ComponentKind {
+        self.kind
+    }
+
+    /// Returns the position of the component's first byte within the stream.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of bytes in the component.
+    pub fn length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the encoded bytes of the component.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the number of containers in which the component is nested.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns a description of what the component's bytes mean, like `int header, 1 byte body`
+    /// or `"hello"`. Symbol IDs are shown along with their text, if it is known.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+// The contents of a local symbol table that the Inspector is in the middle of walking through.
+// The declared symbols take effect once the Inspector steps out of the symbol table.
+#[derive(Default)]
+struct SymbolTableDeclaration {
+    is_append: bool,
+    // The field (`imports` or `symbols`) of the list that the Inspector has stepped into, if any
+    list_field: Option<SymbolId>,
+    // Imported shared symbol tables are not resolved, so each of their symbols has unknown text.
+    imported_symbols: usize,
+    symbols: Vec<Option<String>>,
+}
+
+/// An iterator over the [Component]s of a binary Ion stream held in memory. The components are
+/// produced in the order they appear in the stream, and together they account for every byte
+/// that was read.
+///
+/// If the stream is malformed, the Inspector produces the components that it was able to read
+/// followed by an Err, and then stops.
+pub struct Inspector<'a> {
+    input: &'a [u8],
+    cursor: BinaryIonSliceCursor<'a>,
+    symbol_table: SymbolTable,
+    declaration: Option<SymbolTableDeclaration>,
+    // The offset of the first byte that has not yet been described by a Component
+    next_offset: usize,
+    components: VecDeque<Component<'a>>,
+    is_finished: bool,
+}
+
+impl<'a> Inspector<'a> {
+    pub fn new(input: &'a [u8]) -> Inspector<'a> {
+        Inspector {
+            input,
+            cursor: BinaryIonSliceCursor::new(io::Cursor::new(input)),
+            symbol_table: SymbolTable::new(),
+            declaration: None,
+            next_offset: 0,
+            components: VecDeque::new(),
+            is_finished: false,
+        }
+    }
+
+    // Advances the cursor to the next item in the stream and queues up the components that
+    // encode it.
+    fn advance(&mut self) -> IonResult<()> {
+        match self.cursor.next()? {
+            Some(StreamItem::VersionMarker(major, minor)) => {
+                let offset = self.cursor.bytes_read() - IVM.len();
+                self.symbol_table.reset();
+                self.add(
+                    ComponentKind::VersionMarker,
+                    offset..offset + IVM.len(),
+                    format!("Ion {}.{} version marker", major, minor),
+                );
+            }
+            Some(StreamItem::Value(ion_type, is_null)) => self.inspect_value(ion_type, is_null)?,
+            None => {
+                // Any padding at the end of the stream or container has already been skipped.
+                self.add_padding(self.cursor.bytes_read());
+                if self.cursor.depth() == 0 {
+                    self.is_finished = true;
+                    return Ok(());
+                }
+                self.cursor.step_out()?;
+                match self.cursor.depth() {
+                    0 => self.apply_declaration()?,
+                    1 => {
+                        if let Some(declaration) = self.declaration.as_mut() {
+                            declaration.list_field = None;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inspect_value(&mut self, ion_type: IonType, is_null: bool) -> IonResult<()> {
+        let field_id_range = self.cursor.field_id_range();
+        let annotations_range = self.cursor.annotations_range();
+        let header_range = self.cursor.header_range();
+        let value_range = self.cursor.value_range();
+
+        let start = field_id_range
+            .as_ref()
+            .or(annotations_range.as_ref())
+            .map(|range| range.start)
+            .unwrap_or(header_range.start);
+        self.add_padding(start);
+
+        if let (Some(range), Some(field_id)) = (field_id_range, self.cursor.field_id()) {
+            let description = format!("field name {}", self.describe_symbol(field_id));
+            self.add(ComponentKind::FieldId, range, description);
+        }
+        if let Some(range) = annotations_range {
+            let annotations: Vec<String> = self
+                .cursor
+                .annotation_ids()
+                .iter()
+                .map(|sid| self.describe_symbol(*sid))
+                .collect();
+            let description = format!("annotations {}", annotations.join(", "));
+            self.add(ComponentKind::Annotations, range, description);
+        }
+
+        if is_null {
+            self.track_declared_symbol(None);
+            let description = match ion_type {
+                IonType::Null => "null".to_string(),
+                _ => format!("null.{}", ion_type),
+            };
+            self.add(ComponentKind::Header, header_range, description);
+            return Ok(());
+        }
+
+        if let IonType::List | IonType::SExpression | IonType::Struct = ion_type {
+            let mut description = format!("{} header, {} byte body", ion_type, value_range.len());
+            if self.is_symbol_table(ion_type) {
+                self.declaration = Some(SymbolTableDeclaration::default());
+                description.push_str(" (local symbol table)");
+            } else {
+                self.track_declared_symbol(None);
+                if let Some(declaration) = self.declaration.as_mut() {
+                    if self.cursor.depth() == 1 {
+                        declaration.list_field = self.cursor.field_id();
+                    }
+                }
+            }
+            self.add(ComponentKind::Header, header_range.clone(), description);
+            self.cursor.step_in()?;
+            self.next_offset = header_range.end;
+            return Ok(());
+        }
+
+        let value = self.read_scalar(ion_type)?;
+        if value_range.is_empty() {
+            // The value is encoded entirely within its type descriptor byte.
+            let description = format!("{} {}", ion_type, value);
+            self.add(ComponentKind::Header, header_range, description);
+        } else {
+            let description = format!("{} header, {} byte body", ion_type, value_range.len());
+            self.add(ComponentKind::Header, header_range, description);
+            self.add(ComponentKind::Body, value_range, value);
+        }
+        Ok(())
+    }
+
+    // Reads the current scalar value and returns its text representation.
+    fn read_scalar(&mut self, ion_type: IonType) -> IonResult<String> {
+        use IonType::*;
+        let value = match ion_type {
+            Symbol => {
+                let sid = non_null(self.cursor.read_symbol_id()?)?;
+                self.track_declared_symbol(None);
+                if let Some(declaration) = self.declaration.as_mut() {
+                    if self.cursor.depth() == 1
+                        && self.cursor.field_id() == Some(system_symbol_ids::IMPORTS)
+                    {
+                        declaration.is_append = sid == system_symbol_ids::ION_SYMBOL_TABLE;
+                    }
+                }
+                return Ok(self.describe_symbol(sid));
+            }
+            String => {
+                let text = non_null(self.cursor.read_string()?)?;
+                self.track_declared_symbol(Some(text.clone()));
+                Value::String(text)
+            }
+            Integer => {
+                let integer = non_null(self.cursor.read_integer()?)?;
+                if let Some(declaration) = self.declaration.as_mut() {
+                    let is_max_id = self.cursor.depth() == 3
+                        && declaration.list_field == Some(system_symbol_ids::IMPORTS)
+                        && self.cursor.field_id() == Some(system_symbol_ids::MAX_ID);
+                    if let (true, Some(max_id)) = (is_max_id, integer.as_i64()) {
+                        declaration.imported_symbols = declaration
+                            .imported_symbols
+                            .saturating_add(max_id.max(0) as usize);
+                    }
+                }
+                Value::Integer(integer)
+            }
+            Boolean => Value::Boolean(non_null(self.cursor.read_bool()?)?),
+            Float => Value::Float(non_null(self.cursor.read_f64()?)?),
+            Decimal => Value::Decimal(non_null(self.cursor.read_decimal()?)?),
+            Timestamp => Value::Timestamp(non_null(self.cursor.read_timestamp()?)?),
+            Clob => Value::Clob(non_null(self.cursor.read_clob_bytes()?)?),
+            Blob => Value::Blob(non_null(self.cursor.read_blob_bytes()?)?),
+            Null | List | SExpression | Struct => unreachable!("{:?} is not a scalar", ion_type),
+        };
+        if ion_type != String {
+            self.track_declared_symbol(None);
+        }
+        value_text(value)
+    }
+
+    // Returns true if the current value is a local symbol table declaration.
+    fn is_symbol_table(&self, ion_type: IonType) -> bool {
+        ion_type == IonType::Struct
+            && self.cursor.depth() == 0
+            && self.cursor.annotation_ids().first() == Some(&system_symbol_ids::ION_SYMBOL_TABLE)
+    }
+
+    // If the current value is an entry in a local symbol table's `symbols` list, records the text
+    // it declares. Entries that are not strings declare a symbol with unknown text.
+    fn track_declared_symbol(&mut self, text: Option<String>) {
+        if let Some(declaration) = self.declaration.as_mut() {
+            if self.cursor.depth() == 2
+                && declaration.list_field == Some(system_symbol_ids::SYMBOLS)
+            {
+                declaration.symbols.push(text);
+            }
+        }
+    }
+
+    // Updates the symbol table with the symbols from the declaration the Inspector just stepped
+    // out of.
+    fn apply_declaration(&mut self) -> IonResult<()> {
+        let declaration = match self.declaration.take() {
+            Some(declaration) => declaration,
+            None => return Ok(()),
+        };
+        if !declaration.is_append {
+            self.symbol_table.reset();
+            self.symbol_table
+                .add_unknown_symbols(declaration.imported_symbols)?;
+        }
+        for symbol in declaration.symbols {
+            self.symbol_table.add_symbol(symbol);
+        }
+        Ok(())
+    }
+
+    fn describe_symbol(&self, sid: SymbolId) -> String {
+        match self.symbol_table.text_for(sid) {
+            Some(text) => format!("${} ({})", sid, text),
+            None => format!("${}", sid),
+        }
+    }
+
+    // Describes any bytes between the last component and `offset` as padding.
+    fn add_padding(&mut self, offset: usize) {
+        if offset > self.next_offset {
+            let range = self.next_offset..offset;
+            let description = format!("{} bytes of padding", range.len());
+            self.add(ComponentKind::Padding, range, description);
+        }
+    }
+
+    fn add(&mut self, kind: ComponentKind, range: Range<usize>, description: String) {
+        self.next_offset = range.end;
+        self.components.push_back(Component {
+            kind,
+            offset: range.start,
+            bytes: &self.input[range],
+            depth: self.cursor.depth(),
+            description,
+        });
+    }
+}
+
+impl<'a> Iterator for Inspector<'a> {
+    type Item = IonResult<Component<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(component) = self.components.pop_front() {
+                return Some(Ok(component));
+            }
+            if self.is_finished {
+                return None;
+            }
+            if let Err(error) = self.advance() {
+                self.is_finished = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// Writes a side-by-side view of each [Component] in `input`. Each line shows an offset, up to
+/// eight bytes in hex, and (on the first line of each component) the component's description,
+/// indented to match its depth.
+///
+/// If `input` is malformed, the view ends with a line describing the error, and the error is
+/// returned.
+pub fn render<W: io::Write>(input: &[u8], output: &mut W) -> IonResult<()> {
+    for component in Inspector::new(input) {
+        let component = match component {
+            Ok(component) => component,
+            Err(error) => {
+                writeln!(output, "error: {}", error)?;
+                return Err(error);
+            }
+        };
+        for (index, chunk) in component.bytes().chunks(BYTES_PER_LINE).enumerate() {
+            let offset = component.offset() + index * BYTES_PER_LINE;
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{:02x}", byte)).collect();
+            let mut line = format!("{:>8}  {:<23}  ", offset, hex.join(" "));
+            if index == 0 {
+                line.push_str(&"  ".repeat(component.depth()));
+                line.push_str(component.description());
+            }
+            writeln!(output, "{}", line.trim_end())?;
+        }
+    }
+    Ok(())
+}
+
+// Returns the Ion text representation of `value`.
+fn value_text(value: Value) -> IonResult<String> {
+    let mut text = Vec::new();
+    Element::from(value).write_to(&mut TextWriter::new(&mut text))?;
+    Ok(String::from_utf8_lossy(&text).trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binary::constants::v1_0::IVM;
+    use crate::binary::inspect::{render, Component, ComponentKind, ComponentKind::*, Inspector};
+    use crate::binary::writer::BinaryWriter;
+    use crate::result::IonResult;
+    use crate::types::IonType;
+
+    fn inspect(input: &[u8]) -> IonResult<Vec<Component<'_>>> {
+        Inspector::new(input).collect()
+    }
+
+    fn summarize<'a>(components: &'a [Component<'_>]) -> Vec<(ComponentKind, usize, &'a str)> {
+        components
+            .iter()
+            .map(|c| (c.kind(), c.depth(), c.description()))
+            .collect()
+    }
+
+    #[test]
+    fn inspect_symbol_table_and_values() -> IonResult<()> {
+        let mut input = Vec::new();
+        let mut writer = BinaryWriter::new(&mut input);
+        writer.set_annotations(&["foo"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("name");
+        writer.write_string("widget")?;
+        writer.set_field_name("tags");
+        writer.step_in(IonType::List)?;
+        writer.write_symbol("bar")?;
+        writer.write_null(IonType::Integer)?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.flush()?;
+        drop(writer);
+
+        let components = inspect(&input)?;
+        assert_eq!(
+            summarize(&components),
+            vec![
+                (VersionMarker, 0, "Ion 1.0 version marker"),
+                (Annotations, 0, "annotations $3 ($ion_symbol_table)"),
+                (
+                    Header,
+                    0,
+                    "struct header, 15 byte body (local symbol table)"
+                ),
+                (FieldId, 1, "field name $7 (symbols)"),
+                (Header, 1, "list header, 13 byte body"),
+                (Header, 2, "string header, 3 byte body"),
+                (Body, 2, "\"foo\""),
+                (Header, 2, "string header, 4 byte body"),
+                (Body, 2, "\"tags\""),
+                (Header, 2, "string header, 3 byte body"),
+                (Body, 2, "\"bar\""),
+                (Annotations, 0, "annotations $10 (foo)"),
+                (Header, 0, "struct header, 13 byte body"),
+                (FieldId, 1, "field name $4 (name)"),
+                (Header, 1, "string header, 6 byte body"),
+                (Body, 1, "\"widget\""),
+                (FieldId, 1, "field name $11 (tags)"),
+                (Header, 1, "list header, 3 byte body"),
+                (Header, 2, "symbol header, 1 byte body"),
+                (Body, 2, "$12 (bar)"),
+                (Header, 2, "null.int"),
+            ]
+        );
+        assert_components_cover(&components, &input);
+        Ok(())
+    }
+
+    // Asserts that, together, the components account for every byte of the input in order.
+    fn assert_components_cover(components: &[Component<'_>], input: &[u8]) {
+        let mut offset = 0;
+        for component in components {
+            assert_eq!(component.offset(), offset);
+            assert_eq!(
+                component.bytes(),
+                &input[offset..offset + component.length()]
+            );
+            offset += component.length();
+        }
+        assert_eq!(offset, input.len());
+    }
+
+    #[test]
+    fn inspect_unannotated_field_after_annotated_field() -> IonResult<()> {
+        let mut input = Vec::new();
+        let mut writer = BinaryWriter::new(&mut input);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("a");
+        writer.set_annotations(&["foo"]);
+        writer.step_in(IonType::List)?;
+        writer.write_i64(1)?;
+        writer.step_out()?;
+        writer.set_field_name("b");
+        writer.write_symbol("$ion_symbol_table")?;
+        writer.step_out()?;
+        writer.flush()?;
+        drop(writer);
+
+        let components = inspect(&input)?;
+        let field_b = components
+            .iter()
+            .find(|c| c.kind() == FieldId && c.description().ends_with("(b)"))
+            .expect("field b was not found");
+        assert_eq!(field_b.bytes(), &[0x80 | 12]);
+        assert_components_cover(&components, &input);
+        Ok(())
+    }
+
+    #[test]
+    fn inspect_padding() -> IonResult<()> {
+        let mut input = IVM.to_vec();
+        // A one-byte NOP pad, a four-byte NOP pad, and then a struct containing a three-byte NOP
+        // pad (with a field ID) and `name: 0`.
+        input.extend_from_slice(&[0x00, 0x03, 0xFF, 0xFF, 0xFF]);
+        input.extend_from_slice(&[0xD5, 0x81, 0x01, 0xFF, 0x84, 0x20]);
+        let components = inspect(&input)?;
+        assert_eq!(
+            summarize(&components),
+            vec![
+                (VersionMarker, 0, "Ion 1.0 version marker"),
+                (Padding, 0, "5 bytes of padding"),
+                (Header, 0, "struct header, 5 byte body"),
+                (Padding, 1, "3 bytes of padding"),
+                (FieldId, 1, "field name $4 (name)"),
+                (Header, 1, "int 0"),
+            ]
+        );
+        assert_eq!(components[3].offset(), 10);
+        assert_eq!(components[3].bytes(), &[0x81, 0x01, 0xFF]);
+        Ok(())
+    }
+
+    #[test]
+    fn render_malformed_input() {
+        let mut input = IVM.to_vec();
+        // A string whose header says it has 5 bytes, but only 2 follow.
+        input.extend_from_slice(&[0x85, b'h', b'i']);
+        let mut output = Vec::new();
+        assert!(render(&input, &mut output).is_err());
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("Ion 1.0 version marker"));
+        assert!(lines[1].starts_with("error: "));
+    }
+
+    #[test]
+    fn render_wraps_long_components() -> IonResult<()> {
+        let mut input = IVM.to_vec();
+        input.push(0x8A);
+        input.extend_from_slice(b"0123456789");
+        let mut output = Vec::new();
+        render(&input, &mut output)?;
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            [
+                "       0  e0 01 00 ea              Ion 1.0 version marker",
+                "       4  8a                       string header, 10 byte body",
+                "       5  30 31 32 33 34 35 36 37  \"0123456789\"",
+                "      13  38 39",
+                "",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+}
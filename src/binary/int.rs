@@ -1,10 +1,12 @@
 use std::mem;
 
+use num_bigint::{BigInt, Sign};
+
 use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
 
 type IntStorage = i64;
-const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<IntStorage>();
+pub(crate) const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<IntStorage>();
 
 /// Represents a fixed-length signed integer. See the
 /// [UInt and Int Fields](http://amzn.github.io/ion-docs/docs/binary.html#uint-and-int-fields)
@@ -13,6 +15,9 @@ const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<IntStorage>();
 pub struct Int {
     size_in_bytes: usize,
     value: IntStorage,
+    // Negative zero cannot be represented by `value`, but is meaningful in some contexts
+    // (e.g. a decimal's coefficient).
+    is_negative: bool,
 }
 
 impl Int {
@@ -22,6 +27,7 @@ impl Int {
             return Ok(Int {
                 size_in_bytes: 0,
                 value: 0,
+                is_negative: false,
             });
         } else if length > MAX_INT_SIZE_IN_BYTES {
             return decoding_error(format!(
@@ -52,15 +58,41 @@ impl Int {
         Ok(Int {
             size_in_bytes: length,
             value: magnitude * sign,
+            is_negative: sign < 0,
         })
     }
 
+    /// Interprets all of the provided bytes as a single Int, returning its value as a BigInt.
+    /// Unlike `read`, this can decode Ints of any size.
+    pub fn big_int_from_bytes(bytes: &[u8]) -> BigInt {
+        let (first_byte, remaining_bytes) = match bytes.split_first() {
+            Some(split) => split,
+            None => return BigInt::from(0),
+        };
+        let sign = if first_byte & 0b1000_0000 == 0 {
+            Sign::Plus
+        } else {
+            Sign::Minus
+        };
+        let mut magnitude = Vec::with_capacity(bytes.len());
+        magnitude.push(first_byte & 0b0111_1111);
+        magnitude.extend_from_slice(remaining_bytes);
+        BigInt::from_bytes_be(sign, &magnitude)
+    }
+
     /// Returns the value of the signed integer.
     #[inline(always)]
     pub fn value(&self) -> IntStorage {
         self.value
     }
 
+    /// Returns true if the encoded value was negative zero, which cannot be distinguished from
+    /// zero using `value()`.
+    #[inline(always)]
+    pub fn is_negative_zero(&self) -> bool {
+        self.is_negative && self.value == 0
+    }
+
     /// Returns the number of bytes that were read from the data source to construct this
     /// signed integer.
     #[inline(always)]
@@ -72,7 +104,9 @@ impl Int {
 #[cfg(test)]
 mod tests {
     use super::Int;
+    use num_bigint::BigInt;
     use std::io::Cursor;
+    use std::str::FromStr;
 
     const READ_ERROR_MESSAGE: &str = "Failed to read an Int from the provided cursor.";
 
@@ -98,6 +132,7 @@ mod tests {
         let int = Int::read(&mut Cursor::new(data), data.len()).expect(READ_ERROR_MESSAGE);
         assert_eq!(int.size_in_bytes(), 1);
         assert_eq!(int.value(), 0);
+        assert!(int.is_negative_zero());
     }
 
     #[test]
@@ -106,6 +141,7 @@ mod tests {
         let int = Int::read(&mut Cursor::new(data), data.len()).expect(READ_ERROR_MESSAGE);
         assert_eq!(int.size_in_bytes(), 1);
         assert_eq!(int.value(), 0);
+        assert!(!int.is_negative_zero());
     }
 
     #[test]
@@ -138,4 +174,15 @@ mod tests {
         let _int = Int::read(&mut Cursor::new(data), data.len())
             .expect_err("This should have failed due to overflow.");
     }
+
+    #[test]
+    fn test_big_int_from_bytes() {
+        let data = &[0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(
+            Int::big_int_from_bytes(data),
+            BigInt::from_str("-4722366482869645213695").unwrap()
+        );
+        assert_eq!(Int::big_int_from_bytes(&[0x01, 0x00]), BigInt::from(256));
+        assert_eq!(Int::big_int_from_bytes(&[]), BigInt::from(0));
+    }
 }
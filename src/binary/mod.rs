@@ -1,14 +1,19 @@
-//! This module provides the necessary structures and logic to read values from a binary Ion
-//! data stream.
+//! This module provides the necessary structures and logic to read values from and write values
+//! to a binary Ion data stream.
 
+#[cfg(feature = "async")]
+pub(crate) mod async_cursor;
 pub(crate) mod constants;
 pub(crate) mod cursor;
 mod header;
+pub(crate) mod incremental;
+pub mod inspect;
 mod int;
 mod nibbles;
 mod type_code;
 mod uint;
 mod var_int;
 mod var_uint;
+pub mod writer;
 
 pub(crate) use type_code::IonTypeCode;
@@ -5,7 +5,7 @@ use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
 
 type UIntStorage = u64;
-const MAX_UINT_SIZE_IN_BYTES: usize = mem::size_of::<UIntStorage>();
+pub(crate) const MAX_UINT_SIZE_IN_BYTES: usize = mem::size_of::<UIntStorage>();
 
 /// Represents a fixed-length unsigned integer. See the
 /// [UInt and Int Fields](http://amzn.github.io/ion-docs/docs/binary.html#uint-and-int-fields)
@@ -1,5 +1,6 @@
 use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
+use std::io::Write;
 use std::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
@@ -13,13 +14,18 @@ const BITS_PER_ENCODED_BYTE: usize = 7;
 const STORAGE_SIZE_IN_BITS: usize = mem::size_of::<VarIntStorage>() * 8;
 const MAX_ENCODED_SIZE_IN_BYTES: usize = STORAGE_SIZE_IN_BITS / BITS_PER_ENCODED_BYTE;
 
+const LOWER_6_BITMASK: u8 = 0b0011_1111;
 const LOWER_7_BITMASK: u8 = 0b0111_1111;
 const HIGHEST_BIT_VALUE: u8 = 0b1000_0000;
+const SIGN_BIT_VALUE: u8 = 0b0100_0000;
 
 #[derive(Debug)]
 pub struct VarInt {
     size_in_bytes: usize,
     value: VarIntStorage,
+    // Negative zero cannot be represented by `value`, but is meaningful in some contexts
+    // (e.g. a timestamp's unknown offset).
+    is_negative: bool,
 }
 
 /// Represents a variable-length signed integer. See the
@@ -42,6 +48,7 @@ impl VarInt {
             return Ok(VarInt {
                 size_in_bytes: 1,
                 value: magnitude * sign,
+                is_negative: !is_positive,
             });
         }
 
@@ -64,15 +71,51 @@ impl VarInt {
         Ok(VarInt {
             size_in_bytes: encoded_size_in_bytes,
             value: magnitude * sign,
+            is_negative: !is_positive,
         })
     }
 
+    /// Encodes the provided `value` as a VarInt and writes it to the provided `sink`.
+    pub fn write_var_int<W: Write>(sink: &mut W, value: i64) -> IonResult<()> {
+        // An i64's magnitude needs at most 10 bytes: 6 bits in the first byte and 7 in the rest
+        let mut buffer = [0u8; 10];
+        let mut first_byte = buffer.len() - 1;
+        // wrapping_abs() leaves i64::MIN unchanged, but its bits are the correct u64 magnitude.
+        let mut magnitude = value.wrapping_abs() as u64;
+        while magnitude > LOWER_6_BITMASK as u64 {
+            buffer[first_byte] = magnitude as u8 & LOWER_7_BITMASK;
+            magnitude >>= BITS_PER_ENCODED_BYTE;
+            first_byte -= 1;
+        }
+        buffer[first_byte] = magnitude as u8;
+        if value < 0 {
+            buffer[first_byte] |= SIGN_BIT_VALUE;
+        }
+        // The last byte has its end flag set
+        buffer[buffer.len() - 1] |= HIGHEST_BIT_VALUE;
+        sink.write_all(&buffer[first_byte..])?;
+        Ok(())
+    }
+
+    /// Writes a VarInt representing negative zero to the provided `sink`.
+    pub fn write_negative_zero<W: Write>(sink: &mut W) -> IonResult<()> {
+        sink.write_all(&[HIGHEST_BIT_VALUE | SIGN_BIT_VALUE])?;
+        Ok(())
+    }
+
     /// Returns the value of the signed integer
     #[inline(always)]
     pub fn value(&self) -> VarIntStorage {
         self.value
     }
 
+    /// Returns true if the encoded value was negative zero, which cannot be distinguished from
+    /// zero using `value()`.
+    #[inline(always)]
+    pub fn is_negative_zero(&self) -> bool {
+        self.is_negative && self.value == 0
+    }
+
     /// Returns the number of bytes that were read from the data source to construct this
     /// signed integer
     #[inline(always)]
@@ -87,6 +130,7 @@ mod tests {
     use std::io::{BufReader, Cursor};
 
     const ERROR_MESSAGE: &'static str = "Failed to read a VarUInt from the provided data.";
+    const WRITE_ERROR_MESSAGE: &str = "Writing a VarInt to the provided sink failed.";
 
     #[test]
     fn test_read_negative_var_int() {
@@ -155,4 +199,47 @@ mod tests {
         ]))
         .expect_err("This should have failed due to overflow.");
     }
+
+    fn var_int_encoding_test(value: i64, expected_encoding: &[u8]) {
+        let mut buffer: Vec<u8> = vec![];
+        VarInt::write_var_int(&mut buffer, value).expect(WRITE_ERROR_MESSAGE);
+        assert_eq!(buffer.as_slice(), expected_encoding);
+    }
+
+    #[test]
+    fn test_write_var_int_zero() {
+        var_int_encoding_test(0, &[0b1000_0000]);
+    }
+
+    #[test]
+    fn test_write_var_int_single_byte_values() {
+        var_int_encoding_test(17, &[0b1001_0001]);
+        var_int_encoding_test(-17, &[0b1101_0001]);
+        var_int_encoding_test(63, &[0b1011_1111]);
+    }
+
+    #[test]
+    fn test_write_var_int_two_byte_values() {
+        var_int_encoding_test(64, &[0b0000_0000, 0b1100_0000]);
+        var_int_encoding_test(8_191, &[0b0011_1111, 0b1111_1111]);
+        var_int_encoding_test(-8_191, &[0b0111_1111, 0b1111_1111]);
+    }
+
+    #[test]
+    fn test_write_var_int_three_byte_values() {
+        var_int_encoding_test(935_809, &[0b0011_1001, 0b0000_1111, 0b1000_0001]);
+        var_int_encoding_test(-935_809, &[0b0111_1001, 0b0000_1111, 0b1000_0001]);
+    }
+
+    #[test]
+    fn test_negative_zero() {
+        let mut buffer: Vec<u8> = vec![];
+        VarInt::write_negative_zero(&mut buffer).expect(WRITE_ERROR_MESSAGE);
+        assert_eq!(buffer.as_slice(), &[0b1100_0000]);
+        let var_int = VarInt::read(&mut Cursor::new(buffer)).expect(ERROR_MESSAGE);
+        assert_eq!(var_int.value(), 0);
+        assert!(var_int.is_negative_zero());
+        let var_int = VarInt::read(&mut Cursor::new(&[0b1000_0000])).expect(ERROR_MESSAGE);
+        assert!(!var_int.is_negative_zero());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
+use std::io::Write;
 use std::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
@@ -63,6 +64,24 @@ impl VarUInt {
         })
     }
 
+    /// Encodes the provided `magnitude` as a VarUInt and writes it to the provided `sink`.
+    pub fn write_var_uint<W: Write>(sink: &mut W, magnitude: u64) -> IonResult<()> {
+        // A u64 needs at most 10 bytes of 7-bit groups
+        let mut buffer = [0u8; 10];
+        let mut first_byte = buffer.len() - 1;
+        let mut remaining = magnitude;
+        // The last byte has its end flag set
+        buffer[first_byte] = HIGHEST_BIT_VALUE | (remaining as u8 & LOWER_7_BITMASK);
+        remaining >>= BITS_PER_ENCODED_BYTE;
+        while remaining > 0 {
+            first_byte -= 1;
+            buffer[first_byte] = remaining as u8 & LOWER_7_BITMASK;
+            remaining >>= BITS_PER_ENCODED_BYTE;
+        }
+        sink.write_all(&buffer[first_byte..])?;
+        Ok(())
+    }
+
     /// Returns the magnitude of the unsigned integer
     #[inline(always)]
     pub fn value(&self) -> VarUIntStorage {
@@ -83,6 +102,7 @@ mod tests {
     use std::io::{BufReader, Cursor};
 
     const ERROR_MESSAGE: &'static str = "Failed to read a VarUInt from the provided data.";
+    const WRITE_ERROR_MESSAGE: &str = "Writing a VarUInt to the provided sink failed.";
 
     #[test]
     fn test_read_var_uint() {
@@ -135,4 +155,40 @@ mod tests {
         ]))
         .expect_err("This should have failed due to overflow.");
     }
+
+    fn var_uint_encoding_test(value: u64, expected_encoding: &[u8]) {
+        let mut buffer: Vec<u8> = vec![];
+        VarUInt::write_var_uint(&mut buffer, value).expect(WRITE_ERROR_MESSAGE);
+        assert_eq!(buffer.as_slice(), expected_encoding);
+    }
+
+    #[test]
+    fn test_write_var_uint_zero() {
+        var_uint_encoding_test(0, &[0b1000_0000]);
+    }
+
+    #[test]
+    fn test_write_var_uint_single_byte_values() {
+        var_uint_encoding_test(8, &[0b1000_1000]);
+        var_uint_encoding_test(127, &[0b1111_1111]);
+    }
+
+    #[test]
+    fn test_write_var_uint_two_byte_values() {
+        var_uint_encoding_test(128, &[0b0000_0001, 0b1000_0000]);
+        var_uint_encoding_test(16_383, &[0b0111_1111, 0b1111_1111]);
+    }
+
+    #[test]
+    fn test_write_var_uint_three_byte_value() {
+        var_uint_encoding_test(1_984_385, &[0b0111_1001, 0b0000_1111, 0b1000_0001]);
+    }
+
+    #[test]
+    fn test_write_var_uint_round_trip() {
+        let mut buffer: Vec<u8> = vec![];
+        VarUInt::write_var_uint(&mut buffer, 987_654_321).expect(WRITE_ERROR_MESSAGE);
+        let var_uint = VarUInt::read(&mut Cursor::new(buffer)).expect(ERROR_MESSAGE);
+        assert_eq!(var_uint.value(), 987_654_321);
+    }
 }
@@ -0,0 +1,961 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::mem;
+
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Datelike, FixedOffset, Timelike};
+use num_bigint::{BigInt, Sign};
+
+use crate::binary::constants::v1_0::{length_codes, IVM};
+use crate::binary::uint::UInt;
+use crate::binary::var_int::VarInt;
+use crate::binary::var_uint::VarUInt;
+use crate::binary::IonTypeCode;
+use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
+use crate::result::{illegal_operation, IonResult};
+use crate::symbol_table::SymbolTable;
+use crate::types::{Decimal, Integer, Precision, SymbolId, SymbolToken, Timestamp};
+use crate::IonType;
+
+// A container that the writer has stepped into but not yet stepped out of. Its contents are
+// encoded into a separate buffer because the container's header (which includes the length of
+// its contents) cannot be written until the container is complete.
+struct EncodedContainer {
+    ion_type: IonType,
+    field_id: Option<SymbolId>,
+    annotations: Vec<SymbolId>,
+    contents: Vec<u8>,
+}
+
+/// Writes binary Ion to the provided io::Write implementation.
+///
+/// Field names, annotations, and symbol values are interned in a local symbol table as they are
+/// written. Because the symbol table must appear in the stream before any of the values that
+/// refer to it, encoded top-level values are held in memory until `flush` is called. At that
+/// point, the writer emits an Ion version marker (once per stream), a `$ion_symbol_table` struct
+/// declaring any newly interned symbols, and the buffered values.
+///
+/// Dropping the writer flushes any completed top-level values, but errors cannot be reported
+/// from `drop`; call `flush` to find out whether they were written successfully.
+pub struct BinaryWriter<W: Write> {
+    output: W,
+    symbol_table: SymbolTable,
+    // The number of symbols in `symbol_table` that have already been declared in the output
+    num_declared_symbols: usize,
+    has_written_ivm: bool,
+    // Encoded top-level values that have not yet been written to `output`
+    buffer: Vec<u8>,
+    annotations: Vec<SymbolId>,
+    field_name: Option<SymbolToken>,
+    containers: Vec<EncodedContainer>,
+    // Maps the symbol IDs of tokens with unknown text to the symbols with unknown text that were
+    // declared for them in `symbol_table`
+    unknown_symbol_ids: HashMap<SymbolId, SymbolId>,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Constructs a new instance of BinaryWriter that writes values to the provided io::Write
+    /// implementation.
+    pub fn new(sink: W) -> BinaryWriter<W> {
+        BinaryWriter {
+            output: sink,
+            symbol_table: SymbolTable::new(),
+            num_declared_symbols: SYSTEM_SYMBOLS.len(),
+            has_written_ivm: false,
+            buffer: vec![],
+            annotations: vec![],
+            field_name: None,
+            unknown_symbol_ids: HashMap::new(),
+            containers: vec![],
+        }
+    }
+
+    /// Returns a reference to the underlying io::Write implementation.
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    /// Writes any completed top-level values to the underlying io::Write implementation, preceded
+    /// by the symbol table declarations they depend on. Values inside of a container that has not
+    /// been stepped out of yet remain buffered.
+    pub fn flush(&mut self) -> IonResult<()> {
+        if !self.has_written_ivm {
+            self.output.write_all(&IVM)?;
+            self.has_written_ivm = true;
+        }
+        if self.symbol_table.len() > self.num_declared_symbols {
+            self.write_local_symbol_table()?;
+        }
+        self.output.write_all(&self.buffer)?;
+        self.buffer.clear();
+        self.output.flush()?;
+        Ok(())
+    }
+
+    // Writes a `$ion_symbol_table` struct declaring every symbol that has been interned since the
+    // last time a symbol table was written.
+    fn write_local_symbol_table(&mut self) -> IonResult<()> {
+        let mut symbols = vec![];
+        for text in self.symbol_table.symbols_tail(self.num_declared_symbols) {
+            match text {
+                Some(text) => encode_bytes(&mut symbols, IonTypeCode::String, text.as_bytes())?,
+                // A symbol with unknown text is declared using a non-string value.
+                None => symbols.push(header_byte(
+                    IonTypeCode::NullOrWhitespace,
+                    length_codes::NULL,
+                )),
+            }
+        }
+
+        let mut fields = vec![];
+        if self.num_declared_symbols > SYSTEM_SYMBOLS.len() {
+            // Symbols have already been declared in this stream; append to them.
+            VarUInt::write_var_uint(&mut fields, system_symbol_ids::IMPORTS as u64)?;
+            let mut imports = vec![];
+            UInt::write_uint(&mut imports, system_symbol_ids::ION_SYMBOL_TABLE as u64)?;
+            encode_bytes(&mut fields, IonTypeCode::Symbol, &imports)?;
+        }
+        VarUInt::write_var_uint(&mut fields, system_symbol_ids::SYMBOLS as u64)?;
+        encode_bytes(&mut fields, IonTypeCode::List, &symbols)?;
+
+        let mut symbol_table = vec![];
+        encode_bytes(&mut symbol_table, IonTypeCode::Struct, &fields)?;
+        encode_annotated(
+            &mut self.output,
+            &[system_symbol_ids::ION_SYMBOL_TABLE],
+            &symbol_table,
+        )?;
+        self.num_declared_symbols = self.symbol_table.len();
+        Ok(())
+    }
+
+    /// Sets the current field name to `name`. If the BinaryWriter is currently positioned inside
+    /// of a struct, the field name will be written before the next value. Otherwise, it will be
+    /// ignored.
+    pub fn set_field_name(&mut self, name: &str) {
+        self.field_name = Some(SymbolToken::with_text(name));
+    }
+
+    /// Like [BinaryWriter::set_field_name], but accepts a SymbolToken whose text may be unknown.
+    /// See [BinaryWriter::write_symbol_token].
+    pub fn set_field_name_token(&mut self, name: &SymbolToken) {
+        self.field_name = Some(name.clone());
+    }
+
+    /// Sets a list of annotations that will be applied to the next value that is written.
+    pub fn set_annotations(&mut self, annotations: &[&str]) {
+        for annotation in annotations {
+            let sid = self.symbol_table.intern(annotation.to_string());
+            self.annotations.push(sid);
+        }
+    }
+
+    /// Like [BinaryWriter::set_annotations], but accepts SymbolTokens whose text may be unknown.
+    /// See [BinaryWriter::write_symbol_token].
+    pub fn set_annotation_tokens(&mut self, annotations: &[SymbolToken]) {
+        for annotation in annotations {
+            let sid = self.symbol_id_for(annotation);
+            self.annotations.push(sid);
+        }
+    }
+
+    // Returns the symbol ID that represents `token` in the local symbol table, interning its text
+    // if it is known. Symbol zero has unknown text in every symbol table, so it is used as-is.
+    // Any other token with unknown text is given a new symbol with unknown text, which is shared
+    // by later tokens with the same symbol ID.
+    fn symbol_id_for(&mut self, token: &SymbolToken) -> SymbolId {
+        if let Some(text) = token.text() {
+            return self.symbol_table.intern(text.to_string());
+        }
+        match token.local_sid() {
+            None | Some(0) => 0,
+            Some(sid) => {
+                let symbol_table = &mut self.symbol_table;
+                *self
+                    .unknown_symbol_ids
+                    .entry(sid)
+                    .or_insert_with(|| symbol_table.add_symbol(None))
+            }
+        }
+    }
+
+    /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
+    /// `step_in` will return an Err(IllegalOperation).
+    pub fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        use IonType::*;
+        match ion_type {
+            Struct | List | SExpression => {}
+            _ => return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type)),
+        }
+        let field_id = self.take_field_id()?;
+        let annotations = mem::take(&mut self.annotations);
+        self.containers.push(EncodedContainer {
+            ion_type,
+            field_id,
+            annotations,
+            contents: vec![],
+        });
+        Ok(())
+    }
+
+    /// Returns true if the BinaryWriter is currently positioned within a Struct.
+    pub fn is_in_struct(&self) -> bool {
+        if let Some(EncodedContainer {
+            ion_type: IonType::Struct,
+            ..
+        }) = self.containers.last()
+        {
+            return true;
+        }
+        false
+    }
+
+    /// Completes the current container. If the BinaryWriter is not currently positioned inside a
+    /// container, `step_out` will return an Err(IllegalOperation).
+    pub fn step_out(&mut self) -> IonResult<()> {
+        use IonType::*;
+        let container = match self.containers.pop() {
+            Some(container) => container,
+            None => return illegal_operation("Cannot step out of the top level."),
+        };
+        let type_code = match container.ion_type {
+            Struct => IonTypeCode::Struct,
+            List => IonTypeCode::List,
+            SExpression => IonTypeCode::SExpression,
+            scalar => unreachable!("Inside a non-container type: {:?}", scalar),
+        };
+        let mut value = vec![];
+        encode_bytes(&mut value, type_code, &container.contents)?;
+        self.write_encoded_value(container.field_id, &container.annotations, &value)
+    }
+
+    // If the writer is inside a struct, interns the pending field name and returns its symbol ID.
+    // Field names set outside of a struct are discarded.
+    fn take_field_id(&mut self) -> IonResult<Option<SymbolId>> {
+        let field_name = self.field_name.take();
+        if !self.is_in_struct() {
+            return Ok(None);
+        }
+        match field_name {
+            Some(name) => Ok(Some(self.symbol_id_for(&name))),
+            None => illegal_operation("Values inside a struct must have a field name."),
+        }
+    }
+
+    // Writes the field ID (if any), the annotations wrapper (if any), and the encoded value to the
+    // current container or, at the top level, to the buffer of values waiting to be flushed.
+    fn write_encoded_value(
+        &mut self,
+        field_id: Option<SymbolId>,
+        annotations: &[SymbolId],
+        value: &[u8],
+    ) -> IonResult<()> {
+        let output = match self.containers.last_mut() {
+            Some(container) => &mut container.contents,
+            None => &mut self.buffer,
+        };
+        if let Some(field_id) = field_id {
+            VarUInt::write_var_uint(output, field_id as u64)?;
+        }
+        if annotations.is_empty() {
+            output.extend_from_slice(value);
+            return Ok(());
+        }
+        encode_annotated(output, annotations, value)
+    }
+
+    // Writes:
+    // * the field name (if any)
+    // * the annotations (if any)
+    // * the value encoded by the `scalar_encoder` closure
+    fn write_scalar<F>(&mut self, scalar_encoder: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Vec<u8>) -> IonResult<()>,
+    {
+        let field_id = self.take_field_id()?;
+        let annotations = mem::take(&mut self.annotations);
+        let mut value = vec![];
+        scalar_encoder(&mut value)?;
+        self.write_encoded_value(field_id, &annotations, &value)
+    }
+
+    /// Writes an Ion null of the specified type.
+    pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        use IonType::*;
+        let type_code = match ion_type {
+            Null => IonTypeCode::NullOrWhitespace,
+            Boolean => IonTypeCode::Boolean,
+            Integer => IonTypeCode::PositiveInteger,
+            Float => IonTypeCode::Float,
+            Decimal => IonTypeCode::Decimal,
+            Timestamp => IonTypeCode::Timestamp,
+            Symbol => IonTypeCode::Symbol,
+            String => IonTypeCode::String,
+            Clob => IonTypeCode::Clob,
+            Blob => IonTypeCode::Blob,
+            List => IonTypeCode::List,
+            SExpression => IonTypeCode::SExpression,
+            Struct => IonTypeCode::Struct,
+        };
+        self.write_scalar(|output| {
+            output.push(header_byte(type_code, length_codes::NULL));
+            Ok(())
+        })
+    }
+
+    /// Writes the provided bool value as an Ion boolean.
+    pub fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        self.write_scalar(|output| {
+            output.push(header_byte(IonTypeCode::Boolean, value as u8));
+            Ok(())
+        })
+    }
+
+    /// Writes the provided i64 value as an Ion integer.
+    pub fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        self.write_scalar(|output| {
+            let type_code = if value < 0 {
+                IonTypeCode::NegativeInteger
+            } else {
+                IonTypeCode::PositiveInteger
+            };
+            let mut magnitude = vec![];
+            // wrapping_abs() leaves i64::MIN unchanged, but its bits are the correct u64 magnitude.
+            UInt::write_uint(&mut magnitude, value.wrapping_abs() as u64)?;
+            encode_bytes(output, type_code, &magnitude)
+        })
+    }
+
+    /// Writes the provided Integer value as an Ion integer.
+    pub fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        match value {
+            Integer::I64(value) => self.write_i64(*value),
+            Integer::BigInt(value) => self.write_big_int(value),
+        }
+    }
+
+    /// Writes the provided BigInt value as an Ion integer.
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        self.write_scalar(|output| {
+            let type_code = if value.sign() == Sign::Minus {
+                IonTypeCode::NegativeInteger
+            } else {
+                IonTypeCode::PositiveInteger
+            };
+            // Zero is encoded with an empty magnitude.
+            let magnitude = if value.is_zero() {
+                vec![]
+            } else {
+                value.magnitude().to_bytes_be()
+            };
+            encode_bytes(output, type_code, &magnitude)
+        })
+    }
+
+    /// Writes the provided f32 value as a 4-byte Ion float.
+    pub fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.write_scalar(|output| {
+            // Positive zero can be written as a zero-length float; negative zero cannot.
+            if value == 0.0f32 && value.is_sign_positive() {
+                output.push(header_byte(IonTypeCode::Float, 0));
+                return Ok(());
+            }
+            encode_bytes(output, IonTypeCode::Float, &value.to_be_bytes())
+        })
+    }
+
+    /// Writes the provided f64 value as an Ion float.
+    pub fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        self.write_scalar(|output| {
+            // Positive zero can be written as a zero-length float; negative zero cannot.
+            if value == 0.0f64 && value.is_sign_positive() {
+                output.push(header_byte(IonTypeCode::Float, 0));
+                return Ok(());
+            }
+            encode_bytes(output, IonTypeCode::Float, &value.to_be_bytes())
+        })
+    }
+
+    /// Writes the provided Decimal value as an Ion decimal, preserving its precision and the
+    /// sign of a zero coefficient.
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        self.write_scalar(|output| {
+            let mut representation = vec![];
+            encode_decimal_representation(&mut representation, value)?;
+            encode_bytes(output, IonTypeCode::Decimal, &representation)
+        })
+    }
+
+    /// Writes the provided BigDecimal value as an Ion decimal.
+    pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        self.write_decimal(&Decimal::from(value.clone()))
+    }
+
+    /// Writes the provided DateTime value as an Ion timestamp with second precision, including
+    /// fractional seconds if the DateTime has a non-zero nanoseconds field.
+    pub fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        self.write_timestamp(&Timestamp::from(*value))
+    }
+
+    /// Writes the provided Timestamp value as an Ion timestamp, preserving its precision,
+    /// fractional seconds, and offset.
+    pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        self.write_scalar(|output| {
+            let mut representation = vec![];
+            encode_timestamp_representation(&mut representation, value)?;
+            encode_bytes(output, IonTypeCode::Timestamp, &representation)
+        })
+    }
+
+    /// Writes the provided &str value as an Ion symbol.
+    pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let sid = self.symbol_table.intern(value.as_ref().to_string());
+        self.write_symbol_id(sid)
+    }
+
+    /// Writes the provided SymbolToken as an Ion symbol. If the token's text is unknown, the
+    /// local symbol table declares a symbol with unknown text for it; tokens with the same
+    /// symbol ID share that symbol. A token for `$0` (or with neither text nor a symbol ID) is
+    /// written as `$0`.
+    pub fn write_symbol_token(&mut self, value: &SymbolToken) -> IonResult<()> {
+        let sid = self.symbol_id_for(value);
+        self.write_symbol_id(sid)
+    }
+
+    fn write_symbol_id(&mut self, sid: SymbolId) -> IonResult<()> {
+        self.write_scalar(|output| {
+            let mut representation = vec![];
+            UInt::write_uint(&mut representation, sid as u64)?;
+            encode_bytes(output, IonTypeCode::Symbol, &representation)
+        })
+    }
+
+    /// Writes the provided &str value as an Ion string.
+    pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        self.write_scalar(|output| {
+            encode_bytes(output, IonTypeCode::String, value.as_ref().as_bytes())
+        })
+    }
+
+    /// Writes the provided byte array slice as an Ion blob.
+    pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        self.write_scalar(|output| encode_bytes(output, IonTypeCode::Blob, value))
+    }
+
+    /// Writes the provided byte array slice as an Ion clob.
+    pub fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        self.write_scalar(|output| encode_bytes(output, IonTypeCode::Clob, value))
+    }
+}
+
+impl<W: Write> Drop for BinaryWriter<W> {
+    fn drop(&mut self) {
+        if !self.buffer.is_empty() {
+            // Best effort; errors are reported by calling `flush` before the writer is dropped.
+            let _ = self.flush();
+        }
+    }
+}
+
+// Combines a type code and a length code into a value's header byte.
+fn header_byte(type_code: IonTypeCode, length_code: u8) -> u8 {
+    (type_code as u8) << 4 | length_code
+}
+
+// Writes a header for a value of `type_code` whose representation is `representation`, followed by
+// the representation itself.
+fn encode_bytes<W: Write>(
+    output: &mut W,
+    type_code: IonTypeCode,
+    representation: &[u8],
+) -> IonResult<()> {
+    let length = representation.len();
+    if length < length_codes::VAR_UINT as usize {
+        output.write_all(&[header_byte(type_code, length as u8)])?;
+    } else {
+        output.write_all(&[header_byte(type_code, length_codes::VAR_UINT)])?;
+        VarUInt::write_var_uint(output, length as u64)?;
+    }
+    output.write_all(representation)?;
+    Ok(())
+}
+
+// Writes an annotations wrapper containing the provided annotation symbol IDs and encoded value.
+fn encode_annotated<W: Write>(
+    output: &mut W,
+    annotations: &[SymbolId],
+    value: &[u8],
+) -> IonResult<()> {
+    let mut annotation_sids = vec![];
+    for sid in annotations {
+        VarUInt::write_var_uint(&mut annotation_sids, *sid as u64)?;
+    }
+    let mut wrapper = vec![];
+    VarUInt::write_var_uint(&mut wrapper, annotation_sids.len() as u64)?;
+    wrapper.extend_from_slice(&annotation_sids);
+    wrapper.extend_from_slice(value);
+    encode_bytes(output, IonTypeCode::Annotation, &wrapper)
+}
+
+// Writes the offset (VarInt), the UTC date and time fields (VarUInt) up to the timestamp's
+// precision, and the fractional seconds (if any) of a timestamp.
+pub(crate) fn encode_timestamp_representation(
+    output: &mut Vec<u8>,
+    value: &Timestamp,
+) -> IonResult<()> {
+    match value.offset() {
+        Some(offset_minutes) => VarInt::write_var_int(output, offset_minutes as i64)?,
+        // An unknown offset is encoded as negative zero.
+        None => VarInt::write_negative_zero(output)?,
+    }
+    // Binary timestamps store their fields in UTC.
+    let utc = value.utc_date_time();
+    let precision = value.precision();
+    VarUInt::write_var_uint(output, utc.year() as u64)?;
+    if precision >= Precision::Month {
+        VarUInt::write_var_uint(output, utc.month() as u64)?;
+    }
+    if precision >= Precision::Day {
+        VarUInt::write_var_uint(output, utc.day() as u64)?;
+    }
+    if precision >= Precision::HourAndMinute {
+        VarUInt::write_var_uint(output, utc.hour() as u64)?;
+        VarUInt::write_var_uint(output, utc.minute() as u64)?;
+    }
+    if precision >= Precision::Second {
+        VarUInt::write_var_uint(output, utc.second() as u64)?;
+    }
+    if let Some(fractional_seconds) = value.fractional_seconds() {
+        let fractional_seconds = Decimal::from(fractional_seconds.clone());
+        encode_decimal_representation(output, &fractional_seconds)?;
+    }
+    Ok(())
+}
+
+// Writes the exponent (VarInt) and coefficient (Int) of a decimal. A decimal with a coefficient
+// and exponent of zero has an empty representation; negative zero needs a one-byte coefficient
+// to hold its sign bit.
+pub(crate) fn encode_decimal_representation(
+    output: &mut Vec<u8>,
+    value: &Decimal,
+) -> IonResult<()> {
+    let exponent = value.exponent();
+    let mut magnitude = value.magnitude().to_bytes_be();
+    if magnitude == [0u8] {
+        magnitude.clear();
+    }
+    if exponent == 0 && magnitude.is_empty() && !value.is_negative() {
+        return Ok(());
+    }
+    VarInt::write_var_int(output, exponent)?;
+    // Int is a sign-and-magnitude encoding. If the magnitude's highest bit is already in use, an
+    // extra byte is needed to hold the sign bit.
+    let needs_sign_byte = match magnitude.first() {
+        Some(byte) => byte & 0b1000_0000 != 0,
+        // Negative zero has no magnitude bytes, but still needs somewhere to put its sign bit.
+        None => value.is_negative(),
+    };
+    if needs_sign_byte {
+        magnitude.insert(0, 0);
+    }
+    if value.is_negative() {
+        magnitude[0] |= 0b1000_0000;
+    }
+    output.extend_from_slice(&magnitude);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
+
+    use crate::binary::constants::v1_0::IVM;
+    use crate::binary::cursor::BinaryIonCursor;
+    use crate::binary::writer::BinaryWriter;
+    use crate::result::IonResult;
+    use crate::types::{Decimal, Integer, Timestamp};
+    use crate::{IonType, Reader};
+
+    type TestReader = Reader<BinaryIonCursor<io::Cursor<Vec<u8>>>>;
+
+    // Runs the provided commands against a BinaryWriter and then returns the bytes it produced.
+    fn writer_output<F>(mut commands: F) -> Vec<u8>
+    where
+        F: FnMut(&mut BinaryWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        commands(&mut writer).expect("Invalid BinaryWriter test commands.");
+        writer.flush().expect("Flushing the BinaryWriter failed.");
+        drop(writer);
+        output
+    }
+
+    // Asserts that the provided commands produce an IVM followed by `expected`.
+    fn writer_test<F>(commands: F, expected: &[u8])
+    where
+        F: FnMut(&mut BinaryWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let output = writer_output(commands);
+        assert_eq!(&output[..IVM.len()], &IVM);
+        assert_eq!(&output[IVM.len()..], expected);
+    }
+
+    fn reader_for(bytes: Vec<u8>) -> TestReader {
+        Reader::new(BinaryIonCursor::new(io::Cursor::new(bytes)))
+    }
+
+    #[test]
+    fn write_empty_stream() {
+        writer_test(|_w| Ok(()), &[]);
+    }
+
+    #[test]
+    fn drop_flushes_completed_values() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        writer.write_symbol("foo")?;
+        drop(writer);
+        let mut reader = reader_for(output);
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?.unwrap().text(), Some("foo"));
+
+        // Nothing is written if there is nothing to flush.
+        let mut output = Vec::new();
+        drop(BinaryWriter::new(&mut output));
+        assert!(output.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn write_null_null() {
+        writer_test(|w| w.write_null(IonType::Null), &[0x0F]);
+    }
+
+    #[test]
+    fn write_null_string() {
+        writer_test(|w| w.write_null(IonType::String), &[0x8F]);
+    }
+
+    #[test]
+    fn write_bools() {
+        writer_test(
+            |w| {
+                w.write_bool(true)?;
+                w.write_bool(false)
+            },
+            &[0x11, 0x10],
+        );
+    }
+
+    #[test]
+    fn write_i64() {
+        writer_test(
+            |w| {
+                w.write_i64(0)?;
+                w.write_i64(7)?;
+                w.write_i64(-256)
+            },
+            &[0x20, 0x21, 0x07, 0x32, 0x01, 0x00],
+        );
+    }
+
+    #[test]
+    fn write_big_int() {
+        writer_test(
+            |w| {
+                w.write_big_int(&BigInt::from(0))?;
+                w.write_integer(&Integer::BigInt(BigInt::from(-256)))?;
+                w.write_big_int(&(BigInt::from(u64::MAX) + 1))
+            },
+            &[0x20, 0x32, 0x01, 0x00, 0x29, 0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn write_f64() {
+        writer_test(
+            |w| {
+                w.write_f64(0f64)?;
+                w.write_f64(1.5f64)
+            },
+            &[0x40, 0x48, 0x3F, 0xF8, 0, 0, 0, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn write_f32() {
+        writer_test(
+            |w| {
+                w.write_f32(0f32)?;
+                w.write_f32(-0f32)?;
+                w.write_f32(1.5f32)
+            },
+            &[0x40, 0x44, 0x80, 0, 0, 0, 0x44, 0x3F, 0xC0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn write_decimal() {
+        writer_test(
+            |w| {
+                w.write_big_decimal(&BigDecimal::from_str("0").unwrap())?;
+                w.write_big_decimal(&BigDecimal::from_str("-1.28").unwrap())
+            },
+            // -128 needs an extra byte for its sign bit
+            &[0x50, 0x53, 0xC2, 0x80, 0x80],
+        );
+    }
+
+    #[test]
+    fn write_decimal_preserves_sign_and_exponent() {
+        writer_test(
+            |w| {
+                w.write_decimal(&Decimal::negative_zero())?;
+                w.write_decimal(&Decimal::new(0, -2))?;
+                w.write_decimal(&Decimal::new(150, -2))
+            },
+            &[0x52, 0x80, 0x80, 0x51, 0xC2, 0x53, 0xC2, 0x00, 0x96],
+        );
+    }
+
+    #[test]
+    fn write_string() {
+        writer_test(|w| w.write_string("foo"), &[0x83, b'f', b'o', b'o']);
+    }
+
+    #[test]
+    fn write_long_blob() {
+        let blob = [7u8; 20];
+        let mut expected = vec![0xAE, 0x94];
+        expected.extend_from_slice(&blob);
+        writer_test(|w| w.write_blob(&blob), &expected);
+    }
+
+    #[test]
+    fn write_symbol_declares_local_symbol() {
+        writer_test(
+            |w| w.write_symbol("foo"),
+            &[
+                0xE9, // Annotation wrapper, length 9
+                0x81, 0x83, // $ion_symbol_table::
+                0xD6, // Struct, length 6
+                0x87, // symbols:
+                0xB4, // List, length 4
+                0x83, b'f', b'o', b'o', // "foo"
+                0x71, 0x0A, // Symbol $10
+            ],
+        );
+    }
+
+    #[test]
+    fn write_system_symbol_does_not_declare_symbols() {
+        writer_test(|w| w.write_symbol("name"), &[0x71, 0x04]);
+    }
+
+    #[test]
+    fn write_struct_without_field_name_fails() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        writer.step_in(IonType::Struct)?;
+        assert!(writer.write_i64(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn step_in_scalar_fails() {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        assert!(writer.step_in(IonType::Integer).is_err());
+        assert!(writer.step_out().is_err());
+    }
+
+    #[test]
+    fn round_trip_scalars() -> IonResult<()> {
+        let naive_datetime = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 30, 15);
+        let datetime = FixedOffset::east(0).from_utc_datetime(&naive_datetime);
+        let output = writer_output(|w| {
+            w.write_null(IonType::Timestamp)?;
+            w.write_bool(true)?;
+            w.write_i64(i64::MAX)?;
+            w.write_i64(-5)?;
+            w.write_f64(-0.5f64)?;
+            w.write_big_decimal(&BigDecimal::from_str("731221.9948").unwrap())?;
+            w.write_datetime(&datetime)?;
+            w.write_symbol("foo")?;
+            w.write_string("bar")?;
+            w.write_blob(b"baz")?;
+            w.write_clob(b"quux")
+        });
+
+        let mut reader = reader_for(output);
+        assert_eq!(reader.next()?, Some((IonType::Timestamp, true)));
+        assert_eq!(reader.next()?, Some((IonType::Boolean, false)));
+        assert_eq!(reader.read_bool()?, Some(true));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(i64::MAX));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(-5));
+        assert_eq!(reader.next()?, Some((IonType::Float, false)));
+        assert_eq!(reader.read_f64()?, Some(-0.5f64));
+        assert_eq!(reader.next()?, Some((IonType::Decimal, false)));
+        assert_eq!(
+            reader.read_big_decimal()?,
+            Some(BigDecimal::from_str("731221.9948").unwrap())
+        );
+        assert_eq!(reader.next()?, Some((IonType::Timestamp, false)));
+        assert_eq!(reader.read_datetime()?, Some(datetime));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let sid = reader.read_symbol_id()?.unwrap();
+        assert_eq!(reader.symbol_table().text_for(sid), Some("foo"));
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        assert_eq!(reader.read_string()?, Some("bar".to_string()));
+        assert_eq!(reader.next()?, Some((IonType::Blob, false)));
+        assert_eq!(reader.read_blob_bytes()?, Some(b"baz".to_vec()));
+        assert_eq!(reader.next()?, Some((IonType::Clob, false)));
+        assert_eq!(reader.read_clob_bytes()?, Some(b"quux".to_vec()));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_timestamps() -> IonResult<()> {
+        let timestamps = vec![
+            Timestamp::with_year(2020)?,
+            Timestamp::with_year_and_month(2020, 6)?,
+            Timestamp::with_ymd(2020, 6, 15)?,
+            Timestamp::with_ymd_hm(2020, 1, 1, 0, 30)?.with_offset(60)?,
+            Timestamp::with_ymd_hm(2020, 1, 1, 0, 30)?,
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_offset(-420)?
+                .with_fractional_seconds(BigDecimal::from_str("0.123456789123").unwrap())?,
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_fractional_seconds(BigDecimal::from_str("0.000").unwrap())?,
+        ];
+        let output = writer_output(|w| {
+            for timestamp in &timestamps {
+                w.write_timestamp(timestamp)?;
+            }
+            Ok(())
+        });
+        let mut reader = reader_for(output);
+        for timestamp in &timestamps {
+            assert_eq!(reader.next()?, Some((IonType::Timestamp, false)));
+            assert_eq!(reader.read_timestamp()?.as_ref(), Some(timestamp));
+        }
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_containers() -> IonResult<()> {
+        // foo::{a: bar::[1, 2, 3], b: (baz "a string that needs a VarUInt length"), c: {}}
+        let output = writer_output(|w| {
+            w.set_annotations(&["foo"]);
+            w.step_in(IonType::Struct)?;
+            w.set_field_name("a");
+            w.set_annotations(&["bar"]);
+            w.step_in(IonType::List)?;
+            w.write_i64(1)?;
+            w.write_i64(2)?;
+            w.write_i64(3)?;
+            w.step_out()?;
+            w.set_field_name("b");
+            w.step_in(IonType::SExpression)?;
+            w.write_symbol("baz")?;
+            w.write_string("a string that needs a VarUInt length")?;
+            w.step_out()?;
+            w.set_field_name("c");
+            w.step_in(IonType::Struct)?;
+            w.step_out()?;
+            w.step_out()
+        });
+
+        let mut reader = reader_for(output);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.annotations().collect::<Vec<_>>(), vec!["foo"]);
+        reader.step_in()?;
+
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        assert_eq!(reader.field_name(), Some("a"));
+        assert_eq!(reader.annotations().collect::<Vec<_>>(), vec!["bar"]);
+        reader.step_in()?;
+        let mut values = vec![];
+        while let Some((IonType::Integer, false)) = reader.next()? {
+            values.push(reader.read_i64()?.unwrap());
+        }
+        assert_eq!(values, vec![1, 2, 3]);
+        reader.step_out()?;
+
+        assert_eq!(reader.next()?, Some((IonType::SExpression, false)));
+        assert_eq!(reader.field_name(), Some("b"));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let sid = reader.read_symbol_id()?.unwrap();
+        assert_eq!(reader.symbol_table().text_for(sid), Some("baz"));
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        assert_eq!(
+            reader.read_string()?,
+            Some("a string that needs a VarUInt length".to_string())
+        );
+        reader.step_out()?;
+
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.field_name(), Some("c"));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn flush_appends_new_symbols() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        writer.write_symbol("bar")?;
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = reader_for(output);
+        let mut symbols = vec![];
+        while let Some((IonType::Symbol, false)) = reader.next()? {
+            let sid = reader.read_symbol_id()?.unwrap();
+            symbols.push(reader.symbol_table().text_for(sid).unwrap().to_string());
+        }
+        assert_eq!(symbols, vec!["foo", "bar", "foo"]);
+        Ok(())
+    }
+
+    #[test]
+    fn flush_keeps_incomplete_containers_buffered() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        writer.write_i64(1)?;
+        writer.step_in(IonType::List)?;
+        writer.write_i64(2)?;
+        writer.flush()?;
+        assert_eq!(
+            writer.output().as_slice(),
+            &[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01]
+        );
+        writer.step_out()?;
+        writer.flush()?;
+        assert_eq!(
+            writer.output().as_slice(),
+            &[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01, 0xB2, 0x21, 0x02]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::constants::v1_0::system_symbol_ids;
+use crate::cursor::Cursor;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::{IonType, Reader};
+
+/// A named, versioned list of symbols that can be imported by local symbol tables. See the
+/// [Shared Symbol Tables](http://amzn.github.io/ion-docs/docs/symbols.html#shared-symbol-tables)
+/// section of the Ion spec for more details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedSymbolTable {
+    name: String,
+    version: usize,
+    // Symbols with unknown text are represented as None.
+    symbols: Vec<Option<String>>,
+}
+
+impl SharedSymbolTable {
+    /// Constructs a new shared symbol table. The `name` must not be empty and the `version` must
+    /// be at least 1.
+    pub fn new<N: Into<String>>(
+        name: N,
+        version: usize,
+        symbols: Vec<Option<String>>,
+    ) -> IonResult<SharedSymbolTable> {
+        let name = name.into();
+        if name.is_empty() {
+            return illegal_operation("Shared symbol tables must have a non-empty name.");
+        }
+        if version < 1 {
+            return illegal_operation(format!(
+                "Shared symbol table versions must be at least 1; found {}.",
+                version
+            ));
+        }
+        Ok(SharedSymbolTable {
+            name,
+            version,
+            symbols,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the symbols defined by this table. Symbols with unknown text are represented as
+    /// None.
+    pub fn symbols(&self) -> &[Option<String>] {
+        &self.symbols
+    }
+}
+
+/// A collection of shared symbol tables that a [Reader] can consult when a local symbol table
+/// imports one of them.
+pub trait Catalog {
+    /// Returns the highest version of the shared symbol table with the provided name, if any.
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable>;
+
+    /// Returns the shared symbol table with the provided name and version, if any.
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable>;
+}
+
+/// An in-memory Catalog that stores shared symbol tables by name and version.
+#[derive(Debug, Clone, Default)]
+pub struct MapCatalog {
+    tables_by_name: HashMap<String, BTreeMap<usize, SharedSymbolTable>>,
+}
+
+impl MapCatalog {
+    pub fn new() -> MapCatalog {
+        MapCatalog::default()
+    }
+
+    /// Adds the provided table to the catalog, replacing any existing table with the same name
+    /// and version.
+    pub fn insert_table(&mut self, table: SharedSymbolTable) {
+        self.tables_by_name
+            .entry(table.name.clone())
+            .or_default()
+            .insert(table.version, table);
+    }
+
+    /// Reads each top-level `$ion_shared_symbol_table` struct in the provided stream and adds it
+    /// to the catalog. Any other values in the stream are skipped. A shared symbol table may
+    /// import tables that are already in the catalog, including those that appeared earlier in
+    /// the same stream.
+    ///
+    /// Returns the number of tables that were added.
+    pub fn load_tables<C: Cursor>(&mut self, reader: &mut Reader<C>) -> IonResult<usize> {
+        let mut number_of_tables = 0;
+        while let Some((ion_type, is_null)) = reader.next()? {
+            if ion_type != IonType::Struct || is_null {
+                continue;
+            }
+            if reader.first_annotation_id() != Some(system_symbol_ids::ION_SHARED_SYMBOL_TABLE) {
+                continue;
+            }
+            let table = reader.read_shared_symbol_table(self)?;
+            self.insert_table(table);
+            number_of_tables += 1;
+        }
+        Ok(number_of_tables)
+    }
+}
+
+impl Catalog for MapCatalog {
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        self.tables_by_name
+            .get(name)
+            .and_then(|versions| versions.values().next_back())
+    }
+
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.tables_by_name
+            .get(name)
+            .and_then(|versions| versions.get(&version))
+    }
+}
+
+/// A single entry from a symbol table's `imports` list.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImportDescriptor {
+    pub name: String,
+    pub version: usize,
+    pub max_id: Option<usize>,
+}
+
+// The largest number of symbols with unknown text that an import may add to a shared symbol
+// table. Shared symbol tables store the text of every symbol they define, so an import whose
+// `max_id` goes far beyond the table it refers to is rejected rather than allocated.
+const MAX_UNKNOWN_SHARED_SYMBOLS: usize = 1 << 20;
+
+/// The symbols that an import contributes to the symbol table that imports it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedImport {
+    /// The symbols found in the catalog's table, up to the import's `max_id`
+    pub symbols: Vec<Option<String>>,
+    /// The number of symbol IDs following `symbols` that the import declares but whose text
+    /// is unknown
+    pub unknown_symbols: usize,
+}
+
+impl ResolvedImport {
+    /// Returns every symbol the import contributes, including those with unknown text. Returns
+    /// an Err if the import declares an unreasonable number of symbols with unknown text.
+    pub fn into_symbols(mut self) -> IonResult<Vec<Option<String>>> {
+        if self.unknown_symbols > MAX_UNKNOWN_SHARED_SYMBOLS {
+            return decoding_error(format!(
+                "Found an import that declares {} symbols with unknown text; the limit is {}.",
+                self.unknown_symbols, MAX_UNKNOWN_SHARED_SYMBOLS
+            ));
+        }
+        let length = self.symbols.len() + self.unknown_symbols;
+        self.symbols.resize(length, None);
+        Ok(self.symbols)
+    }
+}
+
+/// Returns the symbols that `import` contributes to the symbol table that imports it.
+///
+/// If the catalog does not contain the exact version requested, the highest available version
+/// is used instead. Any symbol IDs that the table does not define (because the table could not be
+/// found or because `max_id` is larger than the table) have unknown text; these are counted
+/// rather than allocated, since `max_id` comes from the input. If the exact version cannot be
+/// found and the import does not specify a `max_id`, returns an Err.
+pub(crate) fn resolve_import(
+    catalog: &dyn Catalog,
+    import: &ImportDescriptor,
+) -> IonResult<ResolvedImport> {
+    let exact_match = catalog.get_table_with_version(&import.name, import.version);
+    let max_id = match (import.max_id, exact_match) {
+        (Some(max_id), _) => max_id,
+        (None, Some(table)) => table.symbols().len(),
+        (None, None) => {
+            return decoding_error(format!(
+                "Shared symbol table '{}' version {} was not found in the catalog and its import \
+                 did not specify a max_id.",
+                import.name, import.version
+            ))
+        }
+    };
+    let table = exact_match.or_else(|| catalog.get_table(&import.name));
+    let symbols: Vec<Option<String>> = match table {
+        Some(table) => table.symbols().iter().take(max_id).cloned().collect(),
+        None => Vec::new(),
+    };
+    let unknown_symbols = max_id - symbols.len();
+    Ok(ResolvedImport {
+        symbols,
+        unknown_symbols,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::catalog::{resolve_import, ImportDescriptor, ResolvedImport};
+    use crate::result::IonResult;
+    use crate::text::cursor::TextIonCursor;
+    use crate::{Catalog, MapCatalog, Reader, SharedSymbolTable};
+
+    fn symbols(text: &[&str]) -> Vec<Option<String>> {
+        text.iter().map(|text| Some(text.to_string())).collect()
+    }
+
+    fn table(name: &str, version: usize, text: &[&str]) -> SharedSymbolTable {
+        SharedSymbolTable::new(name, version, symbols(text)).unwrap()
+    }
+
+    fn resolved(text: &[&str], unknown_symbols: usize) -> ResolvedImport {
+        ResolvedImport {
+            symbols: symbols(text),
+            unknown_symbols,
+        }
+    }
+
+    fn import(name: &str, version: usize, max_id: Option<usize>) -> ImportDescriptor {
+        ImportDescriptor {
+            name: name.to_string(),
+            version,
+            max_id,
+        }
+    }
+
+    #[test]
+    fn invalid_shared_symbol_tables() {
+        assert!(SharedSymbolTable::new("", 1, vec![]).is_err());
+        assert!(SharedSymbolTable::new("foo", 0, vec![]).is_err());
+    }
+
+    #[test]
+    fn get_table_by_name_and_version() {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table("foo", 1, &["a"]));
+        catalog.insert_table(table("foo", 3, &["a", "b", "c"]));
+        catalog.insert_table(table("foo", 2, &["a", "b"]));
+        assert_eq!(catalog.get_table("foo").map(|t| t.version()), Some(3));
+        assert_eq!(
+            catalog
+                .get_table_with_version("foo", 2)
+                .map(|t| t.version()),
+            Some(2)
+        );
+        assert_eq!(catalog.get_table_with_version("foo", 4), None);
+        assert_eq!(catalog.get_table("bar"), None);
+    }
+
+    #[test]
+    fn resolve_imports() -> IonResult<()> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table("foo", 1, &["a", "b"]));
+        catalog.insert_table(table("foo", 2, &["a", "b", "c"]));
+
+        // Exact match with no max_id imports the whole table
+        assert_eq!(
+            resolve_import(&catalog, &import("foo", 1, None))?,
+            resolved(&["a", "b"], 0)
+        );
+        // A max_id smaller than the table truncates it
+        assert_eq!(
+            resolve_import(&catalog, &import("foo", 2, Some(1)))?,
+            resolved(&["a"], 0)
+        );
+        // A max_id larger than the table declares symbols with unknown text
+        assert_eq!(
+            resolve_import(&catalog, &import("foo", 1, Some(3)))?,
+            resolved(&["a", "b"], 1)
+        );
+        assert_eq!(
+            resolve_import(&catalog, &import("foo", 1, Some(3)))?.into_symbols()?,
+            vec![Some("a".to_string()), Some("b".to_string()), None]
+        );
+        // A missing version falls back to the highest version available
+        assert_eq!(
+            resolve_import(&catalog, &import("foo", 5, Some(3)))?,
+            resolved(&["a", "b", "c"], 0)
+        );
+        // A missing table with a max_id declares symbols with unknown text
+        assert_eq!(
+            resolve_import(&catalog, &import("bar", 1, Some(2)))?,
+            resolved(&[], 2)
+        );
+        // Huge numbers of symbols with unknown text are counted, not allocated
+        let huge = resolve_import(&catalog, &import("bar", 1, Some(4_000_000_000_000)))?;
+        assert_eq!(huge, resolved(&[], 4_000_000_000_000));
+        assert!(huge.into_symbols().is_err());
+        // A missing table without a max_id is an error
+        assert!(resolve_import(&catalog, &import("bar", 1, None)).is_err());
+        assert!(resolve_import(&catalog, &import("foo", 5, None)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn load_tables_from_stream() -> IonResult<()> {
+        let text = r#"
+            $ion_shared_symbol_table::{name: "foo", version: 1, symbols: ["a", "b"]}
+            "not a table"
+            $ion_shared_symbol_table::{
+                name: "bar",
+                imports: [{name: "foo", version: 1}],
+                symbols: ["c", 5, "d"],
+            }
+        "#;
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )));
+        let mut catalog = MapCatalog::new();
+        assert_eq!(catalog.load_tables(&mut reader)?, 2);
+        assert_eq!(
+            catalog.get_table("foo"),
+            Some(&table("foo", 1, &["a", "b"]))
+        );
+        let bar = catalog.get_table_with_version("bar", 1).unwrap();
+        assert_eq!(
+            bar.symbols(),
+            &[
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string()),
+                None,
+                Some("d".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn load_table_with_huge_import() {
+        let text = r#"
+            $ion_shared_symbol_table::{
+                name: "foo",
+                imports: [{name: "x", version: 1, max_id: 4000000000000}],
+                symbols: ["a"],
+            }
+        "#;
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )));
+        assert!(MapCatalog::new().load_tables(&mut reader).is_err());
+    }
+
+    #[test]
+    fn load_table_without_name() {
+        let text = r#"$ion_shared_symbol_table::{version: 1, symbols: ["a"]}"#;
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )));
+        assert!(MapCatalog::new().load_tables(&mut reader).is_err());
+    }
+}
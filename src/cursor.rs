@@ -1,8 +1,9 @@
 use crate::data_source::IonDataSource;
-use crate::result::IonResult;
-use crate::types::{IonType, SymbolId};
+use crate::result::{illegal_operation, IonResult};
+use crate::types::{Decimal, Integer, IonType, SymbolId, Timestamp};
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
 
 /**
  * This trait captures the format-agnostic parser functionality needed to navigate within an Ion
@@ -36,6 +37,24 @@ pub trait Cursor {
     /// field's name; otherwise, returns None.
     fn field_id(&self) -> Option<SymbolId>;
 
+    /// If the current value is a field within a struct, returns its name as it was encoded in the
+    /// stream: either a symbol ID or inline text. Formats that only encode symbol IDs (like
+    /// binary Ion) can rely on the default implementation, which wraps `field_id`.
+    fn raw_field_name(&self) -> Option<RawSymbolTokenRef<'_>> {
+        self.field_id().map(RawSymbolTokenRef::SymbolId)
+    }
+
+    /// Returns the current value's annotations as they were encoded in the stream. Formats that
+    /// only encode symbol IDs (like binary Ion) can rely on the default implementation, which
+    /// wraps `annotation_ids`.
+    fn raw_annotations(&self) -> Vec<RawSymbolTokenRef<'_>> {
+        self.annotation_ids()
+            .iter()
+            .copied()
+            .map(RawSymbolTokenRef::SymbolId)
+            .collect()
+    }
+
     /// If the current value is a null, returns the Ion type of the null; otherwise,
     /// returns None.
     fn read_null(&mut self) -> IonResult<Option<IonType>>;
@@ -44,7 +63,29 @@ pub trait Cursor {
     fn read_bool(&mut self) -> IonResult<Option<bool>>;
 
     /// If the current value is an integer, returns its value as an i64; otherwise, returns None.
-    fn read_i64(&mut self) -> IonResult<Option<i64>>;
+    /// If the integer is too large to be represented as an i64, returns an Err; use
+    /// `read_integer` or `read_big_int` to read integers of any size.
+    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        match self.read_integer()? {
+            Some(Integer::I64(value)) => Ok(Some(value)),
+            Some(Integer::BigInt(value)) => illegal_operation(format!(
+                "Integer {} is too large to be read as an i64; use read_integer() or \
+                 read_big_int() instead.",
+                value
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// If the current value is an integer, returns its value as an Integer, which holds an i64
+    /// when the value is small enough to fit in one and a BigInt otherwise. If the current value
+    /// is not an integer, returns None.
+    fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+
+    /// If the current value is an integer, returns its value as a BigInt; otherwise, returns None.
+    fn read_big_int(&mut self) -> IonResult<Option<BigInt>> {
+        Ok(self.read_integer()?.map(BigInt::from))
+    }
 
     /// If the current value is a float, returns its value as an f32; otherwise, returns None.
     fn read_f32(&mut self) -> IonResult<Option<f32>>;
@@ -52,9 +93,15 @@ pub trait Cursor {
     /// If the current value is a float, returns its value as an f64; otherwise, returns None.
     fn read_f64(&mut self) -> IonResult<Option<f64>>;
 
-    /// If the current value is a decimal, returns its value as an BigDecimal; otherwise,
-    /// returns None.
-    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+    /// If the current value is a decimal, returns its value as a Decimal, which preserves its
+    /// precision and the sign of a zero coefficient; otherwise, returns None.
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+
+    /// If the current value is a decimal, returns its value as a BigDecimal; otherwise,
+    /// returns None. BigDecimal cannot represent negative zero, which will be read as zero.
+    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+        Ok(self.read_decimal()?.map(BigDecimal::from))
+    }
 
     /// If the current value is a string, returns its value as a String; otherwise, returns None.
     fn read_string(&mut self) -> IonResult<Option<String>>;
@@ -80,15 +127,32 @@ pub trait Cursor {
     /// If the current value is a symbol, returns its value as a SymbolId; otherwise, returns None.
     fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>>;
 
+    /// If the current value is a symbol, returns its value as it was encoded in the stream:
+    /// either a symbol ID or inline text. Otherwise, returns None. Formats that only encode
+    /// symbol IDs (like binary Ion) can rely on the default implementation, which wraps
+    /// `read_symbol_id`.
+    fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolTokenRef<'_>>> {
+        Ok(self.read_symbol_id()?.map(RawSymbolTokenRef::SymbolId))
+    }
+
     /// If the current value is a blob, returns its value as a Vec<u8>; otherwise, returns None.
     fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
 
     /// If the current value is a clob, returns its value as a Vec<u8>; otherwise, returns None.
     fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
 
+    /// If the current value is a timestamp, returns its value as a Timestamp, which preserves
+    /// its precision, fractional seconds, and whether its offset is known; otherwise, returns None.
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+
     /// If the current value is a timestamp, returns its value as a DateTime<FixedOffset>;
-    /// otherwise, returns None.
-    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+    /// otherwise, returns None. See `Timestamp::to_datetime` for details on how the conversion
+    /// handles precision and unknown offsets.
+    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+        Ok(self
+            .read_timestamp()?
+            .map(|timestamp| timestamp.to_datetime()))
+    }
 
     /// If the current value is a container (i.e. a struct, list, or s-expression), positions the
     /// cursor at the beginning of that container's sequence of child values. If the current value
@@ -108,10 +172,20 @@ pub trait Cursor {
 pub enum StreamItem {
     /// An Ion Version Marker (IVM) indicating the Ion major and minor version that were used to
     /// encode the values that follow.
-    VersionMarker,
+    VersionMarker(u8, u8),
     /// An Ion value (e.g. an integer, timestamp, or struct).
     /// Includes the value's IonType and whether it is null.
     /// Stream values that represent system constructs (e.g. a struct marked with a
     /// $ion_symbol_table annotation) are still considered values.
     Value(IonType, bool),
 }
+
+/// A symbol as it was encoded in the stream, before any symbol table resolution has taken place.
+///
+/// Binary Ion always encodes symbols as symbol IDs. Text Ion usually encodes them as inline
+/// text, but may also refer to a symbol ID directly using the `$ID` syntax (e.g. `$10`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RawSymbolTokenRef<'a> {
+    SymbolId(SymbolId),
+    Text(&'a str),
+}
@@ -1,4 +1,5 @@
 use crate::result::IonResult;
+use std::io;
 use std::io::BufRead;
 
 /// Optimized read operations for parsing Ion.
@@ -57,6 +58,10 @@ impl<T: BufRead> IonDataSource for T {
         while bytes_skipped < number_of_bytes {
             let buffer = self.fill_buf()?;
             let bytes_in_buffer = buffer.len();
+            if bytes_in_buffer == 0 {
+                // The data source ran out of bytes before we could skip the requested number.
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
             let bytes_to_skip = (number_of_bytes - bytes_skipped).min(bytes_in_buffer);
             self.consume(bytes_to_skip);
             bytes_skipped += bytes_to_skip;
@@ -109,6 +114,10 @@ impl<T: BufRead> IonDataSource for T {
             // Get a reference to the data source's input buffer, refilling it if it's empty.
             let buffer = self.fill_buf()?;
             number_of_buffered_bytes = buffer.len();
+            if number_of_buffered_bytes == 0 {
+                // The data source ran out of bytes before the lambda finished reading.
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
 
             // Iterate over the bytes already in the buffer, calling the provided lambda on each
             // one.
@@ -200,6 +209,12 @@ mod tests {
         assert_eq!(None, data_source.next_byte().unwrap());
     }
 
+    #[test]
+    fn test_skip_bytes_past_end() {
+        let mut data_source = test_data(2, &[1, 2, 3]);
+        assert!(data_source.skip_bytes(4).is_err());
+    }
+
     #[test]
     fn test_read_next_byte_while() {
         let mut data_source = test_data(2, &[1, 2, 3, 4, 5]);
@@ -213,6 +228,12 @@ mod tests {
         assert_eq!(sum, 10);
     }
 
+    #[test]
+    fn test_read_next_byte_while_past_end() {
+        let mut data_source = test_data(2, &[1, 2, 3]);
+        assert!(data_source.read_next_byte_while(&mut |_| true).is_err());
+    }
+
     #[test]
     fn test_read_slice() {
         let mut data_source = test_data(2, &[1, 2, 3, 4, 5]);
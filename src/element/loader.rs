@@ -0,0 +1,185 @@
+use crate::cursor::Cursor;
+use crate::element::{Element, Struct, Value};
+use crate::result::{decoding_error, IonResult};
+use crate::types::IonType;
+use crate::Reader;
+
+/// Reads every remaining value at the Reader's current depth into an Element.
+pub fn load_all<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Vec<Element>> {
+    let mut elements = Vec::new();
+    while let Some(element) = load_next(reader)? {
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
+/// Advances the Reader to the next value at its current depth and reads it into an Element.
+/// Returns None if there are no more values at the current depth.
+pub fn load_next<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Option<Element>> {
+    match reader.next()? {
+        Some((ion_type, is_null)) => load_current(reader, ion_type, is_null).map(Some),
+        None => Ok(None),
+    }
+}
+
+// Reads the value over which the Reader is currently positioned, including any child values.
+fn load_current<C: Cursor>(
+    reader: &mut Reader<C>,
+    ion_type: IonType,
+    is_null: bool,
+) -> IonResult<Element> {
+    let annotations = reader.annotation_tokens()?;
+    if is_null {
+        return Ok(Element::new(annotations, Value::Null(ion_type)));
+    }
+    use IonType::*;
+    let value = match ion_type {
+        Null => unreachable!("Value with IonType::Null returned is_null=false."),
+        Boolean => Value::Boolean(non_null(reader.read_bool()?)?),
+        Integer => Value::Integer(non_null(reader.read_integer()?)?),
+        Float => Value::Float(non_null(reader.read_f64()?)?),
+        Decimal => Value::Decimal(non_null(reader.read_decimal()?)?),
+        Timestamp => Value::Timestamp(non_null(reader.read_timestamp()?)?),
+        Symbol => Value::Symbol(non_null(reader.read_symbol()?)?),
+        String => Value::String(non_null(reader.read_string()?)?),
+        Clob => Value::Clob(non_null(reader.read_clob_bytes()?)?),
+        Blob => Value::Blob(non_null(reader.read_blob_bytes()?)?),
+        List => Value::List(load_sequence(reader)?),
+        SExpression => Value::SExpression(load_sequence(reader)?),
+        Struct => Value::Struct(load_struct(reader)?),
+    };
+    Ok(Element::new(annotations, value))
+}
+
+fn load_sequence<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Vec<Element>> {
+    reader.step_in()?;
+    let elements = load_all(reader)?;
+    reader.step_out()?;
+    Ok(elements)
+}
+
+fn load_struct<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Struct> {
+    let mut fields = Struct::new();
+    reader.step_in()?;
+    while let Some((ion_type, is_null)) = reader.next()? {
+        let name = match reader.field_name_token()? {
+            Some(name) => name,
+            None => return decoding_error("Found a struct field without a name."),
+        };
+        fields.add_field(name, load_current(reader, ion_type, is_null)?);
+    }
+    reader.step_out()?;
+    Ok(fields)
+}
+
+// The Reader's read_* methods only return None for null values, which are handled before
+// any of them are called.
+pub(crate) fn non_null<T>(value: Option<T>) -> IonResult<T> {
+    match value {
+        Some(value) => Ok(value),
+        None => decoding_error("Expected a non-null value, but the Reader returned None."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use crate::binary::constants::v1_0::IVM;
+    use crate::element::{load_all, Element, Struct, Value};
+    use crate::result::IonResult;
+    use crate::types::{IonType, SymbolToken, Timestamp};
+    use crate::{BinaryIonCursor, Reader, TextIonCursor};
+
+    fn load_text(text: &str) -> IonResult<Vec<Element>> {
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )));
+        load_all(&mut reader)
+    }
+
+    fn symbol(text: &str) -> Element {
+        Value::Symbol(SymbolToken::with_text(text)).into()
+    }
+
+    #[test]
+    fn load_scalars() -> IonResult<()> {
+        let elements = load_text(
+            r#"
+            null.int true -7 2.5e0 1.50 2020-06-15T foo "bar" {{"baz"}} {{aGVsbG8=}}
+            "#,
+        )?;
+        let expected: Vec<Element> = vec![
+            Value::Null(IonType::Integer).into(),
+            Value::from(true).into(),
+            Value::from(-7).into(),
+            Value::from(2.5).into(),
+            Value::from(BigDecimal::from_str("1.50").unwrap()).into(),
+            Value::from(Timestamp::with_ymd(2020, 6, 15)?).into(),
+            symbol("foo"),
+            Value::from("bar").into(),
+            Value::Clob(b"baz".to_vec()).into(),
+            Value::Blob(b"hello".to_vec()).into(),
+        ];
+        assert_eq!(elements, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn load_containers() -> IonResult<()> {
+        let elements = load_text("a::b::{x: [1, (+ 2 3)], y: null.list, x: c::4}")?;
+        let one = Element::from(Value::from(1));
+        let sexp = Value::SExpression(vec![
+            symbol("+"),
+            Value::from(2).into(),
+            Value::from(3).into(),
+        ]);
+        let fields: Struct = vec![
+            (
+                "x",
+                Element::from(Value::from(4)).with_annotations(vec!["c"]),
+            ),
+            ("y", Value::Null(IonType::List).into()),
+            ("x", Value::List(vec![one, sexp.into()]).into()),
+        ]
+        .into_iter()
+        .collect();
+        let expected = Element::from(Value::from(fields)).with_annotations(vec!["a", "b"]);
+        assert_eq!(elements, vec![expected]);
+        Ok(())
+    }
+
+    #[test]
+    fn load_binary() -> IonResult<()> {
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(&[
+            0xE9, 0x81, 0x83, // $ion_symbol_table::
+            0xD6, 0x87, 0xB4, // {symbols: [
+            0x83, b'f', b'o', b'o', // "foo"]}
+            0xD3, 0x8A, 0x71, 0x0A, // {$10: $10}
+            0xE4, 0x81, 0x8A, 0x21, 0x01, // $10::1
+        ]);
+        let mut reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(data)));
+        let elements = load_all(&mut reader)?;
+        let fields: Struct = vec![("foo", symbol("foo"))].into_iter().collect();
+        let expected: Vec<Element> = vec![
+            Value::from(fields).into(),
+            Element::from(Value::from(1)).with_annotations(vec!["foo"]),
+        ];
+        assert_eq!(elements, expected);
+        let foo = elements[0].as_struct().and_then(|s| s.get("foo"));
+        let foo = foo.and_then(|e| e.as_symbol()).and_then(|s| s.local_sid());
+        assert_eq!(foo, Some(10));
+        Ok(())
+    }
+
+    #[test]
+    fn load_symbol_out_of_range() {
+        assert!(load_text("$100").is_err());
+        assert!(load_text("$100::1").is_err());
+        assert!(load_text("{$100: 1}").is_err());
+    }
+}
@@ -0,0 +1,466 @@
+//! This module provides an owned, in-memory representation of Ion data. An [Element] is a
+//! [Value] together with any annotations that were applied to it.
+//!
+//! Elements can be [loaded](load_all) from any [Reader](crate::Reader) and written to any
+//! [Writer](crate::Writer). Comparing two Elements with `==` follows the
+//! equivalence rules of the
+//! [Ion Data Model](http://amzn.github.io/ion-docs/docs/spec.html#the-ion-data-model):
+//! annotations and their order are significant, struct field order is not, and decimals and
+//! timestamps must have the same precision to be considered equal.
+
+use bigdecimal::BigDecimal;
+
+use crate::types::{Decimal, Integer, IonType, SymbolToken, Timestamp};
+
+mod loader;
+mod writer;
+
+pub(crate) use loader::non_null;
+pub use loader::{load_all, load_next};
+
+/// An Ion value along with its annotations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    annotations: Vec<SymbolToken>,
+    value: Value,
+}
+
+impl Element {
+    pub fn new(annotations: Vec<SymbolToken>, value: Value) -> Element {
+        Element { annotations, value }
+    }
+
+    /// Returns a copy of this Element with the provided annotations, replacing any annotations
+    /// it already had.
+    pub fn with_annotations<I, A>(self, annotations: I) -> Element
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<SymbolToken>,
+    {
+        Element::new(
+            annotations.into_iter().map(|a| a.into()).collect(),
+            self.value,
+        )
+    }
+
+    pub fn annotations(&self) -> &[SymbolToken] {
+        &self.annotations
+    }
+
+    /// Returns true if any of this Element's annotations has the provided text.
+    pub fn has_annotation(&self, text: &str) -> bool {
+        self.annotations.iter().any(|a| a.text() == Some(text))
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.value.ion_type()
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self.value, Value::Null(_))
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match &self.value {
+            Value::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<&Integer> {
+        match &self.value {
+            Value::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// If this Element is an integer in the range `i64::MIN..=i64::MAX`, returns it as an i64.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_integer().and_then(|value| value.as_i64())
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match &self.value {
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_decimal(&self) -> Option<&Decimal> {
+        match &self.value {
+            Value::Decimal(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// If this Element is a decimal, returns it as a BigDecimal. Negative zero is returned as
+    /// zero.
+    pub fn as_big_decimal(&self) -> Option<BigDecimal> {
+        self.as_decimal().cloned().map(BigDecimal::from)
+    }
+
+    pub fn as_timestamp(&self) -> Option<&Timestamp> {
+        match &self.value {
+            Value::Timestamp(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_symbol(&self) -> Option<&SymbolToken> {
+        match &self.value {
+            Value::Symbol(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// If this Element is a string or a symbol with known text, returns its text.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            Value::String(text) => Some(text),
+            Value::Symbol(token) => token.text(),
+            _ => None,
+        }
+    }
+
+    /// If this Element is a blob or a clob, returns its bytes.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            Value::Blob(bytes) | Value::Clob(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// If this Element is a list or an s-expression, returns its child Elements.
+    pub fn as_sequence(&self) -> Option<&[Element]> {
+        match &self.value {
+            Value::List(elements) | Value::SExpression(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&Struct> {
+        match &self.value {
+            Value::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<Value> for Element {
+    fn from(value: Value) -> Self {
+        Element::new(vec![], value)
+    }
+}
+
+/// The value of an Element. Each variant corresponds to one of the thirteen Ion types; nulls of
+/// any type are represented by `Value::Null`.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Null(IonType),
+    Boolean(bool),
+    Integer(Integer),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
+    Symbol(SymbolToken),
+    String(String),
+    Clob(Vec<u8>),
+    Blob(Vec<u8>),
+    List(Vec<Element>),
+    SExpression(Vec<Element>),
+    Struct(Struct),
+}
+
+impl Value {
+    pub fn ion_type(&self) -> IonType {
+        match self {
+            Value::Null(ion_type) => *ion_type,
+            Value::Boolean(_) => IonType::Boolean,
+            Value::Integer(_) => IonType::Integer,
+            Value::Float(_) => IonType::Float,
+            Value::Decimal(_) => IonType::Decimal,
+            Value::Timestamp(_) => IonType::Timestamp,
+            Value::Symbol(_) => IonType::Symbol,
+            Value::String(_) => IonType::String,
+            Value::Clob(_) => IonType::Clob,
+            Value::Blob(_) => IonType::Blob,
+            Value::List(_) => IonType::List,
+            Value::SExpression(_) => IonType::SExpression,
+            Value::Struct(_) => IonType::Struct,
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        use Value::*;
+        match (self, other) {
+            (Null(a), Null(b)) => a == b,
+            (Boolean(a), Boolean(b)) => a == b,
+            (Integer(a), Integer(b)) => a == b,
+            // All NaNs are equivalent, but 0e0 and -0e0 are not.
+            (Float(a), Float(b)) => (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits(),
+            (Decimal(a), Decimal(b)) => a == b,
+            (Timestamp(a), Timestamp(b)) => a == b,
+            (Symbol(a), Symbol(b)) => a == b,
+            (String(a), String(b)) => a == b,
+            (Clob(a), Clob(b)) => a == b,
+            (Blob(a), Blob(b)) => a == b,
+            (List(a), List(b)) => a == b,
+            (SExpression(a), SExpression(b)) => a == b,
+            (Struct(a), Struct(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Boolean(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(Integer::I64(value))
+    }
+}
+
+impl From<Integer> for Value {
+    fn from(value: Integer) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<Decimal> for Value {
+    fn from(value: Decimal) -> Self {
+        Value::Decimal(value)
+    }
+}
+
+impl From<BigDecimal> for Value {
+    fn from(value: BigDecimal) -> Self {
+        Value::Decimal(value.into())
+    }
+}
+
+impl From<Timestamp> for Value {
+    fn from(value: Timestamp) -> Self {
+        Value::Timestamp(value)
+    }
+}
+
+impl From<SymbolToken> for Value {
+    fn from(value: SymbolToken) -> Self {
+        Value::Symbol(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<Struct> for Value {
+    fn from(value: Struct) -> Self {
+        Value::Struct(value)
+    }
+}
+
+/// The fields of an Ion struct. A struct may contain more than one field with the same name.
+#[derive(Debug, Clone, Default)]
+pub struct Struct {
+    fields: Vec<(SymbolToken, Element)>,
+}
+
+impl Struct {
+    pub fn new() -> Struct {
+        Struct::default()
+    }
+
+    /// Adds a field to the end of the struct, even if a field with the same name already exists.
+    pub fn add_field<N: Into<SymbolToken>>(&mut self, name: N, value: Element) {
+        self.fields.push((name.into(), value));
+    }
+
+    /// Returns the value of the first field with the provided name, if any.
+    pub fn get(&self, name: &str) -> Option<&Element> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name.text() == Some(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the values of every field with the provided name, in the order they were added.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.fields
+            .iter()
+            .filter(move |(field_name, _)| field_name.text() == Some(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the struct's (name, value) pairs, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&SymbolToken, &Element)> {
+        self.fields.iter().map(|(name, value)| (name, value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// Two structs are equal if they contain the same (name, value) pairs, in any order.
+impl PartialEq for Struct {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        // Each field in `self` must be matched with a distinct equivalent field in `other`.
+        let mut unmatched: Vec<&(SymbolToken, Element)> = other.fields.iter().collect();
+        for field in &self.fields {
+            match unmatched
+                .iter()
+                .position(|other_field| *other_field == field)
+            {
+                Some(index) => {
+                    unmatched.swap_remove(index);
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+impl Eq for Struct {}
+
+impl<N: Into<SymbolToken>> std::iter::FromIterator<(N, Element)> for Struct {
+    fn from_iter<T: IntoIterator<Item = (N, Element)>>(iter: T) -> Self {
+        Struct {
+            fields: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use crate::element::{Element, Struct, Value};
+    use crate::types::{Decimal, IonType, SymbolToken};
+
+    fn int(value: i64) -> Element {
+        Value::from(value).into()
+    }
+
+    fn decimal(text: &str) -> Element {
+        Value::from(BigDecimal::from_str(text).unwrap()).into()
+    }
+
+    #[test]
+    fn annotations_are_significant() {
+        assert_eq!(
+            int(1).with_annotations(vec!["a"]),
+            int(1).with_annotations(vec!["a"])
+        );
+        assert_ne!(int(1).with_annotations(vec!["a"]), int(1));
+        assert_ne!(
+            int(1).with_annotations(vec!["a", "b"]),
+            int(1).with_annotations(vec!["b", "a"])
+        );
+    }
+
+    #[test]
+    fn struct_field_order_is_not_significant() {
+        let a: Struct = vec![("a", int(1)), ("b", int(2)), ("a", int(3))]
+            .into_iter()
+            .collect();
+        let b: Struct = vec![("a", int(3)), ("a", int(1)), ("b", int(2))]
+            .into_iter()
+            .collect();
+        assert_eq!(a, b);
+        // Duplicate fields must appear the same number of times
+        let c: Struct = vec![("a", int(1)), ("b", int(2)), ("a", int(1))]
+            .into_iter()
+            .collect();
+        assert_ne!(a, c);
+        assert_eq!(a.get("a"), Some(&int(1)));
+        assert_eq!(a.get_all("a").count(), 2);
+        assert_eq!(a.get("c"), None);
+    }
+
+    #[test]
+    fn decimal_precision_is_significant() {
+        assert_eq!(decimal("1.0"), decimal("1.0"));
+        assert_ne!(decimal("1.0"), decimal("1.00"));
+        assert_ne!(decimal("0.0"), decimal("0"));
+        let negative_zero = Element::from(Value::from(Decimal::negative_zero()));
+        assert_ne!(negative_zero, decimal("0"));
+    }
+
+    #[test]
+    fn float_equivalence() {
+        let float = |value: f64| Element::from(Value::from(value));
+        assert_eq!(float(f64::NAN), float(f64::NAN));
+        assert_ne!(float(0.0), float(-0.0));
+        assert_eq!(float(2.5), float(2.5));
+    }
+
+    #[test]
+    fn types_are_significant() {
+        assert_ne!(
+            Element::from(Value::Null(IonType::Integer)),
+            Value::Null(IonType::Null).into()
+        );
+        assert_ne!(
+            Element::from(Value::Symbol(SymbolToken::with_text("foo"))),
+            Value::from("foo").into()
+        );
+        assert_ne!(
+            Element::from(Value::List(vec![int(1)])),
+            Value::SExpression(vec![int(1)]).into()
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let element = Element::from(Value::Symbol(SymbolToken::with_text("foo")));
+        assert_eq!(element.ion_type(), IonType::Symbol);
+        assert_eq!(element.as_str(), Some("foo"));
+        assert_eq!(element.as_i64(), None);
+        assert!(!element.is_null());
+        assert!(int(5).with_annotations(vec!["a"]).has_annotation("a"));
+        assert_eq!(int(5).as_i64(), Some(5));
+        let list = Element::from(Value::List(vec![int(1), int(2)]));
+        assert_eq!(list.as_sequence().map(|s| s.len()), Some(2));
+        assert!(Element::from(Value::Null(IonType::List)).is_null());
+    }
+}
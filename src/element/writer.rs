@@ -0,0 +1,117 @@
+use crate::element::{Element, Value};
+use crate::result::IonResult;
+use crate::types::IonType;
+use crate::writer::Writer;
+
+impl Element {
+    /// Writes this Element (including its annotations and any child Elements) to the provided
+    /// Writer. Symbols whose text is unknown are written as symbol IDs.
+    pub fn write_to<W: Writer>(&self, writer: &mut W) -> IonResult<()> {
+        if !self.annotations.is_empty() {
+            writer.set_annotation_tokens(&self.annotations);
+        }
+        match &self.value {
+            Value::Null(ion_type) => writer.write_null(*ion_type),
+            Value::Boolean(value) => writer.write_bool(*value),
+            Value::Integer(value) => writer.write_integer(value),
+            Value::Float(value) => writer.write_f64(*value),
+            Value::Decimal(value) => writer.write_decimal(value),
+            Value::Timestamp(value) => writer.write_timestamp(value),
+            Value::Symbol(token) => writer.write_symbol_token(token),
+            Value::String(text) => writer.write_string(text),
+            Value::Clob(bytes) => writer.write_clob(bytes),
+            Value::Blob(bytes) => writer.write_blob(bytes),
+            Value::List(elements) => write_sequence(writer, IonType::List, elements),
+            Value::SExpression(elements) => write_sequence(writer, IonType::SExpression, elements),
+            Value::Struct(fields) => {
+                writer.step_in(IonType::Struct)?;
+                for (name, value) in fields.iter() {
+                    writer.set_field_name_token(name);
+                    value.write_to(writer)?;
+                }
+                writer.step_out()
+            }
+        }
+    }
+}
+
+fn write_sequence<W: Writer>(
+    writer: &mut W,
+    ion_type: IonType,
+    elements: &[Element],
+) -> IonResult<()> {
+    writer.step_in(ion_type)?;
+    for element in elements {
+        element.write_to(writer)?;
+    }
+    writer.step_out()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::binary::writer::BinaryWriter;
+    use crate::element::{load_all, Element, Struct, Value};
+    use crate::result::IonResult;
+    use crate::text::writer::TextWriter;
+    use crate::types::SymbolToken;
+    use crate::{BinaryIonCursor, Reader, TextIonCursor};
+
+    fn write_elements(elements: &[Element]) -> IonResult<Vec<u8>> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        for element in elements {
+            element.write_to(&mut writer)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        Ok(output)
+    }
+
+    #[test]
+    fn round_trip() -> IonResult<()> {
+        let text = r#"
+            null null.struct true 12345678901234567890123 -0e0 nan 1.50 2020-06-15T10:30:00.250Z
+            foo::'bar baz'::"qux" {{aGVsbG8=}}
+            [1, (a b c), {x: 1, y: [], x: 2}]
+        "#;
+        let read =
+            |bytes: Vec<u8>| load_all(&mut Reader::new(TextIonCursor::new(io::Cursor::new(bytes))));
+        let elements = read(text.as_bytes().to_vec())?;
+        assert_eq!(elements.len(), 11);
+        let round_tripped = read(write_elements(&elements)?)?;
+        assert_eq!(elements, round_tripped);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_text_round_trips() -> IonResult<()> {
+        let unknown = SymbolToken::new(None, Some(0));
+        let mut fields = Struct::new();
+        fields.add_field(
+            unknown.clone(),
+            Element::from(Value::Symbol(unknown.clone())),
+        );
+        let elements = vec![
+            Element::from(Value::Symbol(unknown.clone())),
+            Element::from(Value::from(1)).with_annotations(vec![unknown]),
+            Element::from(Value::Struct(fields)),
+        ];
+
+        let text = write_elements(&elements)?;
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(text)));
+        assert_eq!(load_all(&mut reader)?, elements);
+
+        let mut binary = Vec::new();
+        let mut writer = BinaryWriter::new(&mut binary);
+        for element in &elements {
+            element.write_to(&mut writer)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        let mut reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(binary)));
+        assert_eq!(load_all(&mut reader)?, elements);
+        Ok(())
+    }
+}
@@ -0,0 +1,525 @@
+//! Computes [Ion Hash](https://amzn.github.io/ion-hash/docs/spec.html) digests, which identify
+//! Ion values by their content rather than by their encoding. A value has the same digest
+//! whether it was read from text or binary Ion, regardless of the order of its struct fields or
+//! how its symbols were interned.
+//!
+//! ```
+//! use ion_rs::ion_hash::hash_value;
+//! use ion_rs::result::IonResult;
+//! use ion_rs::{Reader, TextIonCursor};
+//!
+//! # fn main() -> IonResult<()> {
+//! let text = r#"{name: "widget", sizes: [1, 2]} {sizes: [1, 2], name: "widget"} [1, 2]"#;
+//! let mut reader = Reader::new(TextIonCursor::new(std::io::Cursor::new(text.as_bytes())));
+//! reader.next()?;
+//! let first = hash_value(&mut reader)?;
+//! assert_eq!(first.len(), 32);
+//!
+//! // Values inside of a container can be hashed on their own.
+//! reader.next()?;
+//! reader.step_in()?;
+//! reader.next()?;
+//! assert_eq!(reader.field_name(), Some("sizes"));
+//! let sizes = hash_value(&mut reader)?;
+//! reader.next()?;
+//! assert_eq!(reader.field_name(), Some("name"));
+//! reader.step_out()?;
+//!
+//! reader.next()?;
+//! assert_eq!(hash_value(&mut reader)?, sizes);
+//! # Ok(())
+//! # }
+//! ```
+
+use sha2::{Digest, Sha256};
+
+use crate::binary::writer::{encode_decimal_representation, encode_timestamp_representation};
+use crate::binary::IonTypeCode;
+use crate::cursor::Cursor;
+use crate::element::non_null;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::types::{Integer, IonType, SymbolToken};
+use crate::Reader;
+
+const BEGIN_MARKER: u8 = 0x0B;
+const END_MARKER: u8 = 0x0E;
+const ESCAPE: u8 = 0x0C;
+
+// The type qualifier (TQ) byte of a serialized value combines a type code (T) with a qualifier (Q).
+const NULL_QUALIFIER: u8 = 0x0F;
+const ANNOTATED_VALUE_TQ: u8 = 0xE0;
+const UNKNOWN_SYMBOL_TQ: u8 = 0x71;
+
+// The representation used for all NaN values, whatever their payload.
+const CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+
+/// A hash function that can be used to compute Ion Hash digests. Structs and their fields are
+/// hashed separately, so a new IonHasher is created for each one.
+pub trait IonHasher {
+    /// Adds `bytes` to the data being hashed.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the digest of all of the bytes that have been passed to `update`.
+    fn finish(self) -> Vec<u8>;
+}
+
+/// The SHA-256 hash function used by [hash_value].
+#[derive(Clone, Default)]
+pub struct Sha256Hasher(Sha256);
+
+impl IonHasher for Sha256Hasher {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+/// Returns the SHA-256 Ion Hash of the value over which the Reader is currently positioned,
+/// including its annotations but not its field name. If the value is a container, the Reader
+/// will be positioned after it when this returns, as though [Reader::next] had skipped over it.
+///
+/// Returns an Err(IllegalOperation) if the Reader is not positioned over a value.
+pub fn hash_value<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Vec<u8>> {
+    hash_value_with(reader, Sha256Hasher::default)
+}
+
+/// Like [hash_value], but computes the digest using the IonHashers returned by `new_hasher`.
+pub fn hash_value_with<C, H, F>(reader: &mut Reader<C>, new_hasher: F) -> IonResult<Vec<u8>>
+where
+    C: Cursor,
+    H: IonHasher,
+    F: Fn() -> H,
+{
+    let ion_type = match reader.ion_type() {
+        Some(ion_type) => ion_type,
+        None => return illegal_operation("The Reader is not positioned over a value to hash."),
+    };
+    let mut hasher = new_hasher();
+    serialize_value(reader, ion_type, &mut hasher, &new_hasher)?;
+    Ok(hasher.finish())
+}
+
+// Passes the serialized form of the current value, including its annotations, to `hasher`.
+fn serialize_value<C, H, F>(
+    reader: &mut Reader<C>,
+    ion_type: IonType,
+    hasher: &mut H,
+    new_hasher: &F,
+) -> IonResult<()>
+where
+    C: Cursor,
+    H: IonHasher,
+    F: Fn() -> H,
+{
+    let annotations = reader.annotation_tokens()?;
+    if annotations.is_empty() {
+        return serialize_unannotated_value(reader, ion_type, hasher, new_hasher);
+    }
+    hasher.update(&[BEGIN_MARKER, ANNOTATED_VALUE_TQ]);
+    for annotation in &annotations {
+        serialize_symbol(hasher, annotation);
+    }
+    serialize_unannotated_value(reader, ion_type, hasher, new_hasher)?;
+    hasher.update(&[END_MARKER]);
+    Ok(())
+}
+
+fn serialize_unannotated_value<C, H, F>(
+    reader: &mut Reader<C>,
+    ion_type: IonType,
+    hasher: &mut H,
+    new_hasher: &F,
+) -> IonResult<()>
+where
+    C: Cursor,
+    H: IonHasher,
+    F: Fn() -> H,
+{
+    if reader.read_null()?.is_some() {
+        serialize_scalar(hasher, type_code(ion_type) | NULL_QUALIFIER, &[]);
+        return Ok(());
+    }
+    let tq = type_code(ion_type);
+    use IonType::*;
+    match ion_type {
+        Null => unreachable!("Value with IonType::Null returned is_null=false."),
+        Boolean => {
+            let value = non_null(reader.read_bool()?)?;
+            serialize_scalar(hasher, tq | value as u8, &[]);
+        }
+        Integer => {
+            let value = non_null(reader.read_integer()?)?;
+            let (is_negative, magnitude) = integer_magnitude(&value);
+            let tq = if is_negative {
+                (IonTypeCode::NegativeInteger as u8) << 4
+            } else {
+                tq
+            };
+            serialize_scalar(hasher, tq, &magnitude);
+        }
+        Float => {
+            let value = non_null(reader.read_f64()?)?;
+            // Floats are always serialized using 64 bits, and positive zero has no representation.
+            let representation = if value == 0.0f64 && value.is_sign_positive() {
+                vec![]
+            } else if value.is_nan() {
+                CANONICAL_NAN.to_be_bytes().to_vec()
+            } else {
+                value.to_be_bytes().to_vec()
+            };
+            serialize_scalar(hasher, tq, &representation);
+        }
+        Decimal => {
+            let value = non_null(reader.read_decimal()?)?;
+            let mut representation = vec![];
+            encode_decimal_representation(&mut representation, &value)?;
+            serialize_scalar(hasher, tq, &representation);
+        }
+        Timestamp => {
+            let value = non_null(reader.read_timestamp()?)?;
+            let mut representation = vec![];
+            encode_timestamp_representation(&mut representation, &value)?;
+            serialize_scalar(hasher, tq, &representation);
+        }
+        Symbol => serialize_symbol(hasher, &non_null(reader.read_symbol()?)?),
+        String => {
+            let text = non_null(reader.string_bytes_map(|bytes| bytes.to_vec())?)?;
+            serialize_scalar(hasher, tq, &text);
+        }
+        Clob => serialize_scalar(hasher, tq, &non_null(reader.read_clob_bytes()?)?),
+        Blob => serialize_scalar(hasher, tq, &non_null(reader.read_blob_bytes()?)?),
+        List | SExpression => {
+            hasher.update(&[BEGIN_MARKER, tq]);
+            reader.step_in()?;
+            while let Some((child_type, _is_null)) = reader.next()? {
+                serialize_value(reader, child_type, hasher, new_hasher)?;
+            }
+            reader.step_out()?;
+            hasher.update(&[END_MARKER]);
+        }
+        Struct => {
+            // Each field is hashed on its own. The resulting digests are sorted so that the
+            // struct's digest does not depend on the order of its fields.
+            hasher.update(&[BEGIN_MARKER, tq]);
+            let mut field_digests = vec![];
+            reader.step_in()?;
+            while let Some((child_type, _is_null)) = reader.next()? {
+                let field_name = match reader.field_name_token()? {
+                    Some(field_name) => field_name,
+                    None => return decoding_error("Found a struct field without a name."),
+                };
+                let mut field_hasher = new_hasher();
+                serialize_symbol(&mut field_hasher, &field_name);
+                serialize_value(reader, child_type, &mut field_hasher, new_hasher)?;
+                field_digests.push(field_hasher.finish());
+            }
+            reader.step_out()?;
+            field_digests.sort();
+            for digest in &field_digests {
+                update_escaped(hasher, digest);
+            }
+            hasher.update(&[END_MARKER]);
+        }
+    }
+    Ok(())
+}
+
+// Returns the high nibble of the TQ byte for values of the specified type. Integers are given
+// the type code for positive integers.
+fn type_code(ion_type: IonType) -> u8 {
+    use IonType::*;
+    let type_code = match ion_type {
+        Null => IonTypeCode::NullOrWhitespace,
+        Boolean => IonTypeCode::Boolean,
+        Integer => IonTypeCode::PositiveInteger,
+        Float => IonTypeCode::Float,
+        Decimal => IonTypeCode::Decimal,
+        Timestamp => IonTypeCode::Timestamp,
+        Symbol => IonTypeCode::Symbol,
+        String => IonTypeCode::String,
+        Clob => IonTypeCode::Clob,
+        Blob => IonTypeCode::Blob,
+        List => IonTypeCode::List,
+        SExpression => IonTypeCode::SExpression,
+        Struct => IonTypeCode::Struct,
+    };
+    (type_code as u8) << 4
+}
+
+// Returns whether the integer is negative along with the big-endian bytes of its magnitude.
+// Zero has an empty magnitude.
+fn integer_magnitude(value: &Integer) -> (bool, Vec<u8>) {
+    match value {
+        Integer::I64(value) => {
+            // wrapping_abs() leaves i64::MIN unchanged, but its bits are the correct u64 magnitude.
+            let bytes = (value.wrapping_abs() as u64).to_be_bytes();
+            let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+            (*value < 0, bytes[first_non_zero..].to_vec())
+        }
+        Integer::BigInt(value) => {
+            let (sign, magnitude) = value.to_bytes_be();
+            let magnitude = if magnitude == [0u8] {
+                vec![]
+            } else {
+                magnitude
+            };
+            (sign == num_bigint::Sign::Minus, magnitude)
+        }
+    }
+}
+
+// Symbols with unknown text are serialized with a distinct TQ and no representation.
+fn serialize_symbol<H: IonHasher>(hasher: &mut H, symbol: &SymbolToken) {
+    match symbol.text() {
+        Some(text) => serialize_scalar(hasher, type_code(IonType::Symbol), text.as_bytes()),
+        None => serialize_scalar(hasher, UNKNOWN_SYMBOL_TQ, &[]),
+    }
+}
+
+fn serialize_scalar<H: IonHasher>(hasher: &mut H, tq: u8, representation: &[u8]) {
+    hasher.update(&[BEGIN_MARKER, tq]);
+    update_escaped(hasher, representation);
+    hasher.update(&[END_MARKER]);
+}
+
+// Passes `bytes` to the hasher, preceding each marker or escape byte with an escape byte so that
+// it cannot be mistaken for the start or end of a value.
+fn update_escaped<H: IonHasher>(hasher: &mut H, bytes: &[u8]) {
+    let mut escaped = Vec::with_capacity(bytes.len());
+    for byte in bytes {
+        if let BEGIN_MARKER | END_MARKER | ESCAPE = *byte {
+            escaped.push(ESCAPE);
+        }
+        escaped.push(*byte);
+    }
+    hasher.update(&escaped);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::binary::writer::BinaryWriter;
+    use crate::result::IonResult;
+    use crate::{transcode, BinaryIonCursor, Cursor, Reader, TextIonCursor};
+
+    use super::*;
+
+    // Like the identity hasher used by the Ion Hash test suite, this returns the bytes it was
+    // given so that tests can check the serialized form of each value.
+    #[derive(Default)]
+    struct IdentityHasher(Vec<u8>);
+
+    impl IonHasher for IdentityHasher {
+        fn update(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+
+        fn finish(self) -> Vec<u8> {
+            self.0
+        }
+    }
+
+    fn text_reader(text: &str) -> Reader<TextIonCursor<io::Cursor<Vec<u8>>>> {
+        Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )))
+    }
+
+    // Returns the digest of each top-level value in the Reader's stream.
+    fn hash_all<C, H, F>(reader: &mut Reader<C>, new_hasher: F) -> IonResult<Vec<Vec<u8>>>
+    where
+        C: Cursor,
+        H: IonHasher,
+        F: Fn() -> H,
+    {
+        let mut digests = vec![];
+        while reader.next()?.is_some() {
+            digests.push(hash_value_with(reader, &new_hasher)?);
+        }
+        Ok(digests)
+    }
+
+    fn assert_identity_hashes(cases: &[(&str, &[u8])]) -> IonResult<()> {
+        for (text, expected) in cases {
+            let digests = hash_all(&mut text_reader(text), IdentityHasher::default)?;
+            assert_eq!(digests, vec![expected.to_vec()], "{}", text);
+        }
+        Ok(())
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn serialize_scalars() -> IonResult<()> {
+        assert_identity_hashes(&[
+            ("null", &[0x0B, 0x0F, 0x0E]),
+            ("null.bool", &[0x0B, 0x1F, 0x0E]),
+            ("false", &[0x0B, 0x10, 0x0E]),
+            ("true", &[0x0B, 0x11, 0x0E]),
+            ("null.int", &[0x0B, 0x2F, 0x0E]),
+            ("0", &[0x0B, 0x20, 0x0E]),
+            ("1", &[0x0B, 0x20, 0x01, 0x0E]),
+            ("-1", &[0x0B, 0x30, 0x01, 0x0E]),
+            // Marker bytes in a representation are escaped.
+            ("11", &[0x0B, 0x20, 0x0C, 0x0B, 0x0E]),
+            ("-12", &[0x0B, 0x30, 0x0C, 0x0C, 0x0E]),
+            (
+                "18446744073709551616",
+                &[0x0B, 0x20, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0x0E],
+            ),
+            ("null.float", &[0x0B, 0x4F, 0x0E]),
+            ("0e0", &[0x0B, 0x40, 0x0E]),
+            ("-0e0", &[0x0B, 0x40, 0x80, 0, 0, 0, 0, 0, 0, 0, 0x0E]),
+            ("1e0", &[0x0B, 0x40, 0x3F, 0xF0, 0, 0, 0, 0, 0, 0, 0x0E]),
+            ("nan", &[0x0B, 0x40, 0x7F, 0xF8, 0, 0, 0, 0, 0, 0, 0x0E]),
+            ("null.decimal", &[0x0B, 0x5F, 0x0E]),
+            ("0d0", &[0x0B, 0x50, 0x0E]),
+            ("-0d0", &[0x0B, 0x50, 0x80, 0x80, 0x0E]),
+            ("1.0", &[0x0B, 0x50, 0xC1, 0x0A, 0x0E]),
+            ("null.timestamp", &[0x0B, 0x6F, 0x0E]),
+            ("2017T", &[0x0B, 0x60, 0xC0, 0x0F, 0xE1, 0x0E]),
+            (
+                "2017-01-01T00:00Z",
+                &[0x0B, 0x60, 0x80, 0x0F, 0xE1, 0x81, 0x81, 0x80, 0x80, 0x0E],
+            ),
+            ("null.symbol", &[0x0B, 0x7F, 0x0E]),
+            ("hi", &[0x0B, 0x70, 0x68, 0x69, 0x0E]),
+            ("null.string", &[0x0B, 0x8F, 0x0E]),
+            ("\"hi\"", &[0x0B, 0x80, 0x68, 0x69, 0x0E]),
+            ("null.clob", &[0x0B, 0x9F, 0x0E]),
+            ("{{\"hi\"}}", &[0x0B, 0x90, 0x68, 0x69, 0x0E]),
+            ("null.blob", &[0x0B, 0xAF, 0x0E]),
+            ("{{aGk=}}", &[0x0B, 0xA0, 0x68, 0x69, 0x0E]),
+        ])
+    }
+
+    #[test]
+    fn serialize_containers_and_annotations() -> IonResult<()> {
+        assert_identity_hashes(&[
+            ("null.list", &[0x0B, 0xBF, 0x0E]),
+            ("[]", &[0x0B, 0xB0, 0x0E]),
+            ("null.sexp", &[0x0B, 0xCF, 0x0E]),
+            ("()", &[0x0B, 0xC0, 0x0E]),
+            ("null.struct", &[0x0B, 0xDF, 0x0E]),
+            ("{}", &[0x0B, 0xD0, 0x0E]),
+            (
+                "[1, (a), \"hi\"]",
+                &[
+                    0x0B, 0xB0, 0x0B, 0x20, 0x01, 0x0E, 0x0B, 0xC0, 0x0B, 0x70, 0x61, 0x0E, 0x0E,
+                    0x0B, 0x80, 0x68, 0x69, 0x0E, 0x0E,
+                ],
+            ),
+            (
+                "a::b::null",
+                &[
+                    0x0B, 0xE0, 0x0B, 0x70, 0x61, 0x0E, 0x0B, 0x70, 0x62, 0x0E, 0x0B, 0x0F, 0x0E,
+                    0x0E,
+                ],
+            ),
+            // Each field's digest is escaped before it is added to the struct's serialization.
+            (
+                "{a: b}",
+                &[
+                    0x0B, 0xD0, 0x0C, 0x0B, 0x70, 0x61, 0x0C, 0x0E, 0x0C, 0x0B, 0x70, 0x62, 0x0C,
+                    0x0E, 0x0E,
+                ],
+            ),
+            (
+                "a::{b: c::1}",
+                &[
+                    0x0B, 0xE0, 0x0B, 0x70, 0x61, 0x0E, 0x0B, 0xD0, 0x0C, 0x0B, 0x70, 0x62, 0x0C,
+                    0x0E, 0x0C, 0x0B, 0xE0, 0x0C, 0x0B, 0x70, 0x63, 0x0C, 0x0E, 0x0C, 0x0B, 0x20,
+                    0x01, 0x0C, 0x0E, 0x0C, 0x0E, 0x0E, 0x0E,
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn serialize_symbols_with_unknown_text() -> IonResult<()> {
+        assert_identity_hashes(&[
+            ("$0", &[0x0B, 0x71, 0x0E]),
+            (
+                "$0::null",
+                &[0x0B, 0xE0, 0x0B, 0x71, 0x0E, 0x0B, 0x0F, 0x0E, 0x0E],
+            ),
+            (
+                "{$0: 1}",
+                &[
+                    0x0B, 0xD0, 0x0C, 0x0B, 0x71, 0x0C, 0x0E, 0x0C, 0x0B, 0x20, 0x01, 0x0C, 0x0E,
+                    0x0E,
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn sha256_digests() -> IonResult<()> {
+        let digests = hash_all(
+            &mut text_reader("null {a: b} {a: 1, b: 2} {b: 2, a: 1}"),
+            Sha256Hasher::default,
+        )?;
+        let digests: Vec<String> = digests.iter().map(|digest| hex(digest)).collect();
+        assert_eq!(
+            digests,
+            vec![
+                "0fb06b6183c21379529fdd45d6af4aba731ac6f081ef9e6c1c94b1fb26177304",
+                "9ec757858ffdf8e698ea0387f62c3cae9894dfd3c70eeec863bbb1aa014e5cf5",
+                "82600fc9b40e3c5392ee8a7f8d149f0e96d6b7e6f739a8f516d2715a07b6ce6b",
+                "82600fc9b40e3c5392ee8a7f8d149f0e96d6b7e6f739a8f516d2715a07b6ce6b",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn text_and_binary_digests_match() -> IonResult<()> {
+        let text = r#"
+            null.int true -12345678901234567890 1.5e0 -0d3 2020-06-15T10:30:00.250-07:00
+            foo::'bar baz'::"qux" {{"clob"}} {{aGVsbG8=}}
+            annotated::[1, (a b c), {x: 1, y: [], x: null.symbol, "z": 2.50}]
+        "#;
+        let mut binary = Vec::new();
+        transcode(&mut text_reader(text), &mut BinaryWriter::new(&mut binary))?;
+        let mut binary_reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(binary)));
+
+        let text_digests = hash_all(&mut text_reader(text), Sha256Hasher::default)?;
+        assert_eq!(text_digests.len(), 10);
+        assert_eq!(
+            hash_all(&mut binary_reader, Sha256Hasher::default)?,
+            text_digests
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hash_nested_value() -> IonResult<()> {
+        let mut reader = text_reader("{a: [1, 2], b: 3} 4");
+        assert!(hash_value(&mut reader).is_err());
+
+        reader.next()?;
+        reader.step_in()?;
+        reader.next()?;
+        let digest = hash_value_with(&mut reader, IdentityHasher::default)?;
+        assert_eq!(
+            digest,
+            vec![0x0B, 0xB0, 0x0B, 0x20, 0x01, 0x0E, 0x0B, 0x20, 0x02, 0x0E, 0x0E]
+        );
+        // Hashing the list leaves the Reader ready to move on to the next field.
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("b"));
+        reader.step_out()?;
+        reader.next()?;
+        assert_eq!(
+            hash_value_with(&mut reader, IdentityHasher::default)?,
+            vec![0x0B, 0x20, 0x04, 0x0E]
+        );
+        Ok(())
+    }
+}
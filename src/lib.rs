@@ -5,20 +5,41 @@ extern crate failure_derive;
 
 pub mod result;
 
+pub mod analysis;
 pub mod binary;
 pub mod cursor;
 pub mod data_source;
+pub mod element;
+#[cfg(feature = "ion-hash")]
+pub mod ion_hash;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod text;
 pub mod types;
+pub mod writer;
 
+#[cfg(feature = "async")]
+mod async_reader;
+mod catalog;
 mod constants;
 mod reader;
 mod symbol_table;
+mod transcode;
 
-pub use binary::cursor::BinaryIonCursor;
+#[cfg(feature = "async")]
+pub use async_reader::AsyncReader;
+#[cfg(feature = "async")]
+pub use binary::async_cursor::AsyncBinaryIonCursor;
+pub use binary::cursor::{BinaryIonCursor, BinaryIonSliceCursor, LazyContainer};
+pub use binary::incremental::IncrementalBinaryCursor;
+pub use catalog::{Catalog, MapCatalog, SharedSymbolTable};
 pub use cursor::Cursor;
 pub use data_source::IonDataSource;
-pub use reader::Reader;
+pub use element::{Element, Value};
+pub use reader::{Reader, ReaderCheckpoint};
 pub use symbol_table::SymbolTable;
 pub use symbol_table::SymbolTableEventHandler;
+pub use text::cursor::TextIonCursor;
+pub use transcode::{transcode, transcode_with, TranscodeAction};
 pub use types::IonType;
+pub use writer::Writer;
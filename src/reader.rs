@@ -1,17 +1,24 @@
 use std::boxed::Box;
 use std::io;
+use std::sync::Arc;
 
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
 
 use delegate::delegate;
+use num_bigint::BigInt;
 
-use crate::constants::v1_0::system_symbol_ids;
-use crate::cursor::StreamItem::*;
-use crate::result::IonResult;
+use crate::binary::cursor::Checkpoint;
+use crate::catalog::{resolve_import, Catalog, ImportDescriptor, MapCatalog, SharedSymbolTable};
+use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
+use crate::cursor::{RawSymbolTokenRef, StreamItem::*};
+use crate::result::{decoding_error, IonResult};
 use crate::symbol_table::SymbolTable;
-use crate::types::SymbolId;
-use crate::{BinaryIonCursor, Cursor, IonType, SymbolTableEventHandler};
+use crate::types::{Decimal, Integer, SymbolId, SymbolToken, Timestamp};
+use crate::{
+    BinaryIonCursor, Cursor, IncrementalBinaryCursor, IonDataSource, IonType,
+    SymbolTableEventHandler, TextIonCursor,
+};
 
 /// A streaming Ion reader that resolves symbol IDs into the appropriate text.
 ///
@@ -21,6 +28,10 @@ pub struct Reader<C: Cursor> {
     cursor: C,
     symbol_table: SymbolTable,
     symtab_event_handler: Option<Box<dyn SymbolTableEventHandler>>,
+    catalog: Box<dyn Catalog>,
+    // A shared copy of the current symbol table for use by checkpoints. This is cleared whenever
+    // the symbol table changes.
+    symbol_table_snapshot: Option<Arc<SymbolTable>>,
 }
 
 impl<C: Cursor> Reader<C> {
@@ -29,9 +40,20 @@ impl<C: Cursor> Reader<C> {
             cursor,
             symbol_table: SymbolTable::new(),
             symtab_event_handler: None,
+            catalog: Box::new(MapCatalog::new()),
+            symbol_table_snapshot: None,
         }
     }
 
+    /// Sets the Catalog that will be used to resolve shared symbol table imports. By default, the
+    /// Reader uses an empty catalog, so every import's symbols will have unknown text.
+    pub fn set_catalog<K>(&mut self, catalog: K)
+    where
+        K: 'static + Catalog,
+    {
+        self.catalog = Box::new(catalog);
+    }
+
     /// Allows the user to specify an implementation of SymbolTableEventHandler to respond
     /// to otherwise internal events like symbol table imports and appends.
     // TODO: Boxing this type means that it is impossible to retrieve from the Reader later.
@@ -49,13 +71,26 @@ impl<C: Cursor> Reader<C> {
     pub fn next(&mut self) -> IonResult<Option<(IonType, bool)>> {
         loop {
             match self.cursor.next()? {
-                Some(VersionMarker) => {
+                Some(VersionMarker(major, minor)) => {
+                    // Each Ion version defines its own system symbol table; Ion 1.0 is the only
+                    // version that has been published.
+                    if (major, minor) != (1, 0) {
+                        return decoding_error(format!(
+                            "Unsupported Ion version {}.{}; only Ion 1.0 is supported.",
+                            major, minor
+                        ));
+                    }
                     self.symbol_table.reset();
+                    self.symbol_table_snapshot = None;
                 }
                 Some(Value(IonType::Struct, false)) => {
-                    if let [system_symbol_ids::ION_SYMBOL_TABLE, ..] = self.cursor.annotation_ids()
+                    // Local symbol tables are only recognized at the top level; elsewhere, an
+                    // annotated struct is user data.
+                    if self.cursor.depth() == 0
+                        && self.first_annotation_id() == Some(system_symbol_ids::ION_SYMBOL_TABLE)
                     {
                         self.read_symbol_table()?;
+                        self.symbol_table_snapshot = None;
                     } else {
                         return Ok(Some((IonType::Struct, false)));
                     }
@@ -70,32 +105,43 @@ impl<C: Cursor> Reader<C> {
         self.cursor.step_in()?;
 
         let mut is_append = false;
+        let mut imported_symbols = vec![];
         let mut new_symbols = vec![];
+        let mut found_imports = false;
+        let mut found_symbols = false;
 
         while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
-            let field_id = self
-                .cursor
-                .field_id()
-                .expect("No field ID found inside $ion_symbol_table struct.");
+            let field_id = self.cursor.raw_field_name().and_then(system_symbol_id);
+            // A local symbol table may define each of its `imports` and `symbols` fields at most
+            // once.
+            let found_field = match field_id {
+                Some(system_symbol_ids::IMPORTS) => Some(&mut found_imports),
+                Some(system_symbol_ids::SYMBOLS) => Some(&mut found_symbols),
+                _ => None,
+            };
+            if let Some(found_field) = found_field {
+                if *found_field {
+                    return decoding_error("Found a local symbol table with a repeated field.");
+                }
+                *found_field = true;
+            }
             match (field_id, ion_type, is_null) {
-                // TODO: This implementation only supports local symbol table imports and appends.
-                (system_symbol_ids::IMPORTS, IonType::Symbol, false) => {
-                    if self.cursor.read_symbol_id()?.unwrap() != 3 {
-                        unimplemented!("Can't handle non-$ion_symbol_table imports value.");
-                    }
-                    is_append = true;
+                (Some(system_symbol_ids::IMPORTS), IonType::Symbol, false) => {
+                    // `imports: $ion_symbol_table` appends to the current symbol table. Any other
+                    // symbol is ignored.
+                    let imports = self.cursor.read_raw_symbol()?.and_then(system_symbol_id);
+                    is_append = imports == Some(system_symbol_ids::ION_SYMBOL_TABLE);
                 }
-                (system_symbol_ids::SYMBOLS, IonType::List, false) => {
-                    self.cursor.step_in()?;
-                    while let Some(Value(IonType::String, false)) = self.cursor.next()? {
-                        let text = self.cursor.read_string()?.unwrap();
-                        new_symbols.push(text);
+                (Some(system_symbol_ids::IMPORTS), IonType::List, false) => {
+                    for import in self.read_imports()? {
+                        imported_symbols.push(resolve_import(self.catalog.as_ref(), &import)?);
                     }
-                    self.cursor.step_out()?;
                 }
-                something_else => {
-                    unimplemented!("No support for {:?}", something_else);
+                (Some(system_symbol_ids::SYMBOLS), IonType::List, false) => {
+                    new_symbols = self.read_symbol_list()?;
                 }
+                // Any other fields are ignored.
+                _ => {}
             }
         }
 
@@ -103,17 +149,24 @@ impl<C: Cursor> Reader<C> {
             // We're adding new symbols to the end of the symbol table.
             let new_ids_start = self.symbol_table.len();
             for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
+                let _id = self.symbol_table.add_symbol(new_symbol);
             }
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table and the ID of the first new symbol that was added.
             self.invoke_on_append_handler(new_ids_start);
         } else {
-            // The symbol table has been set by defining new symbols without importing the current
-            // symbol table.
+            // The symbol table has been set by importing shared symbol tables and/or defining new
+            // symbols without importing the current symbol table.
             self.symbol_table.reset();
+            for import in imported_symbols.drain(..) {
+                for symbol in import.symbols {
+                    let _id = self.symbol_table.add_symbol(symbol);
+                }
+                self.symbol_table
+                    .add_unknown_symbols(import.unknown_symbols)?;
+            }
             for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
+                let _id = self.symbol_table.add_symbol(new_symbol);
             }
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table so it can be inspected.
@@ -124,6 +177,126 @@ impl<C: Cursor> Reader<C> {
         Ok(())
     }
 
+    /// Reads the `$ion_shared_symbol_table` struct over which the cursor is positioned. Any
+    /// shared symbol tables that it imports are resolved using the provided catalog.
+    pub(crate) fn read_shared_symbol_table(
+        &mut self,
+        catalog: &dyn Catalog,
+    ) -> IonResult<SharedSymbolTable> {
+        self.cursor.step_in()?;
+
+        let mut name = None;
+        let mut version = None;
+        let mut imported_symbols = vec![];
+        let mut new_symbols = vec![];
+
+        while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+            let field_id = self.cursor.raw_field_name().and_then(system_symbol_id);
+            match (field_id, ion_type, is_null) {
+                (Some(system_symbol_ids::NAME), IonType::String, false) => {
+                    name = self.cursor.read_string()?;
+                }
+                (Some(system_symbol_ids::VERSION), IonType::Integer, false) => {
+                    version = self.cursor.read_integer()?.and_then(|v| v.as_i64());
+                }
+                (Some(system_symbol_ids::IMPORTS), IonType::List, false) => {
+                    for import in self.read_imports()? {
+                        imported_symbols.extend(resolve_import(catalog, &import)?.into_symbols()?);
+                    }
+                }
+                (Some(system_symbol_ids::SYMBOLS), IonType::List, false) => {
+                    new_symbols = self.read_symbol_list()?;
+                }
+                // Any other fields are ignored.
+                _ => {}
+            }
+        }
+        self.cursor.step_out()?;
+
+        let name = match name {
+            Some(name) if !name.is_empty() => name,
+            _ => return decoding_error("Found a shared symbol table without a name."),
+        };
+        // A missing or invalid version is treated as version 1.
+        let version = version.filter(|version| *version >= 1).unwrap_or(1) as usize;
+        imported_symbols.append(&mut new_symbols);
+        SharedSymbolTable::new(name, version, imported_symbols)
+    }
+
+    // Reads the list of import structs found in a symbol table's `imports` field.
+    fn read_imports(&mut self) -> IonResult<Vec<ImportDescriptor>> {
+        let mut imports = vec![];
+        self.cursor.step_in()?;
+        while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+            if ion_type != IonType::Struct || is_null {
+                continue;
+            }
+            let mut name = None;
+            let mut version = None;
+            let mut max_id = None;
+            self.cursor.step_in()?;
+            while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+                let field_id = self.cursor.raw_field_name().and_then(system_symbol_id);
+                match (field_id, ion_type, is_null) {
+                    (Some(system_symbol_ids::NAME), IonType::String, false) => {
+                        name = self.cursor.read_string()?;
+                    }
+                    (Some(system_symbol_ids::VERSION), IonType::Integer, false) => {
+                        version = self.cursor.read_integer()?.and_then(|v| v.as_i64());
+                    }
+                    (Some(system_symbol_ids::MAX_ID), IonType::Integer, false) => {
+                        max_id = self.cursor.read_integer()?.and_then(|v| v.as_i64());
+                    }
+                    _ => {}
+                }
+            }
+            self.cursor.step_out()?;
+            // Imports without a name are ignored, as are imports of the system symbol table,
+            // which is always imported implicitly.
+            let name = match name {
+                Some(name) if !name.is_empty() && name != "$ion" => name,
+                _ => continue,
+            };
+            imports.push(ImportDescriptor {
+                name,
+                // A missing or invalid version is treated as version 1.
+                version: version.filter(|version| *version >= 1).unwrap_or(1) as usize,
+                // A negative max_id is treated as if it were missing.
+                max_id: max_id
+                    .filter(|max_id| *max_id >= 0)
+                    .map(|max_id| max_id as usize),
+            });
+        }
+        self.cursor.step_out()?;
+        Ok(imports)
+    }
+
+    // Reads the list of symbols found in a symbol table's `symbols` field. Any value other than a
+    // non-null string declares a symbol with unknown text.
+    fn read_symbol_list(&mut self) -> IonResult<Vec<Option<String>>> {
+        let mut symbols = vec![];
+        self.cursor.step_in()?;
+        while let Some(Value(ion_type, is_null)) = self.cursor.next()? {
+            let text = match (ion_type, is_null) {
+                (IonType::String, false) => self.cursor.read_string()?,
+                _ => None,
+            };
+            symbols.push(text);
+        }
+        self.cursor.step_out()?;
+        Ok(symbols)
+    }
+
+    /// Returns the system symbol ID of the current value's first annotation, if it has one and
+    /// it refers to a system symbol.
+    pub(crate) fn first_annotation_id(&self) -> Option<SymbolId> {
+        self.cursor
+            .raw_annotations()
+            .first()
+            .copied()
+            .and_then(system_symbol_id)
+    }
+
     fn invoke_on_reset_handler(&mut self) {
         // Temporarily break apart 'self' to get simultaneous references to the symbol table
         // and the symtab event handler.
@@ -150,24 +323,65 @@ impl<C: Cursor> Reader<C> {
             .map(|h| h.on_append(symbol_table, new_ids_start));
     }
 
+    /// If the current value is a field within a struct, returns the text of its name. Returns
+    /// None if the name's text is unknown; use [Reader::field_name_token] to distinguish between
+    /// symbols with unknown text and invalid symbol IDs.
     pub fn field_name(&self) -> Option<&str> {
-        if let Some(id) = self.cursor.field_id() {
-            return self.symbol_table.text_for(id);
+        match self.cursor.raw_field_name()? {
+            RawSymbolTokenRef::SymbolId(sid) => self.symbol_table.text_for(sid),
+            RawSymbolTokenRef::Text(text) => Some(text),
         }
-        None
     }
 
+    /// If the current value is a field within a struct, returns its name as a SymbolToken.
+    /// Returns an Err if the field name's symbol ID is not defined in the current symbol table.
+    pub fn field_name_token(&self) -> IonResult<Option<SymbolToken>> {
+        self.cursor
+            .raw_field_name()
+            .map(|token| resolve_symbol(&self.symbol_table, token))
+            .transpose()
+    }
+
+    /// Returns the text of each of the current value's annotations. Annotations whose text is
+    /// unknown are skipped; use [Reader::annotation_tokens] to see all of them.
     pub fn annotations(&self) -> impl Iterator<Item = &str> {
         self.cursor
-            .annotation_ids()
-            .iter()
-            .map(move |sid| self.symbol_table.text_for(sid.clone()).unwrap())
+            .raw_annotations()
+            .into_iter()
+            .filter_map(move |token| match token {
+                RawSymbolTokenRef::SymbolId(sid) => self.symbol_table.text_for(sid),
+                RawSymbolTokenRef::Text(text) => Some(text),
+            })
+    }
+
+    /// Returns each of the current value's annotations as a SymbolToken. Returns an Err if any
+    /// of the annotations' symbol IDs are not defined in the current symbol table.
+    pub fn annotation_tokens(&self) -> IonResult<Vec<SymbolToken>> {
+        self.cursor
+            .raw_annotations()
+            .into_iter()
+            .map(|token| resolve_symbol(&self.symbol_table, token))
+            .collect()
+    }
+
+    /// If the current value is a symbol, resolves it using the current symbol table and returns
+    /// it as a SymbolToken. Returns an Err if the value's symbol ID is not defined in the current
+    /// symbol table.
+    pub fn read_symbol(&mut self) -> IonResult<Option<SymbolToken>> {
+        match self.cursor.read_raw_symbol()? {
+            Some(token) => resolve_symbol(&self.symbol_table, token).map(Some),
+            None => Ok(None),
+        }
     }
 
     pub fn symbol_table(&self) -> &SymbolTable {
         &self.symbol_table
     }
 
+    pub(crate) fn cursor(&self) -> &C {
+        &self.cursor
+    }
+
     // The Reader needs to expose many of the same functions as the Cursor, but only some of those
     // need to be re-defined to allow for system value processing. Any method listed here will be
     // delegated to self.cursor directly.
@@ -180,15 +394,20 @@ impl<C: Cursor> Reader<C> {
             pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
             pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
             pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            pub fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+            pub fn read_big_int(&mut self) -> IonResult<Option<BigInt>>;
             pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
             pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
             pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
             pub fn read_string(&mut self) -> IonResult<Option<String>>;
             pub fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&str) -> T;
             pub fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&[u8]) -> T;
             pub fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>>;
+            pub fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolTokenRef<'_>>>;
             pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
             pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
             pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
             pub fn step_in(&mut self) -> IonResult<()>;
             pub fn step_out(&mut self) -> IonResult<()>;
@@ -197,6 +416,111 @@ impl<C: Cursor> Reader<C> {
     }
 }
 
+/// Returns the system symbol ID that `token` refers to, whether it was encoded as a symbol ID or
+/// as text. Returns None if the token does not refer to a system symbol.
+fn system_symbol_id(token: RawSymbolTokenRef) -> Option<SymbolId> {
+    match token {
+        RawSymbolTokenRef::SymbolId(sid) => Some(sid),
+        RawSymbolTokenRef::Text(text) => SYSTEM_SYMBOLS.iter().position(|symbol| *symbol == text),
+    }
+}
+
+/// Resolves `token` into a SymbolToken using the provided symbol table. Symbol zero (`$0`) always
+/// has unknown text. Returns an Err if the token is a symbol ID greater than the table's max ID.
+fn resolve_symbol(symbol_table: &SymbolTable, token: RawSymbolTokenRef) -> IonResult<SymbolToken> {
+    match token {
+        RawSymbolTokenRef::Text(text) => Ok(SymbolToken::with_text(text)),
+        RawSymbolTokenRef::SymbolId(sid) if sid >= symbol_table.len() => decoding_error(format!(
+            "Found symbol ID ${}, but the current symbol table's max ID is {}.",
+            sid,
+            symbol_table.len() - 1
+        )),
+        RawSymbolTokenRef::SymbolId(sid) => Ok(SymbolToken::new(
+            symbol_table.text_for(sid).map(String::from),
+            Some(sid),
+        )),
+    }
+}
+
+/// A snapshot of a Reader's position in a binary Ion stream, including the symbol table that was
+/// active at that position. See [Reader::checkpoint] and [Reader::seek_to].
+#[derive(Clone, Debug)]
+pub struct ReaderCheckpoint {
+    cursor: Checkpoint,
+    symbol_table: Arc<SymbolTable>,
+}
+
+impl ReaderCheckpoint {
+    /// Returns the number of bytes the Reader had read from its data source when the checkpoint
+    /// was created.
+    pub fn offset(&self) -> usize {
+        self.cursor.offset()
+    }
+}
+
+impl<R: IonDataSource> Reader<BinaryIonCursor<R>> {
+    /// Returns the number of bytes that have been consumed from the data source.
+    pub fn bytes_read(&self) -> usize {
+        self.cursor.bytes_read()
+    }
+
+    /// Captures the Reader's current position in the stream along with the active symbol table.
+    /// Checkpoints created while the same symbol table is active share a single copy of it.
+    pub fn checkpoint(&mut self) -> ReaderCheckpoint {
+        let symbol_table = match &self.symbol_table_snapshot {
+            Some(snapshot) => Arc::clone(snapshot),
+            None => {
+                let snapshot = Arc::new(self.symbol_table.clone());
+                self.symbol_table_snapshot = Some(Arc::clone(&snapshot));
+                snapshot
+            }
+        };
+        ReaderCheckpoint {
+            cursor: self.cursor.checkpoint(),
+            symbol_table,
+        }
+    }
+}
+
+impl<R: IonDataSource> Reader<TextIonCursor<R>> {
+    /// Returns the number of bytes that have been consumed from the data source.
+    pub fn bytes_read(&self) -> usize {
+        self.cursor.bytes_read()
+    }
+}
+
+impl<R: IonDataSource + io::Seek> Reader<BinaryIonCursor<R>> {
+    /// Returns the Reader to the position captured by `checkpoint` and restores the symbol table
+    /// that was active there. If the symbol table changes as a result, the symtab event handler's
+    /// `on_reset` method will be called.
+    pub fn seek_to(&mut self, checkpoint: &ReaderCheckpoint) -> IonResult<()> {
+        self.cursor.seek_to(&checkpoint.cursor)?;
+        let is_same_table = match &self.symbol_table_snapshot {
+            Some(snapshot) => Arc::ptr_eq(snapshot, &checkpoint.symbol_table),
+            None => false,
+        };
+        if !is_same_table {
+            self.symbol_table = checkpoint.symbol_table.as_ref().clone();
+            self.symbol_table_snapshot = Some(Arc::clone(&checkpoint.symbol_table));
+            self.invoke_on_reset_handler();
+        }
+        Ok(())
+    }
+}
+
+impl Reader<IncrementalBinaryCursor> {
+    /// Appends `bytes` to the data available to the Reader. See [IncrementalBinaryCursor] for
+    /// details on reading partial input.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.cursor.push(bytes);
+    }
+
+    /// Indicates that no more data will be pushed to the Reader.
+    pub fn end_of_stream(&mut self) {
+        self.cursor.end_of_stream();
+    }
+}
+
 /// Functionality that is only available if the data source we're reading from is in-memory, like
 /// a Vec<u8> or &[u8].
 impl<T: AsRef<[u8]>> Reader<BinaryIonCursor<io::Cursor<T>>> {
@@ -206,16 +530,33 @@ impl<T: AsRef<[u8]>> Reader<BinaryIonCursor<io::Cursor<T>>> {
     }
 }
 
+/// Functionality that is only available if the data source is a byte slice. See
+/// [BinaryIonSliceCursor](crate::BinaryIonSliceCursor).
+impl<'a> Reader<BinaryIonCursor<io::Cursor<&'a [u8]>>> {
+    delegate! {
+        to self.cursor {
+            pub fn read_str(&mut self) -> IonResult<Option<&'a str>>;
+            pub fn read_blob(&mut self) -> IonResult<Option<&'a [u8]>>;
+            pub fn read_clob(&mut self) -> IonResult<Option<&'a [u8]>>;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::io;
 
     use crate::binary::constants::v1_0::IVM;
     use crate::binary::cursor::BinaryIonCursor;
+    use crate::constants::v1_0::SYSTEM_SYMBOLS;
     use crate::cursor::{Cursor, StreamItem::*};
-    use crate::result::IonResult;
-    use crate::types::IonType;
-    use crate::{Reader, SymbolTable, SymbolTableEventHandler};
+    use crate::result::{IonError, IonResult};
+    use crate::text::cursor::TextIonCursor;
+    use crate::types::{IonType, SymbolToken};
+    use crate::{
+        IncrementalBinaryCursor, MapCatalog, Reader, SharedSymbolTable, SymbolTable,
+        SymbolTableEventHandler,
+    };
 
     type TestDataSource = io::Cursor<Vec<u8>>;
 
@@ -237,7 +578,7 @@ mod tests {
     fn ion_cursor_for(bytes: &[u8]) -> BinaryIonCursor<TestDataSource> {
         let mut binary_cursor = BinaryIonCursor::new(data_source_for(bytes));
         assert_eq!(binary_cursor.ion_type(), None);
-        assert_eq!(binary_cursor.next(), Ok(Some(VersionMarker)));
+        assert_eq!(binary_cursor.next(), Ok(Some(VersionMarker(1, 0))));
         assert_eq!(binary_cursor.ion_version(), (1u8, 0u8));
         binary_cursor
     }
@@ -249,7 +590,7 @@ mod tests {
     const EXAMPLE_STREAM: &[u8] = &[
         // $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["foo", "bar", "baz"]}
         0xEE, // Var len annotations
-        0x94, // Annotations + Value length: 20 bytes
+        0x95, // Annotations + Value length: 21 bytes
         0x81, // Annotations length: 1
         0x83, // Annotation 3 ('$ion_symbol_table')
         0xDE, // Var len struct
@@ -277,9 +618,9 @@ mod tests {
         fn on_append<'a>(&'a mut self, symbol_table: &'a SymbolTable, starting_id: usize) {
             let new_symbols = symbol_table.symbols_tail(starting_id);
             assert_eq!(3, new_symbols.len());
-            assert_eq!("foo", new_symbols[0].as_str());
-            assert_eq!("bar", new_symbols[1].as_str());
-            assert_eq!("baz", new_symbols[2].as_str());
+            assert_eq!(Some("foo"), new_symbols[0].as_deref());
+            assert_eq!(Some("bar"), new_symbols[1].as_deref());
+            assert_eq!(Some("baz"), new_symbols[2].as_deref());
         }
 
         fn on_reset<'a>(&'a mut self, _symbol_table: &'a SymbolTable) {
@@ -307,4 +648,268 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_text_struct() -> IonResult<()> {
+        let text = r#"
+            $ion_1_0
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["foo", "bar"]}
+            ann::{$10: 1, bar: 2, 'baz': 3}
+        "#;
+        let cursor = TextIonCursor::new(io::Cursor::new(text.as_bytes().to_vec()));
+        let mut reader = Reader::new(cursor);
+
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        assert_eq!(reader.annotations().collect::<Vec<_>>(), vec!["ann"]);
+        reader.step_in()?;
+
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("bar"));
+
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("baz"));
+
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_marker_resets_symbol_table() -> IonResult<()> {
+        // The local symbol table from EXAMPLE_STREAM, then an IVM, then the symbol $10
+        let mut data = EXAMPLE_STREAM[..23].to_vec();
+        data.extend_from_slice(&IVM);
+        data.extend_from_slice(&[0x71, 0x0A]);
+        let mut reader = ion_reader_for(&data);
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.symbol_table().len(), SYSTEM_SYMBOLS.len());
+        assert_eq!(reader.symbol_table().text_for(10), None);
+        Ok(())
+    }
+
+    fn text_reader_for(text: &str) -> Reader<TextIonCursor<TestDataSource>> {
+        Reader::new(TextIonCursor::new(io::Cursor::new(
+            text.as_bytes().to_vec(),
+        )))
+    }
+
+    #[test]
+    fn test_import_shared_symbol_tables() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{
+                imports: [
+                    {name: "foo", version: 1},
+                    {name: "bar", version: 1, max_id: 2},
+                    {name: "$ion", version: 1, max_id: 100},
+                ],
+                symbols: ["quux", null.string, 7],
+            }
+            $10 $11 $12 $13
+        "#;
+        let mut catalog = MapCatalog::new();
+        let foo_symbols = vec![Some("a".to_string()), Some("b".to_string())];
+        catalog.insert_table(SharedSymbolTable::new("foo", 1, foo_symbols)?);
+        let mut reader = text_reader_for(text);
+        reader.set_catalog(catalog);
+
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let symbol_table = reader.symbol_table();
+        // 9 system symbols, 2 from "foo", 2 unknown from "bar" and 3 local symbols. The "$ion"
+        // import is ignored.
+        assert_eq!(symbol_table.len(), SYSTEM_SYMBOLS.len() + 7);
+        assert_eq!(symbol_table.text_for(10), Some("a"));
+        assert_eq!(symbol_table.text_for(11), Some("b"));
+        assert_eq!(symbol_table.text_for(12), None);
+        assert_eq!(symbol_table.text_for(13), None);
+        assert_eq!(symbol_table.text_for(14), Some("quux"));
+        assert_eq!(symbol_table.text_for(15), None);
+        assert_eq!(symbol_table.text_for(16), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_missing_table_without_max_id() {
+        let text = r#"
+            $ion_symbol_table::{imports: [{name: "foo", version: 1}]}
+            $10
+        "#;
+        let mut reader = text_reader_for(text);
+        assert!(reader.next().is_err());
+    }
+
+    #[test]
+    fn test_import_huge_max_id() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{
+                imports: [{name: "x", version: 1, max_id: 4000000000000}],
+                symbols: ["foo"],
+            }
+            $10
+            $4000000000010
+        "#;
+        let mut reader = text_reader_for(text);
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(
+            reader.read_symbol()?,
+            Some(SymbolToken::new(None, Some(10)))
+        );
+        assert_eq!(
+            reader.symbol_table().len(),
+            SYSTEM_SYMBOLS.len() + 4_000_000_000_001
+        );
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?.unwrap().text(), Some("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_repeated_symbol_table_field() {
+        let text = r#"
+            $ion_symbol_table::{symbols: ["foo"], symbols: ["bar"]}
+            $10
+        "#;
+        let mut reader = text_reader_for(text);
+        assert!(reader.next().is_err());
+    }
+
+    #[test]
+    fn test_nested_symbol_table_is_user_data() -> IonResult<()> {
+        let text = r#"
+            {a: $ion_symbol_table::{symbols: ["x"]}}
+            $10
+        "#;
+        let mut reader = text_reader_for(text);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.field_name(), Some("a"));
+        assert_eq!(
+            reader.annotations().collect::<Vec<_>>(),
+            vec!["$ion_symbol_table"]
+        );
+        reader.step_out()?;
+        // The nested struct did not change the symbol table, so $10 is out of range.
+        assert_eq!(reader.symbol_table().len(), SYSTEM_SYMBOLS.len());
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert!(reader.read_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_tokens() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $0::$10::{$10: $10, $0: $0, 'bar': bar}
+            $11
+        "#;
+        let mut reader = text_reader_for(text);
+        let foo = SymbolToken::new(Some("foo".to_string()), Some(10));
+        let zero = SymbolToken::new(None, Some(0));
+
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.annotation_tokens()?, vec![zero.clone(), foo.clone()]);
+        assert_eq!(reader.annotations().collect::<Vec<_>>(), vec!["foo"]);
+        reader.step_in()?;
+
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.field_name_token()?, Some(foo.clone()));
+        assert_eq!(reader.read_symbol()?, Some(foo));
+
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.field_name(), None);
+        assert_eq!(reader.field_name_token()?, Some(zero.clone()));
+        assert_eq!(reader.read_symbol()?, Some(zero));
+
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(
+            reader.field_name_token()?,
+            Some(SymbolToken::with_text("bar"))
+        );
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("bar")));
+
+        reader.step_out()?;
+        // $11 is beyond the symbol table's max ID
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert!(reader.read_symbol().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_out_of_range() -> IonResult<()> {
+        // $11::1, which is beyond the system symbol table's max ID
+        let mut reader = ion_reader_for(&[0xE4, 0x81, 0x8B, 0x21, 0x01]);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert!(reader.annotation_tokens().is_err());
+        assert_eq!(reader.annotations().count(), 0);
+        assert_eq!(reader.read_i64()?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_restores_symbol_table() -> IonResult<()> {
+        let mut data = vec![];
+        // $ion_symbol_table::{symbols: ["foo"]} $10
+        data.extend_from_slice(&[0xE9, 0x81, 0x83, 0xD6, 0x87, 0xB4, 0x83, b'f', b'o', b'o']);
+        data.extend_from_slice(&[0x71, 0x0A]);
+        // $ion_symbol_table::{symbols: ["bar"]} $10
+        data.extend_from_slice(&[0xE9, 0x81, 0x83, 0xD6, 0x87, 0xB4, 0x83, b'b', b'a', b'r']);
+        data.extend_from_slice(&[0x71, 0x0A]);
+        let mut reader = ion_reader_for(&data);
+
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let foo = reader.checkpoint();
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("foo")));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let bar = reader.checkpoint();
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("bar")));
+        assert_eq!(reader.next()?, None);
+
+        reader.seek_to(&foo)?;
+        assert_eq!(reader.symbol_table().text_for(10), Some("foo"));
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("foo")));
+        // Reading on from the checkpoint processes the second symbol table again
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("bar")));
+
+        reader.seek_to(&bar)?;
+        assert_eq!(reader.read_symbol()?, Some(SymbolToken::with_text("bar")));
+        assert!(bar.offset() > foo.offset());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_borrowed_str() -> IonResult<()> {
+        let data = ion_data(&[0x83, b'f', b'o', b'o']);
+        let mut reader = Reader::new(BinaryIonCursor::from_slice(&data));
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        let text = reader.read_str()?;
+        drop(reader);
+        assert_eq!(text, Some("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_symbol_table() -> IonResult<()> {
+        let data = ion_data(EXAMPLE_STREAM);
+        let mut reader = Reader::new(IncrementalBinaryCursor::new());
+        // Split the stream partway through the symbol table
+        reader.push(&data[..12]);
+        assert!(matches!(reader.next(), Err(IonError::Incomplete { .. })));
+        assert_eq!(reader.symbol_table().len(), SYSTEM_SYMBOLS.len());
+        reader.push(&data[12..]);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        reader.step_out()?;
+        assert!(matches!(reader.next(), Err(IonError::Incomplete { .. })));
+        reader.end_of_stream();
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
 }
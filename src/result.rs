@@ -22,6 +22,15 @@ pub enum IonError {
         description
     )]
     IllegalOperation { description: String },
+
+    /// Returned by incremental readers when the data that has been provided so far ends partway
+    /// through a value. The reader's position is unchanged; the operation can be retried once
+    /// more data is available.
+    #[fail(
+        display = "Not enough data is available to complete the operation: {}",
+        description
+    )]
+    Incomplete { description: String },
 }
 
 /// A convenience method for creating an IonResult containing an IonError::IoError with the
@@ -40,6 +49,14 @@ pub fn decoding_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
     })
 }
 
+/// A convenience method for creating an IonResult containing an IonError::Incomplete with the
+/// provided description text.
+pub fn incomplete_data_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
+    Err(IonError::Incomplete {
+        description: description.as_ref().to_string(),
+    })
+}
+
 /// A convenience method for creating an IonResult containing an IonError::IllegalOperation with the
 /// provided description text.
 pub fn illegal_operation<T, S: AsRef<str>>(description: S) -> IonResult<T> {
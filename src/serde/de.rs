@@ -0,0 +1,510 @@
+use std::convert::TryFrom;
+use std::io;
+
+use ::serde::de::value::StringDeserializer;
+use ::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    Unexpected, VariantAccess, Visitor,
+};
+use ::serde::forward_to_deserialize_any;
+
+use crate::binary::constants::v1_0::IVM;
+use crate::cursor::Cursor;
+use crate::result::IonResult;
+use crate::serde::types::{DECIMAL_NEWTYPE_NAME, TIMESTAMP_NEWTYPE_NAME};
+use crate::serde::{Error, Result};
+use crate::types::{Integer, IonType, SymbolToken};
+use crate::{BinaryIonCursor, Reader, TextIonCursor};
+
+/// Deserializes the next top-level value in the provided Reader. Any values that follow it are
+/// left in the stream.
+pub fn from_reader<C: Cursor, T: DeserializeOwned>(reader: &mut Reader<C>) -> Result<T> {
+    match Deserializer::new(reader).next_value()? {
+        Some(value) => Ok(value),
+        None => Err(Error::Message(
+            "Expected a value, but the stream was exhausted.".to_string(),
+        )),
+    }
+}
+
+/// Deserializes a stream containing exactly one top-level value. The stream is read as binary
+/// Ion if it begins with an Ion version marker; otherwise, it is read as text Ion.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    if bytes.starts_with(&IVM) {
+        from_single_value(Reader::new(BinaryIonCursor::new(io::Cursor::new(bytes))))
+    } else {
+        from_single_value(Reader::new(TextIonCursor::new(io::Cursor::new(bytes))))
+    }
+}
+
+fn from_single_value<C: Cursor, T: DeserializeOwned>(mut reader: Reader<C>) -> Result<T> {
+    let value = from_reader(&mut reader)?;
+    if reader.next()?.is_some() {
+        return Err(Error::Message(
+            "Expected a single value, but the stream contained more than one.".to_string(),
+        ));
+    }
+    Ok(value)
+}
+
+/// A serde Deserializer that reads values from a [Reader].
+pub struct Deserializer<'a, C: Cursor> {
+    reader: &'a mut Reader<C>,
+    // The type and nullness of the value over which the Reader is positioned, if any.
+    current: Option<(IonType, bool)>,
+    // The number of the current value's annotations that have been used as enum variant names.
+    annotations_consumed: usize,
+}
+
+impl<'a, C: Cursor> Deserializer<'a, C> {
+    pub fn new(reader: &'a mut Reader<C>) -> Deserializer<'a, C> {
+        Deserializer {
+            reader,
+            current: None,
+            annotations_consumed: 0,
+        }
+    }
+
+    /// Advances the Reader to the next value at its current depth and deserializes it. Returns
+    /// None if there are no more values at the current depth.
+    pub fn next_value<T: DeserializeOwned>(&mut self) -> Result<Option<T>> {
+        if !self.next()? {
+            return Ok(None);
+        }
+        T::deserialize(self).map(Some)
+    }
+
+    fn next(&mut self) -> Result<bool> {
+        self.current = self.reader.next()?;
+        self.annotations_consumed = 0;
+        Ok(self.current.is_some())
+    }
+
+    fn current(&self) -> Result<(IonType, bool)> {
+        self.current.ok_or_else(|| {
+            Error::Message("The Deserializer is not positioned over a value.".to_string())
+        })
+    }
+
+    fn read_text(&mut self, ion_type: IonType) -> Result<String> {
+        match ion_type {
+            IonType::Symbol => known_text(non_null(self.reader.read_symbol())?),
+            _ => non_null(self.reader.read_string()),
+        }
+    }
+
+    fn visit_sequence<'de, V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.reader.step_in()?;
+        let value = visitor.visit_seq(SequenceAccess { de: self })?;
+        self.reader.step_out()?;
+        Ok(value)
+    }
+
+    fn visit_struct<'de, V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        self.reader.step_in()?;
+        let value = visitor.visit_map(StructAccess { de: self })?;
+        self.reader.step_out()?;
+        Ok(value)
+    }
+}
+
+// The Reader's read_* methods only return None for null values, which the Deserializer checks
+// for before calling them.
+fn non_null<T>(value: IonResult<Option<T>>) -> Result<T> {
+    value?.ok_or_else(|| Error::Message("Unexpectedly encountered a null value.".to_string()))
+}
+
+fn known_text(token: SymbolToken) -> Result<String> {
+    match token.text() {
+        Some(text) => Ok(text.to_string()),
+        None => Err(Error::Message(format!(
+            "Cannot deserialize symbol {} because its text is unknown.",
+            token
+        ))),
+    }
+}
+
+fn unexpected(ion_type: IonType) -> Unexpected<'static> {
+    use IonType::*;
+    let name = match ion_type {
+        Null => "Ion null",
+        Boolean => "Ion bool",
+        Integer => "Ion int",
+        Float => "Ion float",
+        Decimal => "Ion decimal",
+        Timestamp => "Ion timestamp",
+        Symbol => "Ion symbol",
+        String => "Ion string",
+        Clob => "Ion clob",
+        Blob => "Ion blob",
+        List => "Ion list",
+        SExpression => "Ion sexp",
+        Struct => "Ion struct",
+    };
+    Unexpected::Other(name)
+}
+
+impl<'de, 'b, 'a, C: Cursor> de::Deserializer<'de> for &'b mut Deserializer<'a, C> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let (ion_type, is_null) = self.current()?;
+        if is_null {
+            return visitor.visit_unit();
+        }
+        match ion_type {
+            IonType::Null => visitor.visit_unit(),
+            IonType::Boolean => visitor.visit_bool(non_null(self.reader.read_bool())?),
+            IonType::Integer => match non_null(self.reader.read_integer())? {
+                Integer::I64(value) => visitor.visit_i64(value),
+                Integer::BigInt(value) => {
+                    if let Ok(value) = u64::try_from(&value) {
+                        visitor.visit_u64(value)
+                    } else if let Ok(value) = i128::try_from(&value) {
+                        visitor.visit_i128(value)
+                    } else if let Ok(value) = u128::try_from(&value) {
+                        visitor.visit_u128(value)
+                    } else {
+                        Err(Error::Message(format!(
+                            "Integer {} is too large to deserialize.",
+                            value
+                        )))
+                    }
+                }
+            },
+            IonType::Float => visitor.visit_f64(non_null(self.reader.read_f64())?),
+            IonType::Decimal => {
+                let decimal = non_null(self.reader.read_decimal())?;
+                // Writing the coefficient's sign separately preserves negative zero.
+                let sign = if decimal.is_negative() { "-" } else { "" };
+                let text = format!("{}{}e{}", sign, decimal.magnitude(), decimal.exponent());
+                match text.parse::<f64>() {
+                    Ok(value) => visitor.visit_f64(value),
+                    Err(_) => Err(Error::Message(format!(
+                        "Decimal {} cannot be represented as an f64.",
+                        decimal
+                    ))),
+                }
+            }
+            IonType::Timestamp => {
+                visitor.visit_string(non_null(self.reader.read_timestamp())?.to_string())
+            }
+            IonType::Symbol | IonType::String => visitor.visit_string(self.read_text(ion_type)?),
+            IonType::Clob => visitor.visit_byte_buf(non_null(self.reader.read_clob_bytes())?),
+            IonType::Blob => visitor.visit_byte_buf(non_null(self.reader.read_blob_bytes())?),
+            IonType::List | IonType::SExpression => self.visit_sequence(visitor),
+            IonType::Struct => self.visit_struct(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.current()? {
+            (_, true) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.current()? {
+            (_, true) => visitor.visit_unit(),
+            (ion_type, false) => Err(de::Error::invalid_type(unexpected(ion_type), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        // IonTimestamp and IonDecimal are passed the Ion text representation of their values.
+        match (name, self.current()?) {
+            (TIMESTAMP_NEWTYPE_NAME, (IonType::Timestamp, false)) => {
+                visitor.visit_str(&non_null(self.reader.read_timestamp())?.to_string())
+            }
+            (DECIMAL_NEWTYPE_NAME, (IonType::Decimal, false)) => {
+                visitor.visit_str(&non_null(self.reader.read_decimal())?.to_string())
+            }
+            (DECIMAL_NEWTYPE_NAME, (IonType::Integer, false)) => {
+                visitor.visit_str(&format!("{}d0", non_null(self.reader.read_integer())?))
+            }
+            (TIMESTAMP_NEWTYPE_NAME, (ion_type, _)) | (DECIMAL_NEWTYPE_NAME, (ion_type, _)) => {
+                Err(de::Error::invalid_type(unexpected(ion_type), &visitor))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        // Enum variants with data are written as annotated values. Each annotation is only used
+        // once so that a variant's value can itself be an annotated enum.
+        let annotations = self.reader.annotation_tokens()?;
+        if let Some(annotation) = annotations.into_iter().nth(self.annotations_consumed) {
+            self.annotations_consumed += 1;
+            let variant = known_text(annotation)?;
+            return visitor.visit_enum(AnnotatedVariant { de: self, variant });
+        }
+        // Unit variants are written as symbols.
+        match self.current()? {
+            (ion_type @ IonType::Symbol, false) | (ion_type @ IonType::String, false) => {
+                let variant: StringDeserializer<Error> =
+                    self.read_text(ion_type)?.into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            (ion_type, _) => Err(de::Error::invalid_type(unexpected(ion_type), &visitor)),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // The Reader will skip over the value when it is advanced.
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct SequenceAccess<'b, 'a, C: Cursor> {
+    de: &'b mut Deserializer<'a, C>,
+}
+
+impl<'de, 'b, 'a, C: Cursor> SeqAccess<'de> for SequenceAccess<'b, 'a, C> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if !self.de.next()? {
+            return Ok(None);
+        }
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+}
+
+struct StructAccess<'b, 'a, C: Cursor> {
+    de: &'b mut Deserializer<'a, C>,
+}
+
+impl<'de, 'b, 'a, C: Cursor> MapAccess<'de> for StructAccess<'b, 'a, C> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if !self.de.next()? {
+            return Ok(None);
+        }
+        let field_name = match self.de.reader.field_name_token()? {
+            Some(token) => known_text(token)?,
+            None => {
+                return Err(Error::Message(
+                    "Found a struct field without a name.".to_string(),
+                ))
+            }
+        };
+        let field_name: StringDeserializer<Error> = field_name.into_deserializer();
+        seed.deserialize(field_name).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+// An enum variant whose name was found in an annotation.
+struct AnnotatedVariant<'b, 'a, C: Cursor> {
+    de: &'b mut Deserializer<'a, C>,
+    variant: String,
+}
+
+impl<'de, 'b, 'a, C: Cursor> EnumAccess<'de> for AnnotatedVariant<'b, 'a, C> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant: StringDeserializer<Error> = self.variant.clone().into_deserializer();
+        Ok((seed.deserialize(variant)?, self))
+    }
+}
+
+impl<'de, 'b, 'a, C: Cursor> VariantAccess<'de> for AnnotatedVariant<'b, 'a, C> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_seq(&mut *self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_map(&mut *self.de, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::io;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::binary::constants::v1_0::IVM;
+    use crate::serde::{from_reader, from_slice, to_string, IonDecimal, IonTimestamp};
+    use crate::types::{Decimal, Timestamp};
+    use crate::{Reader, TextIonCursor};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Rectangle(u32, u32),
+        Polygon { sides: u8, length: u32 },
+        Nested(Option<Box<Shape>>),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Drawing {
+        title: String,
+        shapes: Vec<Shape>,
+        scale: Option<f64>,
+        tags: BTreeMap<String, i64>,
+        #[serde(with = "serde_bytes")]
+        thumbnail: Vec<u8>,
+        created: IonTimestamp,
+        price: IonDecimal,
+    }
+
+    fn drawing() -> Drawing {
+        let mut tags = BTreeMap::new();
+        tags.insert("layer".to_string(), 2);
+        let created = Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 0)
+            .and_then(|t| t.with_offset(-300))
+            .unwrap();
+        Drawing {
+            title: "sketch".to_string(),
+            shapes: vec![
+                Shape::Point,
+                Shape::Circle(5),
+                Shape::Rectangle(2, 3),
+                Shape::Polygon {
+                    sides: 6,
+                    length: 10,
+                },
+                Shape::Nested(Some(Box::new(Shape::Circle(1)))),
+                Shape::Nested(None),
+            ],
+            scale: None,
+            tags,
+            thumbnail: vec![1, 2, 3],
+            created: IonTimestamp(created),
+            price: IonDecimal(Decimal::new(1990, -2)),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let original = drawing();
+        let text = to_string(&original).unwrap();
+        let round_tripped: Drawing = from_slice(text.as_bytes()).unwrap();
+        assert_eq!(original, round_tripped);
+    }
+
+    #[test]
+    fn deserialize_from_text() {
+        let text = r#"
+            {
+                title: sketch,
+                shapes: [Point, Circle::5, Rectangle::(2 3), Nested::Nested::Point],
+                scale: null.float,
+                tags: {},
+                thumbnail: {{AQID}},
+                created: 2020-06-15T,
+                price: 20,
+                ignored: [1, 2, 3],
+            }
+        "#;
+        let drawing: Drawing = from_slice(text.as_bytes()).unwrap();
+        assert_eq!(drawing.title, "sketch");
+        assert_eq!(
+            drawing.shapes,
+            vec![
+                Shape::Point,
+                Shape::Circle(5),
+                Shape::Rectangle(2, 3),
+                Shape::Nested(Some(Box::new(Shape::Nested(Some(Box::new(Shape::Point)))))),
+            ]
+        );
+        assert_eq!(drawing.scale, None);
+        assert_eq!(drawing.thumbnail, vec![1, 2, 3]);
+        assert_eq!(drawing.created.0, Timestamp::with_ymd(2020, 6, 15).unwrap());
+        assert_eq!(drawing.price, IonDecimal(Decimal::new(20, 0)));
+    }
+
+    #[test]
+    fn decimals_preserve_negative_zero() {
+        for text in &["-0d0", "-0.00"] {
+            let decimal: IonDecimal = from_slice(text.as_bytes()).unwrap();
+            assert!(decimal.0.is_negative() && decimal.0.is_zero());
+            let round_tripped: IonDecimal =
+                from_slice(to_string(&decimal).unwrap().as_bytes()).unwrap();
+            assert_eq!(round_tripped, decimal);
+        }
+        let decimal: IonDecimal = from_slice(b"-0.00").unwrap();
+        assert_eq!(
+            decimal,
+            IonDecimal(Decimal::negative_zero_with_exponent(-2))
+        );
+        let value: f64 = from_slice(b"-0d0").unwrap();
+        assert!(value == 0.0 && value.is_sign_negative());
+    }
+
+    #[test]
+    fn deserialize_numbers() {
+        assert_eq!(from_slice::<f64>(b"1.5").unwrap(), 1.5);
+        assert_eq!(from_slice::<f64>(b"3").unwrap(), 3.0);
+        assert_eq!(
+            from_slice::<u64>(b"18446744073709551615").unwrap(),
+            u64::MAX
+        );
+        assert!(from_slice::<u8>(b"256").is_err());
+        assert!(from_slice::<i64>(b"\"5\"").is_err());
+    }
+
+    #[test]
+    fn deserialize_binary() {
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(&[0x21, 0x05]);
+        assert_eq!(from_slice::<i64>(&data).unwrap(), 5);
+    }
+
+    #[test]
+    fn deserialize_stream() {
+        let text = "1 2 3";
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(text.as_bytes())));
+        assert_eq!(from_reader::<_, i64>(&mut reader).unwrap(), 1);
+        assert_eq!(from_reader::<_, i64>(&mut reader).unwrap(), 2);
+        assert_eq!(from_reader::<_, i64>(&mut reader).unwrap(), 3);
+        assert!(from_reader::<_, i64>(&mut reader).is_err());
+        assert!(from_slice::<i64>(text.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Support for converting between Rust types and Ion using [serde](https://serde.rs).
+//!
+//! Values are read using a [Reader](crate::Reader) and written using a
+//! [TextWriter](crate::text::writer::TextWriter). Rust's data model is mapped onto Ion's as
+//! follows:
+//!
+//! * `bool`: bool
+//! * integers: int
+//! * `f32` and `f64`: float. Decimals and ints can also be read as floats.
+//! * `char`, `String` and `&str`: string. Symbols can also be read as strings.
+//! * byte buffers (e.g. those using `serde_bytes`): blob. Clobs can also be read as bytes.
+//! * `Option`: `null` for None. A null of any type (e.g. `null.int`) is read as None.
+//! * `()` and unit structs: `null`
+//! * sequences and tuples: list. S-expressions can also be read as sequences.
+//! * structs and maps with string keys: struct
+//! * unit enum variants: a symbol with the variant's name (e.g. `Red`)
+//! * other enum variants: the variant's value annotated with the variant's name
+//!   (e.g. `Rgb::[0, 0, 255]` or `Hsv::{h: 0, s: 0, v: 0}`)
+//! * [IonTimestamp] values: timestamp
+//! * [IonDecimal] values: decimal
+//!
+//! Serde has no notion of timestamps or arbitrary-precision decimals, so fields of type
+//! [Timestamp](crate::types::Timestamp) or [Decimal](crate::types::Decimal) must be wrapped in
+//! [IonTimestamp] or [IonDecimal] to keep their Ion types.
+
+use std::fmt;
+
+use crate::result::IonError;
+
+mod de;
+mod ser;
+mod types;
+
+pub use de::{from_reader, from_slice, Deserializer};
+pub use ser::{to_string, to_writer, Serializer};
+pub use types::{IonDecimal, IonTimestamp};
+
+/// A convenient alias for a Result whose error type is [Error].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error type returned by the Serializer and Deserializer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// An error raised while reading or writing Ion data.
+    Ion(IonError),
+    /// An error raised by a type's Serialize or Deserialize implementation, or by a mismatch
+    /// between the Rust type and the Ion data.
+    Message(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Ion(error) => write!(f, "{}", error),
+            Error::Message(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<IonError> for Error {
+    fn from(error: IonError) -> Self {
+        Error::Ion(error)
+    }
+}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Error::Message(message.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Error::Message(message.to_string())
+    }
+}
@@ -0,0 +1,586 @@
+use std::io::Write;
+
+use ::serde::ser::{self, Impossible, Serialize};
+use num_bigint::BigInt;
+
+use crate::serde::types::{DECIMAL_NEWTYPE_NAME, TIMESTAMP_NEWTYPE_NAME};
+use crate::serde::{Error, Result};
+use crate::text::parsers::{parse_decimal, parse_timestamp};
+use crate::text::writer::TextWriter;
+use crate::types::{Integer, IonType};
+
+/// Serializes `value` as Ion text and writes it to the provided io::Write implementation.
+pub fn to_writer<W: Write, T: ?Sized + Serialize>(writer: W, value: &T) -> Result<()> {
+    let mut text_writer = TextWriter::new(writer);
+    value.serialize(&mut Serializer::new(&mut text_writer))?;
+    text_writer.flush()?;
+    Ok(())
+}
+
+/// Serializes `value` as Ion text and returns it as a String. Like all top-level values written
+/// by the TextWriter, the output ends in a newline.
+pub fn to_string<T: ?Sized + Serialize>(value: &T) -> Result<String> {
+    let mut output = Vec::new();
+    to_writer(&mut output, value)?;
+    String::from_utf8(output).map_err(|error| Error::Message(error.to_string()))
+}
+
+/// A serde Serializer that writes each value it is given to a TextWriter.
+pub struct Serializer<'a, W: Write> {
+    writer: &'a mut TextWriter<W>,
+    // Set when an IonTimestamp or IonDecimal is being serialized. The newtype's text
+    // representation will be written as the corresponding Ion type instead of as a string.
+    pending_newtype: Option<&'static str>,
+}
+
+impl<'a, W: Write> Serializer<'a, W> {
+    pub fn new(writer: &'a mut TextWriter<W>) -> Serializer<'a, W> {
+        Serializer {
+            writer,
+            pending_newtype: None,
+        }
+    }
+
+    fn write_big_int(&mut self, value: BigInt) -> Result<()> {
+        Ok(self.writer.write_integer(&Integer::from(value))?)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> Result<&mut Self> {
+        self.writer.step_in(ion_type)?;
+        Ok(self)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::Serializer for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        Ok(self.writer.write_bool(value)?)
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        Ok(self.writer.write_i64(value)?)
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<()> {
+        self.write_big_int(BigInt::from(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_big_int(BigInt::from(value))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<()> {
+        self.write_big_int(BigInt::from(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.serialize_f64(f64::from(value))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        Ok(self.writer.write_f64(value)?)
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.serialize_str(&value.to_string())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        match self.pending_newtype.take() {
+            Some(TIMESTAMP_NEWTYPE_NAME) => {
+                Ok(self.writer.write_timestamp(&parse_timestamp(value)?)?)
+            }
+            Some(DECIMAL_NEWTYPE_NAME) => Ok(self.writer.write_decimal(&parse_decimal(value)?)?),
+            _ => Ok(self.writer.write_string(value)?),
+        }
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        Ok(self.writer.write_blob(value)?)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Ok(self.writer.write_null(IonType::Null)?)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(self.writer.write_null(IonType::Null)?)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        Ok(self.writer.write_symbol(variant)?)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        if name == TIMESTAMP_NEWTYPE_NAME || name == DECIMAL_NEWTYPE_NAME {
+            self.pending_newtype = Some(name);
+        }
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.writer.set_annotations(&[variant]);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.writer.set_annotations(&[variant]);
+        self.step_in(IonType::List)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        self.step_in(IonType::Struct)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        self.step_in(IonType::Struct)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.writer.set_annotations(&[variant]);
+        self.step_in(IonType::Struct)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeSeq for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeTuple for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeTupleStruct for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeTupleVariant for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeMap for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let field_name = key.serialize(FieldNameSerializer)?;
+        self.writer.set_field_name(&field_name);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeStruct for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.writer.set_field_name(key);
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+impl<'a, 'w, W: Write> ser::SerializeStructVariant for &'a mut Serializer<'w, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.writer.set_field_name(key);
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.writer.step_out()?)
+    }
+}
+
+// Ion struct field names are symbols, so only map keys that serialize as text are supported.
+struct FieldNameSerializer;
+
+fn field_name_must_be_text<T>() -> Result<T> {
+    Err(Error::Message(
+        "Map keys must be serialized as strings to be used as Ion struct field names.".to_string(),
+    ))
+}
+
+impl ser::Serializer for FieldNameSerializer {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_str(self, value: &str) -> Result<String> {
+        Ok(value.to_string())
+    }
+
+    fn serialize_char(self, value: char) -> Result<String> {
+        Ok(value.to_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _value: bool) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_i8(self, _value: i8) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_i16(self, _value: i16) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_i32(self, _value: i32) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_i64(self, _value: i64) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_u8(self, _value: u8) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_u16(self, _value: u16) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_u32(self, _value: u32) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_u64(self, _value: u64) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_f32(self, _value: f32) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_f64(self, _value: f64) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_none(self) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_unit(self) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        field_name_must_be_text()
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        field_name_must_be_text()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use crate::serde::{to_string, IonDecimal, IonTimestamp};
+    use crate::types::{Decimal, Timestamp};
+
+    #[derive(Serialize)]
+    enum Color {
+        Red,
+        Gray(u8),
+        Rgb(u8, u8, u8),
+        Hsv { h: u16, s: u8, v: u8 },
+    }
+
+    #[derive(Serialize)]
+    struct Widget {
+        name: String,
+        count: u64,
+        weight: Option<f64>,
+        colors: Vec<Color>,
+        data: serde_bytes::ByteBuf,
+    }
+
+    #[test]
+    fn serialize_scalars() {
+        assert_eq!(to_string(&true).unwrap(), "true\n");
+        assert_eq!(to_string(&-5i8).unwrap(), "-5\n");
+        assert_eq!(to_string(&u64::MAX).unwrap(), "18446744073709551615\n");
+        assert_eq!(to_string(&1.5f64).unwrap(), "1.5e0\n");
+        assert_eq!(to_string("foo").unwrap(), "\"foo\"\n");
+        assert_eq!(to_string(&'x').unwrap(), "\"x\"\n");
+        assert_eq!(to_string(&None::<i64>).unwrap(), "null\n");
+        assert_eq!(to_string(&Some(7)).unwrap(), "7\n");
+        assert_eq!(to_string(&()).unwrap(), "null\n");
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let widget = Widget {
+            name: "sprocket".to_string(),
+            count: 3,
+            weight: None,
+            colors: vec![
+                Color::Red,
+                Color::Gray(7),
+                Color::Rgb(1, 2, 3),
+                Color::Hsv { h: 1, s: 2, v: 3 },
+            ],
+            data: serde_bytes::ByteBuf::from(b"hello".to_vec()),
+        };
+        assert_eq!(
+            to_string(&widget).unwrap(),
+            "{name:\"sprocket\",count:3,weight:null,colors:[Red,Gray::7,Rgb::[1,2,3,],\
+             Hsv::{h:1,s:2,v:3,},],data:{{aGVsbG8=}},}\n"
+        );
+    }
+
+    #[test]
+    fn serialize_map() {
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(to_string(&map).unwrap(), "{a:1,b:2,}\n");
+
+        let mut map = BTreeMap::new();
+        map.insert(1, 1);
+        assert!(to_string(&map).is_err());
+    }
+
+    #[test]
+    fn serialize_ion_types() {
+        let timestamp = Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 0)
+            .and_then(|t| t.with_offset(0))
+            .unwrap();
+        assert_eq!(
+            to_string(&IonTimestamp(timestamp)).unwrap(),
+            "2020-06-15T10:30:00Z\n"
+        );
+        let decimal = Decimal::new(150, -2);
+        assert_eq!(to_string(&IonDecimal(decimal)).unwrap(), "1.50\n");
+        let negative_zero = Decimal::negative_zero_with_exponent(-2);
+        assert_eq!(to_string(&IonDecimal(negative_zero)).unwrap(), "-0.00\n");
+    }
+}
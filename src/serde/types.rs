@@ -0,0 +1,117 @@
+use std::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::text::parsers::{parse_decimal, parse_timestamp};
+use crate::types::{Decimal, Timestamp};
+
+// The names that the Serializer and Deserializer use to recognize IonTimestamp and IonDecimal.
+pub(crate) const TIMESTAMP_NEWTYPE_NAME: &str = "$__ion_rs_timestamp";
+pub(crate) const DECIMAL_NEWTYPE_NAME: &str = "$__ion_rs_decimal";
+
+/// Wraps a [Timestamp] so that it is serialized as an Ion timestamp.
+///
+/// When used with other serde formats, the timestamp is serialized as a string containing its
+/// Ion text representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IonTimestamp(pub Timestamp);
+
+/// Wraps a [Decimal] so that it is serialized as an Ion decimal, preserving its precision and
+/// the sign of a zero coefficient.
+///
+/// When used with other serde formats, the decimal is serialized as a string containing its
+/// Ion text representation (e.g. `1.50`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IonDecimal(pub Decimal);
+
+impl Serialize for IonTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(TIMESTAMP_NEWTYPE_NAME, &self.0.to_string())
+    }
+}
+
+impl Serialize for IonDecimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(DECIMAL_NEWTYPE_NAME, &self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IonTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(TIMESTAMP_NEWTYPE_NAME, TimestampVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for IonDecimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(DECIMAL_NEWTYPE_NAME, DecimalVisitor)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = IonTimestamp;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an Ion timestamp")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_timestamp(value)
+            .map(IonTimestamp)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+struct DecimalVisitor;
+
+impl<'de> Visitor<'de> for DecimalVisitor {
+    type Value = IonDecimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an Ion decimal")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_decimal(value)
+            .map(IonDecimal)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::parsers::parse_decimal;
+    use crate::types::Decimal;
+
+    #[test]
+    fn decimal_text_preserves_sign_and_precision() {
+        let decimals = [
+            Decimal::new(150, -2),
+            Decimal::new(0, -1),
+            Decimal::new(-12, 0),
+            Decimal::new(1, 5),
+            Decimal::negative_zero(),
+            Decimal::negative_zero_with_exponent(-2),
+        ];
+        for value in &decimals {
+            assert_eq!(&parse_decimal(&value.to_string()).unwrap(), value);
+        }
+    }
+}
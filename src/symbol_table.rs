@@ -1,12 +1,21 @@
 use std::collections::HashMap;
 
 use crate::constants::v1_0;
+use crate::result::{illegal_operation, IonResult};
 use crate::types::SymbolId;
 
-/// Stores mappings from Symbol IDs to text and vice-versa.
+/// Stores mappings from Symbol IDs to text and vice-versa. A symbol ID may be defined without
+/// any known text (for example, when a shared symbol table import cannot be resolved).
+#[derive(Clone, Debug)]
 pub struct SymbolTable {
-    symbols_by_id: Vec<String>,
+    symbols_by_id: Vec<Option<String>>,
     ids_by_text: HashMap<String, SymbolId>,
+    // Runs of consecutive symbol IDs with unknown text that are counted rather than stored in
+    // `symbols_by_id`, as (first symbol ID, number of symbol IDs). An import can declare any
+    // number of symbols, so storing each of them would let the input decide how much to allocate.
+    unknown_runs: Vec<(SymbolId, usize)>,
+    // The total number of symbol IDs in `unknown_runs`
+    unknown_run_length: usize,
 }
 
 impl SymbolTable {
@@ -15,15 +24,18 @@ impl SymbolTable {
         let mut symbol_table = SymbolTable {
             symbols_by_id: Vec::with_capacity(v1_0::SYSTEM_SYMBOLS.len()),
             ids_by_text: HashMap::new(),
+            unknown_runs: Vec::new(),
+            unknown_run_length: 0,
         };
         symbol_table.initialize();
         symbol_table
     }
 
-    // Interns the v1.0 system symbols
+    // Interns the v1.0 system symbols. Symbol zero (`$0`) is reserved and always has unknown text.
     fn initialize(&mut self) {
-        for (id, text) in v1_0::SYSTEM_SYMBOLS.iter().enumerate() {
-            self.symbols_by_id.push(text.to_string());
+        self.symbols_by_id.push(None);
+        for (id, text) in v1_0::SYSTEM_SYMBOLS.iter().enumerate().skip(1) {
+            self.symbols_by_id.push(Some(text.to_string()));
             self.ids_by_text.insert(text.to_string(), id);
         }
     }
@@ -31,6 +43,8 @@ impl SymbolTable {
     pub fn reset(&mut self) {
         self.symbols_by_id.clear();
         self.ids_by_text.clear();
+        self.unknown_runs.clear();
+        self.unknown_run_length = 0;
         self.initialize();
     }
 
@@ -41,12 +55,46 @@ impl SymbolTable {
         }
 
         // Otherwise, intern it and return the new ID.
-        let id = self.symbols_by_id.len();
-        self.symbols_by_id.push(text.to_string());
+        let id = self.len();
+        self.symbols_by_id.push(Some(text.to_string()));
         self.ids_by_text.insert(text, id);
         id
     }
 
+    /// Assigns the next symbol ID to `text` and returns it, even if the text is already in the
+    /// symbol table. If `text` is None, the new symbol ID will have unknown text.
+    pub fn add_symbol(&mut self, text: Option<String>) -> SymbolId {
+        let id = self.len();
+        if let Some(text) = &text {
+            // If the text was already defined, lookups will continue to return the lowest ID.
+            self.ids_by_text.entry(text.clone()).or_insert(id);
+        }
+        self.symbols_by_id.push(text);
+        id
+    }
+
+    /// Assigns the next `count` symbol IDs without any known text. Unlike calling
+    /// [add_symbol](Self::add_symbol) `count` times, this does not allocate space for each ID.
+    /// Returns an Err if the table would contain more symbol IDs than a `usize` can represent.
+    pub fn add_unknown_symbols(&mut self, count: usize) -> IonResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+        let start = self.len();
+        if start.checked_add(count).is_none() {
+            return illegal_operation(format!(
+                "Cannot add {} symbols to a symbol table that already defines {}.",
+                count, start
+            ));
+        }
+        match self.unknown_runs.last_mut() {
+            Some((run_start, length)) if *run_start + *length == start => *length += count,
+            _ => self.unknown_runs.push((start, count)),
+        }
+        self.unknown_run_length += count;
+        Ok(())
+    }
+
     /// If defined, returns the Symbol ID associated with the provided text.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
@@ -54,24 +102,48 @@ impl SymbolTable {
 
     /// If defined, returns the text associated with the provided Symbol ID.
     pub fn text_for(&self, sid: usize) -> Option<&str> {
-        self.symbols_by_id.get(sid).map(|text| text.as_str())
+        let is_in_unknown_run = self
+            .unknown_runs
+            .iter()
+            .any(|(start, length)| sid >= *start && sid - *start < *length);
+        if is_in_unknown_run {
+            return None;
+        }
+        self.symbols_by_id
+            .get(self.storage_index(sid))
+            .and_then(|text| text.as_deref())
     }
 
-    // Returns a slice of references to the symbol text stored in the table.
-    pub fn symbols(&self) -> &[String] {
+    // Returns the index in `symbols_by_id` of the first stored symbol whose ID is greater than or
+    // equal to `sid`.
+    fn storage_index(&self, sid: SymbolId) -> usize {
+        let mut skipped = 0;
+        for (start, length) in &self.unknown_runs {
+            if sid <= *start {
+                break;
+            }
+            skipped += (sid - *start).min(*length);
+        }
+        sid - skipped
+    }
+
+    // Returns a slice of references to the symbol text stored in the table. Symbols with unknown
+    // text are represented as None, except for those added by `add_unknown_symbols`, which are
+    // not stored.
+    pub fn symbols(&self) -> &[Option<String>] {
         &self.symbols_by_id
     }
 
     // Returns a slice of references to the symbol text stored in the table starting at the given
     // symbol ID. If a symbol table append occurs during reading, this function can be used to
     // easily view the new symbols that has been added to the table.
-    pub fn symbols_tail(&self, start: usize) -> &[String] {
-        &self.symbols_by_id[start..]
+    pub fn symbols_tail(&self, start: usize) -> &[Option<String>] {
+        &self.symbols_by_id[self.storage_index(start)..]
     }
 
     // The number of symbols defined in the table.
     pub fn len(&self) -> usize {
-        self.symbols_by_id.len()
+        self.symbols_by_id.len() + self.unknown_run_length
     }
 }
 
@@ -83,3 +155,45 @@ pub trait SymbolTableEventHandler {
     /// Invoked when the active symbol table is reset, potentially defining new symbols.
     fn on_reset<'a>(&'a mut self, symbol_table: &'a SymbolTable);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::v1_0::SYSTEM_SYMBOLS;
+    use crate::symbol_table::SymbolTable;
+
+    #[test]
+    fn unknown_symbol_runs() {
+        let mut symbol_table = SymbolTable::new();
+        let first_id = symbol_table.len();
+        symbol_table.add_unknown_symbols(1_000_000_000_000).unwrap();
+        symbol_table.add_unknown_symbols(5).unwrap();
+        let foo_id = symbol_table.add_symbol(Some("foo".to_string()));
+        symbol_table.add_unknown_symbols(2).unwrap();
+        let bar_id = symbol_table.intern("bar".to_string());
+
+        assert_eq!(foo_id, first_id + 1_000_000_000_005);
+        assert_eq!(bar_id, foo_id + 3);
+        assert_eq!(symbol_table.len(), bar_id + 1);
+        assert_eq!(
+            symbol_table.text_for(first_id - 1),
+            Some("$ion_shared_symbol_table")
+        );
+        assert_eq!(symbol_table.text_for(first_id), None);
+        assert_eq!(symbol_table.text_for(foo_id - 1), None);
+        assert_eq!(symbol_table.text_for(foo_id), Some("foo"));
+        assert_eq!(symbol_table.text_for(foo_id + 1), None);
+        assert_eq!(symbol_table.text_for(bar_id), Some("bar"));
+        assert_eq!(symbol_table.text_for(bar_id + 1), None);
+        assert_eq!(symbol_table.sid_for(&"bar"), Some(bar_id));
+        assert_eq!(symbol_table.symbols().len(), SYSTEM_SYMBOLS.len() + 2);
+        assert_eq!(
+            symbol_table.symbols_tail(foo_id + 1),
+            &[Some("bar".to_string())]
+        );
+        assert!(symbol_table.add_unknown_symbols(usize::MAX).is_err());
+
+        symbol_table.reset();
+        assert_eq!(symbol_table.len(), SYSTEM_SYMBOLS.len());
+        assert_eq!(symbol_table.text_for(first_id), None);
+    }
+}
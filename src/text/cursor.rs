@@ -0,0 +1,1667 @@
+use crate::cursor::{Cursor, RawSymbolTokenRef, StreamItem};
+use crate::data_source::IonDataSource;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::text::parsers::{
+    classify_numeric_token, parse_decimal, parse_float, parse_integer, parse_timestamp, NumericKind,
+};
+use crate::types::{Decimal, Integer, IonType, SymbolId, Timestamp};
+
+/// A symbol as it appeared in the text stream: either inline text (`foo`, `'foo bar'`) or a
+/// symbol ID (`$10`).
+#[derive(Clone, Debug, PartialEq)]
+enum TextSymbol {
+    SymbolId(SymbolId),
+    Text(String),
+}
+
+impl TextSymbol {
+    fn as_raw_symbol_token(&self) -> RawSymbolTokenRef<'_> {
+        match self {
+            TextSymbol::SymbolId(sid) => RawSymbolTokenRef::SymbolId(*sid),
+            TextSymbol::Text(text) => RawSymbolTokenRef::Text(text.as_str()),
+        }
+    }
+}
+
+/// The syntax that was used to write a symbol. Only some forms of symbol can be used as an
+/// annotation or be interpreted as an Ion Version Marker.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum SymbolSyntax {
+    // foo, $ion_1_0, $10
+    Identifier,
+    // 'foo'
+    Quoted,
+    // +, <=, ...
+    Operator,
+}
+
+/// The value over which the TextIonCursor is currently positioned. Scalars are fully parsed
+/// when the cursor advances to them; containers are only opened, and their contents are read
+/// as the cursor steps into them.
+#[derive(Clone, Debug, PartialEq)]
+enum TextValue {
+    Null(IonType),
+    Boolean(bool),
+    Integer(Integer),
+    Float(f64),
+    Decimal(Decimal),
+    Timestamp(Timestamp),
+    Symbol(TextSymbol),
+    String(String),
+    Blob(Vec<u8>),
+    Clob(Vec<u8>),
+    List,
+    SExpression,
+    Struct,
+}
+
+impl TextValue {
+    fn ion_type(&self) -> IonType {
+        use TextValue::*;
+        match self {
+            Null(ion_type) => *ion_type,
+            Boolean(_) => IonType::Boolean,
+            Integer(_) => IonType::Integer,
+            Float(_) => IonType::Float,
+            Decimal(_) => IonType::Decimal,
+            Timestamp(_) => IonType::Timestamp,
+            Symbol(_) => IonType::Symbol,
+            String(_) => IonType::String,
+            Blob(_) => IonType::Blob,
+            Clob(_) => IonType::Clob,
+            List => IonType::List,
+            SExpression => IonType::SExpression,
+            Struct => IonType::Struct,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, TextValue::Null(_))
+    }
+}
+
+/// A container that the cursor has stepped into, along with the information needed to restore
+/// the cursor's position when it steps back out.
+#[derive(Clone, Debug)]
+struct TextParent {
+    value: TextValue,
+    field_name: Option<TextSymbol>,
+    annotations: Vec<TextSymbol>,
+    index_at_depth: usize,
+}
+
+/// A Cursor implementation that reads text Ion from any IonDataSource.
+///
+/// Text Ion allows symbols to be written either as inline text or as symbol IDs (`$10`). Field
+/// names, annotations, and symbol values written as text do not have a symbol ID, so they are
+/// not included in the output of `field_id`, `annotation_ids`, or `read_symbol_id`. Use
+/// `raw_field_name`, `raw_annotations`, and `read_raw_symbol` (or a `Reader`, which resolves both
+/// forms into text) to access them.
+pub struct TextIonCursor<R>
+where
+    R: IonDataSource,
+{
+    // The file, socket, array, or other data source containing text Ion bytes
+    data_source: R,
+    // Bytes that were read from the data source to look ahead and then returned.
+    // They are stored in reverse order so the next byte can be removed with pop().
+    pushback: Vec<u8>,
+    // How many bytes we've consumed from our data source
+    bytes_read: usize,
+    // The value on which the cursor is currently sitting, if any
+    value: Option<TextValue>,
+    // The current value's field name, if it is inside a struct
+    field_name: Option<TextSymbol>,
+    // The current value's annotations
+    annotations: Vec<TextSymbol>,
+    // The symbol IDs of any annotations on the current value that were written as `$ID`
+    annotation_ids: Vec<SymbolId>,
+    // All of the containers into which the cursor has stepped. Empty at the top level.
+    parents: Vec<TextParent>,
+    // The number of values that have been read at the current depth
+    index_at_depth: usize,
+    // Whether the closing delimiter of the container being traversed has been consumed
+    is_at_container_end: bool,
+    // Whether the current value is a container whose contents have not been consumed yet
+    has_unread_contents: bool,
+}
+
+impl<R: IonDataSource> Cursor for TextIonCursor<R> {
+    type DataSource = R;
+
+    fn ion_version(&self) -> (u8, u8) {
+        (1, 0)
+    }
+
+    fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        // If the current value is a container that the user did not step into, skip its contents.
+        if self.has_unread_contents {
+            self.skip_unread_contents()?;
+        }
+        self.next_item()
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        self.value.as_ref().map(|value| value.ion_type())
+    }
+
+    fn annotation_ids(&self) -> &[SymbolId] {
+        &self.annotation_ids
+    }
+
+    fn field_id(&self) -> Option<SymbolId> {
+        match self.field_name {
+            Some(TextSymbol::SymbolId(sid)) => Some(sid),
+            _ => None,
+        }
+    }
+
+    fn raw_field_name(&self) -> Option<RawSymbolTokenRef<'_>> {
+        self.field_name
+            .as_ref()
+            .map(|field_name| field_name.as_raw_symbol_token())
+    }
+
+    fn raw_annotations(&self) -> Vec<RawSymbolTokenRef<'_>> {
+        self.annotations
+            .iter()
+            .map(|annotation| annotation.as_raw_symbol_token())
+            .collect()
+    }
+
+    fn read_null(&mut self) -> IonResult<Option<IonType>> {
+        match self.value {
+            Some(TextValue::Null(ion_type)) => Ok(Some(ion_type)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_bool(&mut self) -> IonResult<Option<bool>> {
+        match self.value {
+            Some(TextValue::Boolean(value)) => Ok(Some(value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
+        match &self.value {
+            Some(TextValue::Integer(value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_f32(&mut self) -> IonResult<Option<f32>> {
+        Ok(self.read_f64()?.map(|value| value as f32)) // Lossy if the value needs 64 bits
+    }
+
+    fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        match self.value {
+            Some(TextValue::Float(value)) => Ok(Some(value)),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        match &self.value {
+            Some(TextValue::Decimal(value)) => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_string(&mut self) -> IonResult<Option<String>> {
+        self.string_ref_map(|s: &str| s.into())
+    }
+
+    fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&str) -> T,
+    {
+        match &self.value {
+            Some(TextValue::String(text)) => Ok(Some(f(text.as_str()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        match &self.value {
+            Some(TextValue::String(text)) => Ok(Some(f(text.as_bytes()))),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_symbol_id(&mut self) -> IonResult<Option<SymbolId>> {
+        match &self.value {
+            Some(TextValue::Symbol(TextSymbol::SymbolId(sid))) => Ok(Some(*sid)),
+            Some(TextValue::Symbol(TextSymbol::Text(text))) => illegal_operation(format!(
+                "The symbol '{}' was written as text and has no symbol ID; use read_raw_symbol().",
+                text
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolTokenRef<'_>>> {
+        match &self.value {
+            Some(TextValue::Symbol(symbol)) => Ok(Some(symbol.as_raw_symbol_token())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        match &self.value {
+            Some(TextValue::Blob(bytes)) => Ok(Some(bytes.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        match &self.value {
+            Some(TextValue::Clob(bytes)) => Ok(Some(bytes.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        match &self.value {
+            Some(TextValue::Timestamp(timestamp)) => Ok(Some(timestamp.clone())),
+            _ => Ok(None),
+        }
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        if !self.has_unread_contents {
+            return illegal_operation(format!(
+                "You cannot step into a(n) {:?}",
+                self.value.as_ref().map(|value| value.ion_type())
+            ));
+        }
+        self.has_unread_contents = false;
+        let parent = TextParent {
+            // The cursor only steps into containers, so there is always a current value here.
+            value: self.value.take().unwrap(),
+            field_name: self.field_name.take(),
+            annotations: self.annotations.drain(..).collect(),
+            index_at_depth: self.index_at_depth,
+        };
+        self.annotation_ids.clear();
+        self.parents.push(parent);
+        self.index_at_depth = 0;
+        self.is_at_container_end = false;
+        Ok(())
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        if self.parents.is_empty() {
+            return illegal_operation("You cannot step out of the root level.");
+        }
+        // Consume (and validate) any values remaining in the container, including its closing
+        // delimiter.
+        while self.next()?.is_some() {}
+        self.pop_parent();
+        Ok(())
+    }
+
+    fn depth(&self) -> usize {
+        self.parents.len()
+    }
+}
+
+impl<R> TextIonCursor<R>
+where
+    R: IonDataSource,
+{
+    pub fn new(data_source: R) -> Self {
+        TextIonCursor {
+            data_source,
+            pushback: Vec::new(),
+            bytes_read: 0,
+            value: None,
+            field_name: None,
+            annotations: Vec::new(),
+            annotation_ids: Vec::new(),
+            parents: Vec::new(),
+            index_at_depth: 0,
+            is_at_container_end: false,
+            has_unread_contents: false,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.value.as_ref().map(|v| v.is_null()).unwrap_or(false)
+    }
+
+    /// Returns the number of bytes that have been consumed from the data source.
+    pub fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+
+    // Reads the next value at the current depth. The caller is responsible for skipping over
+    // the contents of the current value first if it is an unread container.
+    fn next_item(&mut self) -> IonResult<Option<StreamItem>> {
+        self.value = None;
+        self.field_name = None;
+        self.annotations.clear();
+        self.annotation_ids.clear();
+
+        if self.is_at_container_end || !self.advance_to_next_value()? {
+            return Ok(None);
+        }
+
+        if self.is_in_struct() {
+            self.field_name = Some(self.read_field_name()?);
+        }
+
+        let (value, syntax) = self.read_annotations_and_value()?;
+
+        // Only an unquoted, unannotated symbol at the top level can be an Ion Version Marker.
+        if self.parents.is_empty()
+            && self.annotations.is_empty()
+            && syntax == Some(SymbolSyntax::Identifier)
+        {
+            if let TextValue::Symbol(TextSymbol::Text(text)) = &value {
+                if is_version_marker(text) {
+                    if text != "$ion_1_0" {
+                        return decoding_error(format!(
+                            "Found an Ion Version Marker for an unsupported Ion version: {}",
+                            text
+                        ));
+                    }
+                    return Ok(Some(StreamItem::VersionMarker(1, 0)));
+                }
+            }
+        }
+
+        let ion_type = value.ion_type();
+        let is_null = value.is_null();
+        self.has_unread_contents = !is_null && is_container(ion_type);
+        self.value = Some(value);
+        self.index_at_depth += 1;
+        Ok(Some(StreamItem::Value(ion_type, is_null)))
+    }
+
+    // Skips the rest of the current container, which the user did not step into. Nested
+    // containers are skipped with a loop rather than by recursing, so that deeply nested input
+    // cannot exhaust the stack.
+    fn skip_unread_contents(&mut self) -> IonResult<()> {
+        let depth = self.parents.len();
+        self.step_in()?;
+        while self.parents.len() > depth {
+            if self.has_unread_contents {
+                self.step_in()?;
+            } else if self.next_item()?.is_none() {
+                self.pop_parent();
+            }
+        }
+        Ok(())
+    }
+
+    // Restores the state of the container that is being stepped out of. The container's closing
+    // delimiter must already have been consumed.
+    fn pop_parent(&mut self) {
+        // Callers only pop a parent after stepping into it.
+        let parent = self.parents.pop().unwrap();
+        self.annotation_ids = annotation_ids_of(&parent.annotations);
+        self.value = Some(parent.value);
+        self.field_name = parent.field_name;
+        self.annotations = parent.annotations;
+        self.index_at_depth = parent.index_at_depth;
+        self.is_at_container_end = false;
+        self.has_unread_contents = false;
+    }
+
+    fn parent_type(&self) -> Option<IonType> {
+        self.parents.last().map(|parent| parent.value.ion_type())
+    }
+
+    fn is_in_struct(&self) -> bool {
+        self.parent_type() == Some(IonType::Struct)
+    }
+
+    fn is_in_s_expression(&self) -> bool {
+        self.parent_type() == Some(IonType::SExpression)
+    }
+
+    // Returns a decoding error that includes the cursor's current position in the stream.
+    fn error<T, S: AsRef<str>>(&self, description: S) -> IonResult<T> {
+        decoding_error(format!(
+            "{} (at byte offset {})",
+            description.as_ref(),
+            self.bytes_read
+        ))
+    }
+
+    fn unexpected_eof<T>(&self) -> IonResult<T> {
+        self.error("Unexpected end of stream")
+    }
+
+    // Consumes any delimiters between the previous value and the next one. Returns false if the
+    // end of the current container (or of the stream, at the top level) has been reached.
+    fn advance_to_next_value(&mut self) -> IonResult<bool> {
+        self.skip_whitespace()?;
+        let (separator, end_delimiter) = match self.parent_type() {
+            None => return Ok(self.peek_byte()?.is_some()),
+            Some(IonType::List) => (Some(b','), b']'),
+            Some(IonType::Struct) => (Some(b','), b'}'),
+            Some(IonType::SExpression) => (None, b')'),
+            Some(other) => unreachable!("Inside a non-container type: {:?}", other),
+        };
+
+        if let Some(separator) = separator {
+            if self.index_at_depth > 0 {
+                match self.peek_byte()? {
+                    Some(byte) if byte == separator => {
+                        self.next_byte()?;
+                        self.skip_whitespace()?;
+                    }
+                    Some(byte) if byte == end_delimiter => {}
+                    Some(byte) => {
+                        return self.error(format!(
+                            "Expected '{}' or '{}' but found '{}'",
+                            separator as char, end_delimiter as char, byte as char
+                        ))
+                    }
+                    None => return self.unexpected_eof(),
+                }
+            }
+        }
+
+        match self.peek_byte()? {
+            Some(byte) if byte == end_delimiter => {
+                self.next_byte()?;
+                self.is_at_container_end = true;
+                Ok(false)
+            }
+            Some(byte) if Some(byte) == separator => {
+                self.error(format!("Found an unexpected '{}'", byte as char))
+            }
+            Some(_) => Ok(true),
+            None => self.unexpected_eof(),
+        }
+    }
+
+    // Reads a struct field name and the ':' that follows it.
+    fn read_field_name(&mut self) -> IonResult<TextSymbol> {
+        let field_name = match self.peek_byte()? {
+            Some(b'"') => {
+                self.next_byte()?;
+                TextSymbol::Text(self.read_short_string_text()?)
+            }
+            Some(b'\'') => {
+                if self.next_bytes_are(b"'''")? {
+                    TextSymbol::Text(self.read_long_string_text()?)
+                } else {
+                    self.next_byte()?;
+                    TextSymbol::Text(self.read_quoted_symbol_text()?)
+                }
+            }
+            Some(byte) if is_identifier_start(byte) => {
+                let text = self.read_identifier_text()?;
+                if is_keyword(&text) {
+                    return self.error(format!(
+                        "The keyword '{}' cannot be used as a field name without quotes",
+                        text
+                    ));
+                }
+                identifier_symbol(text)
+            }
+            Some(byte) => {
+                return self.error(format!(
+                    "Expected a field name but found '{}'",
+                    byte as char
+                ))
+            }
+            None => return self.unexpected_eof(),
+        };
+        self.skip_whitespace()?;
+        if self.next_byte()? != Some(b':') || self.peek_byte()? == Some(b':') {
+            return self.error("Expected a ':' after the field name");
+        }
+        self.skip_whitespace()?;
+        Ok(field_name)
+    }
+
+    // Reads any annotations that precede the next value, then reads the value itself.
+    fn read_annotations_and_value(&mut self) -> IonResult<(TextValue, Option<SymbolSyntax>)> {
+        loop {
+            let (value, syntax) = self.read_value()?;
+            if let TextValue::Symbol(symbol) = value {
+                match syntax {
+                    Some(SymbolSyntax::Identifier) | Some(SymbolSyntax::Quoted) => {
+                        self.skip_whitespace()?;
+                        if self.next_bytes_are(b"::")? {
+                            self.next_byte()?;
+                            self.next_byte()?;
+                            self.skip_whitespace()?;
+                            if let TextSymbol::SymbolId(sid) = symbol {
+                                self.annotation_ids.push(sid);
+                            }
+                            self.annotations.push(symbol);
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+                return Ok((TextValue::Symbol(symbol), syntax));
+            }
+            return Ok((value, syntax));
+        }
+    }
+
+    // Reads the next token as a value. If the value is a symbol, the syntax that was used to
+    // write it is also returned.
+    fn read_value(&mut self) -> IonResult<(TextValue, Option<SymbolSyntax>)> {
+        let byte = match self.peek_byte()? {
+            Some(byte) => byte,
+            None => return self.unexpected_eof(),
+        };
+        let value = match byte {
+            b'{' => {
+                self.next_byte()?;
+                if self.peek_byte()? == Some(b'{') {
+                    self.next_byte()?;
+                    self.read_lob()?
+                } else {
+                    TextValue::Struct
+                }
+            }
+            b'[' => {
+                self.next_byte()?;
+                TextValue::List
+            }
+            b'(' => {
+                self.next_byte()?;
+                TextValue::SExpression
+            }
+            b'"' => {
+                self.next_byte()?;
+                TextValue::String(self.read_short_string_text()?)
+            }
+            b'\'' => {
+                if self.next_bytes_are(b"'''")? {
+                    TextValue::String(self.read_long_string_text()?)
+                } else {
+                    self.next_byte()?;
+                    let text = self.read_quoted_symbol_text()?;
+                    return Ok((
+                        TextValue::Symbol(TextSymbol::Text(text)),
+                        Some(SymbolSyntax::Quoted),
+                    ));
+                }
+            }
+            b'+' if self.next_bytes_are(b"+inf")? && self.is_stop_byte_at(4)? => {
+                self.skip_bytes(4)?;
+                TextValue::Float(f64::INFINITY)
+            }
+            b'-' if self.next_bytes_are(b"-inf")? && self.is_stop_byte_at(4)? => {
+                self.skip_bytes(4)?;
+                TextValue::Float(f64::NEG_INFINITY)
+            }
+            b'-' if self.is_digit_at(1)? => self.read_numeric_value()?,
+            byte if byte.is_ascii_digit() => self.read_numeric_value()?,
+            byte if is_identifier_start(byte) => {
+                let text = self.read_identifier_text()?;
+                return self.keyword_or_symbol(text);
+            }
+            byte if self.is_in_s_expression() && is_operator_byte(byte) => {
+                let text = self.read_operator_text()?;
+                return Ok((
+                    TextValue::Symbol(TextSymbol::Text(text)),
+                    Some(SymbolSyntax::Operator),
+                ));
+            }
+            byte => return self.error(format!("Found an unexpected '{}'", byte as char)),
+        };
+        Ok((value, None))
+    }
+
+    // Interprets an identifier as either a keyword (null, true, false, nan) or a symbol.
+    fn keyword_or_symbol(&mut self, text: String) -> IonResult<(TextValue, Option<SymbolSyntax>)> {
+        let value = match text.as_str() {
+            "null" => {
+                if self.peek_byte()? == Some(b'.') {
+                    self.next_byte()?;
+                    let type_name = self.read_identifier_text()?;
+                    TextValue::Null(self.null_type(&type_name)?)
+                } else {
+                    TextValue::Null(IonType::Null)
+                }
+            }
+            "true" => TextValue::Boolean(true),
+            "false" => TextValue::Boolean(false),
+            "nan" => TextValue::Float(f64::NAN),
+            _ => {
+                return Ok((
+                    TextValue::Symbol(identifier_symbol(text)),
+                    Some(SymbolSyntax::Identifier),
+                ))
+            }
+        };
+        Ok((value, None))
+    }
+
+    fn null_type(&self, type_name: &str) -> IonResult<IonType> {
+        let ion_type = match type_name {
+            "null" => IonType::Null,
+            "bool" => IonType::Boolean,
+            "int" => IonType::Integer,
+            "float" => IonType::Float,
+            "decimal" => IonType::Decimal,
+            "timestamp" => IonType::Timestamp,
+            "symbol" => IonType::Symbol,
+            "string" => IonType::String,
+            "clob" => IonType::Clob,
+            "blob" => IonType::Blob,
+            "list" => IonType::List,
+            "sexp" => IonType::SExpression,
+            "struct" => IonType::Struct,
+            _ => return self.error(format!("Found an invalid null type: 'null.{}'", type_name)),
+        };
+        Ok(ion_type)
+    }
+
+    // Reads an integer, float, decimal, or timestamp.
+    fn read_numeric_value(&mut self) -> IonResult<TextValue> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = self.peek_byte()? {
+            if is_stop_byte(byte) {
+                break;
+            }
+            bytes.push(byte);
+            self.next_byte()?;
+        }
+        let text = match String::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(_) => return self.error("Found a numeric value that was not valid UTF-8"),
+        };
+        let result = match classify_numeric_token(&text) {
+            NumericKind::Integer => parse_integer(&text).map(TextValue::Integer),
+            NumericKind::Float => parse_float(&text).map(TextValue::Float),
+            NumericKind::Decimal => parse_decimal(&text).map(TextValue::Decimal),
+            NumericKind::Timestamp => parse_timestamp(&text).map(TextValue::Timestamp),
+        };
+        result.or_else(|error| self.error(error.to_string()))
+    }
+
+    fn read_identifier_text(&mut self) -> IonResult<String> {
+        let mut text = String::new();
+        while let Some(byte) = self.peek_byte()? {
+            if !is_identifier_byte(byte) {
+                break;
+            }
+            text.push(byte as char);
+            self.next_byte()?;
+        }
+        if text.is_empty() {
+            return self.error("Expected an identifier");
+        }
+        Ok(text)
+    }
+
+    fn read_operator_text(&mut self) -> IonResult<String> {
+        let mut text = String::new();
+        while let Some(byte) = self.peek_byte()? {
+            if !is_operator_byte(byte)
+                || self.next_bytes_are(b"//")?
+                || self.next_bytes_are(b"/*")?
+            {
+                break;
+            }
+            text.push(byte as char);
+            self.next_byte()?;
+        }
+        Ok(text)
+    }
+
+    // Reads the remainder of a "short string". The opening quote has already been consumed.
+    fn read_short_string_text(&mut self) -> IonResult<String> {
+        let mut bytes = Vec::new();
+        self.read_quoted_text(b'"', false, &mut bytes)?;
+        self.utf8_string(bytes)
+    }
+
+    // Reads the remainder of a 'quoted symbol'. The opening quote has already been consumed.
+    fn read_quoted_symbol_text(&mut self) -> IonResult<String> {
+        let mut bytes = Vec::new();
+        self.read_quoted_text(b'\'', false, &mut bytes)?;
+        self.utf8_string(bytes)
+    }
+
+    // Reads one or more adjacent '''long string''' segments and concatenates them.
+    fn read_long_string_text(&mut self) -> IonResult<String> {
+        let mut bytes = Vec::new();
+        self.read_long_string_segments(false, &mut bytes)?;
+        self.utf8_string(bytes)
+    }
+
+    fn read_long_string_segments(&mut self, is_clob: bool, bytes: &mut Vec<u8>) -> IonResult<()> {
+        while self.next_bytes_are(b"'''")? {
+            self.skip_bytes(3)?;
+            self.read_long_string_segment(is_clob, bytes)?;
+            // Comments may appear between long string segments, but not inside a clob.
+            if is_clob {
+                self.skip_lob_whitespace()?;
+            } else {
+                self.skip_whitespace()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_long_string_segment(&mut self, is_clob: bool, bytes: &mut Vec<u8>) -> IonResult<()> {
+        loop {
+            match self.next_byte()? {
+                None => return self.unexpected_eof(),
+                Some(b'\'') if self.next_bytes_are(b"''")? => {
+                    self.skip_bytes(2)?;
+                    return Ok(());
+                }
+                Some(b'\\') => self.read_escape(is_clob, bytes)?,
+                Some(b'\r') => {
+                    // Normalize CR and CRLF line endings to LF.
+                    if self.peek_byte()? == Some(b'\n') {
+                        self.next_byte()?;
+                    }
+                    bytes.push(b'\n');
+                }
+                Some(byte) => self.push_text_byte(byte, is_clob, bytes)?,
+            }
+        }
+    }
+
+    // Reads text up to (and including) the closing `delimiter`, processing escapes along the way.
+    fn read_quoted_text(
+        &mut self,
+        delimiter: u8,
+        is_clob: bool,
+        bytes: &mut Vec<u8>,
+    ) -> IonResult<()> {
+        loop {
+            match self.next_byte()? {
+                None => return self.unexpected_eof(),
+                Some(byte) if byte == delimiter => return Ok(()),
+                Some(b'\\') => self.read_escape(is_clob, bytes)?,
+                Some(b'\n') | Some(b'\r') => {
+                    return self.error("Found an unescaped newline in a quoted value")
+                }
+                Some(byte) => self.push_text_byte(byte, is_clob, bytes)?,
+            }
+        }
+    }
+
+    fn push_text_byte(&self, byte: u8, is_clob: bool, bytes: &mut Vec<u8>) -> IonResult<()> {
+        if is_clob && byte > 0x7F {
+            return self.error("Clobs may only contain ASCII text");
+        }
+        if is_clob && byte < 0x20 && !is_whitespace(byte) {
+            return self.error("Clobs cannot contain unescaped control characters");
+        }
+        bytes.push(byte);
+        Ok(())
+    }
+
+    // Processes the escape sequence following a backslash, appending its value to `bytes`.
+    fn read_escape(&mut self, is_clob: bool, bytes: &mut Vec<u8>) -> IonResult<()> {
+        let byte = match self.next_byte()? {
+            Some(byte) => byte,
+            None => return self.unexpected_eof(),
+        };
+        let escaped = match byte {
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b't' => b'\t',
+            b'n' => b'\n',
+            b'f' => 0x0C,
+            b'r' => b'\r',
+            b'v' => 0x0B,
+            b'?' => b'?',
+            b'0' => 0x00,
+            b'\'' => b'\'',
+            b'"' => b'"',
+            b'/' => b'/',
+            b'\\' => b'\\',
+            b'\n' => return Ok(()), // An escaped newline continues the text on the next line.
+            b'\r' => {
+                if self.peek_byte()? == Some(b'\n') {
+                    self.next_byte()?;
+                }
+                return Ok(());
+            }
+            b'x' => {
+                let value = self.read_hex_digits(2)?;
+                if is_clob {
+                    bytes.push(value as u8);
+                    return Ok(());
+                }
+                return self.push_code_point(value, bytes);
+            }
+            b'u' | b'U' if is_clob => {
+                return self.error("Unicode escapes are not permitted in clobs");
+            }
+            b'u' => {
+                let value = self.read_hex_digits(4)?;
+                if (0xD800..0xDC00).contains(&value) {
+                    // This is the high half of a surrogate pair; the low half must follow.
+                    if !self.next_bytes_are(b"\\u")? {
+                        return self.error("Found an incomplete UTF-16 surrogate pair");
+                    }
+                    self.skip_bytes(2)?;
+                    let low = self.read_hex_digits(4)?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return self.error("Found an invalid UTF-16 surrogate pair");
+                    }
+                    let code_point = 0x10000 + ((value - 0xD800) << 10) + (low - 0xDC00);
+                    return self.push_code_point(code_point, bytes);
+                }
+                return self.push_code_point(value, bytes);
+            }
+            b'U' => {
+                let value = self.read_hex_digits(8)?;
+                return self.push_code_point(value, bytes);
+            }
+            other => {
+                return self.error(format!(
+                    "Found an invalid escape sequence: '\\{}'",
+                    other as char
+                ))
+            }
+        };
+        bytes.push(escaped);
+        Ok(())
+    }
+
+    fn read_hex_digits(&mut self, count: usize) -> IonResult<u32> {
+        let mut value: u32 = 0;
+        for _ in 0..count {
+            let digit = match self.next_byte()? {
+                Some(byte) => (byte as char).to_digit(16),
+                None => return self.unexpected_eof(),
+            };
+            match digit {
+                Some(digit) => value = (value << 4) | digit,
+                None => return self.error("Found an invalid hexadecimal escape sequence"),
+            }
+        }
+        Ok(value)
+    }
+
+    fn push_code_point(&self, code_point: u32, bytes: &mut Vec<u8>) -> IonResult<()> {
+        match std::char::from_u32(code_point) {
+            Some(c) => {
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                Ok(())
+            }
+            None => self.error(format!(
+                "Found an invalid Unicode code point: {:X}",
+                code_point
+            )),
+        }
+    }
+
+    fn utf8_string(&self, bytes: Vec<u8>) -> IonResult<String> {
+        match String::from_utf8(bytes) {
+            Ok(text) => Ok(text),
+            Err(_) => self.error("Found text that was not valid UTF-8"),
+        }
+    }
+
+    // Reads the remainder of a blob or clob. The opening `{{` has already been consumed.
+    fn read_lob(&mut self) -> IonResult<TextValue> {
+        self.skip_lob_whitespace()?;
+        let value = match self.peek_byte()? {
+            Some(b'"') => {
+                self.next_byte()?;
+                let mut bytes = Vec::new();
+                self.read_quoted_text(b'"', true, &mut bytes)?;
+                self.skip_lob_whitespace()?;
+                TextValue::Clob(bytes)
+            }
+            Some(b'\'') => {
+                let mut bytes = Vec::new();
+                self.read_long_string_segments(true, &mut bytes)?;
+                TextValue::Clob(bytes)
+            }
+            _ => {
+                let mut base64_text = Vec::new();
+                while let Some(byte) = self.peek_byte()? {
+                    if byte == b'}' {
+                        break;
+                    }
+                    self.next_byte()?;
+                    if !is_whitespace(byte) {
+                        base64_text.push(byte);
+                    }
+                }
+                // Base64 text must be padded with '=' to a multiple of four characters.
+                if base64_text.len() % 4 != 0 {
+                    return self.error("Found a blob whose base64 text is not correctly padded");
+                }
+                match base64::decode(&base64_text) {
+                    Ok(bytes) => TextValue::Blob(bytes),
+                    Err(error) => return self.error(format!("Found an invalid blob: {}", error)),
+                }
+            }
+        };
+        if !self.next_bytes_are(b"}}")? {
+            return self.error("Expected '}}' at the end of the lob");
+        }
+        self.skip_bytes(2)?;
+        Ok(value)
+    }
+
+    // Skips whitespace and comments.
+    fn skip_whitespace(&mut self) -> IonResult<()> {
+        loop {
+            match self.peek_byte()? {
+                Some(byte) if is_whitespace(byte) => {
+                    self.next_byte()?;
+                }
+                Some(b'/') if self.next_bytes_are(b"//")? => {
+                    while let Some(byte) = self.next_byte()? {
+                        if byte == b'\n' || byte == b'\r' {
+                            break;
+                        }
+                    }
+                }
+                Some(b'/') if self.next_bytes_are(b"/*")? => {
+                    self.skip_bytes(2)?;
+                    loop {
+                        if self.next_bytes_are(b"*/")? {
+                            self.skip_bytes(2)?;
+                            break;
+                        }
+                        if self.next_byte()?.is_none() {
+                            return self.unexpected_eof();
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    // Skips whitespace inside a blob or clob, where comments are not permitted.
+    fn skip_lob_whitespace(&mut self) -> IonResult<()> {
+        while let Some(byte) = self.peek_byte()? {
+            if !is_whitespace(byte) {
+                break;
+            }
+            self.next_byte()?;
+        }
+        Ok(())
+    }
+
+    fn next_byte(&mut self) -> IonResult<Option<u8>> {
+        let byte = match self.pushback.pop() {
+            Some(byte) => Some(byte),
+            None => self.data_source.next_byte()?,
+        };
+        if byte.is_some() {
+            self.bytes_read += 1;
+        }
+        Ok(byte)
+    }
+
+    fn unread_byte(&mut self, byte: u8) {
+        self.pushback.push(byte);
+        self.bytes_read -= 1;
+    }
+
+    fn peek_byte(&mut self) -> IonResult<Option<u8>> {
+        let byte = self.next_byte()?;
+        if let Some(byte) = byte {
+            self.unread_byte(byte);
+        }
+        Ok(byte)
+    }
+
+    fn skip_bytes(&mut self, number_of_bytes: usize) -> IonResult<()> {
+        for _ in 0..number_of_bytes {
+            if self.next_byte()?.is_none() {
+                return self.unexpected_eof();
+            }
+        }
+        Ok(())
+    }
+
+    // Returns true if the next bytes in the stream match `expected`. Does not consume any input.
+    fn next_bytes_are(&mut self, expected: &[u8]) -> IonResult<bool> {
+        let mut consumed = Vec::with_capacity(expected.len());
+        let mut matches = true;
+        for &expected_byte in expected {
+            match self.next_byte()? {
+                Some(byte) => {
+                    consumed.push(byte);
+                    if byte != expected_byte {
+                        matches = false;
+                        break;
+                    }
+                }
+                None => {
+                    matches = false;
+                    break;
+                }
+            }
+        }
+        while let Some(byte) = consumed.pop() {
+            self.unread_byte(byte);
+        }
+        Ok(matches)
+    }
+
+    // Returns the byte `offset` bytes ahead of the cursor without consuming any input.
+    fn peek_byte_at(&mut self, offset: usize) -> IonResult<Option<u8>> {
+        let mut consumed = Vec::with_capacity(offset + 1);
+        let mut result = None;
+        for index in 0..=offset {
+            match self.next_byte()? {
+                Some(byte) => {
+                    consumed.push(byte);
+                    if index == offset {
+                        result = Some(byte);
+                    }
+                }
+                None => break,
+            }
+        }
+        while let Some(byte) = consumed.pop() {
+            self.unread_byte(byte);
+        }
+        Ok(result)
+    }
+
+    fn is_digit_at(&mut self, offset: usize) -> IonResult<bool> {
+        Ok(self
+            .peek_byte_at(offset)?
+            .map(|byte| byte.is_ascii_digit())
+            .unwrap_or(false))
+    }
+
+    fn is_stop_byte_at(&mut self, offset: usize) -> IonResult<bool> {
+        Ok(self.peek_byte_at(offset)?.map(is_stop_byte).unwrap_or(true))
+    }
+}
+
+fn identifier_symbol(text: String) -> TextSymbol {
+    // `$` followed only by digits refers to a symbol ID rather than to text.
+    if text.len() > 1 && text.starts_with('$') && text[1..].bytes().all(|b| b.is_ascii_digit()) {
+        if let Ok(sid) = text[1..].parse() {
+            return TextSymbol::SymbolId(sid);
+        }
+    }
+    TextSymbol::Text(text)
+}
+
+fn annotation_ids_of(annotations: &[TextSymbol]) -> Vec<SymbolId> {
+    annotations
+        .iter()
+        .filter_map(|annotation| match annotation {
+            TextSymbol::SymbolId(sid) => Some(*sid),
+            TextSymbol::Text(_) => None,
+        })
+        .collect()
+}
+
+// Returns true if `text` has the form `$ion_<major>_<minor>`.
+fn is_version_marker(text: &str) -> bool {
+    match text.strip_prefix("$ion_") {
+        Some(version) => {
+            let is_number =
+                |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+            match version.find('_') {
+                Some(index) => is_number(&version[..index]) && is_number(&version[index + 1..]),
+                None => false,
+            }
+        }
+        None => false,
+    }
+}
+
+fn is_container(ion_type: IonType) -> bool {
+    matches!(
+        ion_type,
+        IonType::List | IonType::SExpression | IonType::Struct
+    )
+}
+
+fn is_keyword(text: &str) -> bool {
+    matches!(text, "null" | "true" | "false" | "nan")
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | 0x0B | 0x0C)
+}
+
+fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || byte == b'$'
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$'
+}
+
+fn is_operator_byte(byte: u8) -> bool {
+    b"!#%&*+-./;<=>?@^`|~".contains(&byte)
+}
+
+// Numeric values and keywords must be followed by one of these bytes (or the end of the stream).
+fn is_stop_byte(byte: u8) -> bool {
+    is_whitespace(byte) || b"{}[](),\"'/".contains(&byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
+
+    use crate::cursor::{Cursor, RawSymbolTokenRef, StreamItem::*};
+    use crate::result::IonResult;
+    use crate::text::cursor::TextIonCursor;
+    use crate::types::{Decimal, Integer, IonType, Precision, Timestamp};
+
+    type TestDataSource = io::Cursor<Vec<u8>>;
+
+    fn text_cursor_for(text: &str) -> TextIonCursor<TestDataSource> {
+        let cursor = TextIonCursor::new(io::Cursor::new(text.as_bytes().to_vec()));
+        assert_eq!(cursor.ion_type(), None);
+        cursor
+    }
+
+    #[test]
+    fn test_read_nulls() -> IonResult<()> {
+        let mut cursor = text_cursor_for("null null.string null.struct");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Null, true)));
+        assert_eq!(cursor.read_null()?, Some(IonType::Null));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, true)));
+        assert_eq!(cursor.read_null()?, Some(IonType::String));
+        assert_eq!(cursor.read_string()?, None);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, true)));
+        assert!(cursor.step_in().is_err());
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_bools() -> IonResult<()> {
+        let mut cursor = text_cursor_for("true false");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(true));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(false));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_integers() -> IonResult<()> {
+        let mut cursor = text_cursor_for("0 -17 1_000 0xFF -0b101");
+        let mut values = vec![];
+        while let Some(Value(IonType::Integer, false)) = cursor.next()? {
+            values.push(cursor.read_i64()?.unwrap());
+        }
+        assert_eq!(values, vec![0, -17, 1000, 255, -5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_big_integers() -> IonResult<()> {
+        let mut cursor = text_cursor_for("9223372036854775807 -9223372036854775809");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_integer()?, Some(Integer::I64(i64::MAX)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let big = BigInt::from_str("-9223372036854775809").unwrap();
+        assert_eq!(cursor.read_big_int()?, Some(big.clone()));
+        assert_eq!(cursor.read_integer()?, Some(Integer::BigInt(big)));
+        assert!(cursor.read_i64().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_floats() -> IonResult<()> {
+        let mut cursor = text_cursor_for("1.5e0 -2e3 +inf -inf nan");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(1.5f64));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(-2000f64));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(f64::INFINITY));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(f64::NEG_INFINITY));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert!(cursor.read_f64()?.unwrap().is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_decimals() -> IonResult<()> {
+        let mut cursor = text_cursor_for("1.5 -20d-1 3d2");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_big_decimal()?, BigDecimal::from_str("1.5").ok());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_big_decimal()?, BigDecimal::from_str("-2").ok());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_big_decimal()?, Some(BigDecimal::from(300)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_decimals_preserves_sign_and_exponent() -> IonResult<()> {
+        let mut cursor = text_cursor_for("-0d0 0.00 1.50");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::negative_zero()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::new(0, -2)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::new(150, -2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp() -> IonResult<()> {
+        let mut cursor = text_cursor_for("2000-01-01T00:00:00+00:00");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let naive_datetime = NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0);
+        let datetime = FixedOffset::west(0).from_utc_datetime(&naive_datetime);
+        assert_eq!(cursor.read_datetime()?, Some(datetime));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_precision() -> IonResult<()> {
+        let mut cursor = text_cursor_for("2020T 2020-01-01T00:00Z 2020-01-01T00:00:00.500-00:00");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(cursor.read_timestamp()?, Some(Timestamp::with_year(2020)?));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert_eq!(
+            cursor.read_timestamp()?,
+            Some(Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?.with_offset(0)?)
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let timestamp = cursor.read_timestamp()?.unwrap();
+        assert_eq!(timestamp.precision(), Precision::Second);
+        assert_eq!(timestamp.offset(), None);
+        assert_eq!(
+            timestamp.fractional_seconds(),
+            Some(&BigDecimal::from_str("0.500").unwrap())
+        );
+        assert_eq!(cursor.read_i64()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_strings() -> IonResult<()> {
+        let mut cursor = text_cursor_for(
+            r#""" "foo" "tab\there \"quoted\" é\U0001F600 \x41" '''long''' '''string'''"#,
+        );
+        let mut values = vec![];
+        while let Some(Value(IonType::String, false)) = cursor.next()? {
+            values.push(cursor.read_string()?.unwrap());
+        }
+        assert_eq!(
+            values,
+            vec![
+                "",
+                "foo",
+                "tab\there \"quoted\" \u{e9}\u{1F600} A",
+                "longstring"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_long_string_with_comments_and_newlines() -> IonResult<()> {
+        let mut cursor = text_cursor_for("'''one\r\n''' /* comment */ // comment\n '''two'''");
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("one\ntwo".to_string()));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_surrogate_pair() -> IonResult<()> {
+        let mut cursor = text_cursor_for(r#""\uD83D\uDE00""#);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("\u{1F600}".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_symbols() -> IonResult<()> {
+        let mut cursor = text_cursor_for("foo 'bar baz' $10 '$10' ''");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("foo"))
+        );
+        assert!(cursor.read_symbol_id().is_err());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("bar baz"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::SymbolId(10))
+        );
+        assert_eq!(cursor.read_symbol_id()?, Some(10));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("$10"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.read_raw_symbol()?, Some(RawSymbolTokenRef::Text("")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lobs() -> IonResult<()> {
+        let mut cursor =
+            text_cursor_for(r#"{{aGVs bG8=}} {{ "a\x00\"b" }} {{ '''multi''' '''part''' }} {{}}"#);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        assert_eq!(cursor.read_blob_bytes()?, Some(b"hello".to_vec()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_clob_bytes()?, Some(b"a\x00\"b".to_vec()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_clob_bytes()?, Some(b"multipart".to_vec()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        assert_eq!(cursor.read_blob_bytes()?, Some(vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_clob_rejects_non_ascii_text() -> IonResult<()> {
+        let mut cursor = text_cursor_for(r#"{{ "é" }}"#);
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_lobs() {
+        for text in &["{{ \"a\x01b\" }}", "{{ '''a\x00''' }}", "{{ YQ }}", "{{ YQ= }}"] {
+            assert!(text_cursor_for(text).next().is_err(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_read_annotations() -> IonResult<()> {
+        let mut cursor = text_cursor_for("foo::'bar baz'::$10::5 $ion_symbol_table::{}");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(
+            cursor.raw_annotations(),
+            vec![
+                RawSymbolTokenRef::Text("foo"),
+                RawSymbolTokenRef::Text("bar baz"),
+                RawSymbolTokenRef::SymbolId(10),
+            ]
+        );
+        assert_eq!(cursor.annotation_ids(), &[10]);
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(
+            cursor.raw_annotations(),
+            vec![RawSymbolTokenRef::Text("$ion_symbol_table")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_list() -> IonResult<()> {
+        let mut cursor = text_cursor_for("[1, 2, 3,] []");
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        let mut list = vec![];
+        cursor.step_in()?;
+        assert_eq!(cursor.depth(), 1);
+        while let Some(Value(IonType::Integer, false)) = cursor.next()? {
+            list.push(cursor.read_i64()?.unwrap());
+        }
+        cursor.step_out()?;
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(list, vec![1i64, 2, 3]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_list_missing_comma() -> IonResult<()> {
+        let mut cursor = text_cursor_for("[1 2]");
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_s_expression_with_operators() -> IonResult<()> {
+        let mut cursor = text_cursor_for("(+ 1 -2 - a.b <= -inf /* c */ '+'::x)");
+        assert_eq!(cursor.next()?, Some(Value(IonType::SExpression, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("+"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(-2));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("-"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("a"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("."))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("b"))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("<="))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f64()?, Some(f64::NEG_INFINITY));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.raw_annotations(), vec![RawSymbolTokenRef::Text("+")]);
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text("x"))
+        );
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_operators_outside_s_expression_are_illegal() {
+        let mut cursor = text_cursor_for("[+]");
+        assert!(cursor.next().is_ok());
+        cursor.step_in().unwrap();
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_read_struct() -> IonResult<()> {
+        let mut cursor = text_cursor_for(r#"{a: 1, "b": 2, 'c': ann::3, '''d''': 4, $10: 5}"#);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        let mut fields = vec![];
+        while let Some(Value(IonType::Integer, false)) = cursor.next()? {
+            let field_name = match cursor.raw_field_name().unwrap() {
+                RawSymbolTokenRef::Text(text) => text.to_string(),
+                RawSymbolTokenRef::SymbolId(sid) => format!("${}", sid),
+            };
+            fields.push((field_name, cursor.read_i64()?.unwrap()));
+        }
+        cursor.step_out()?;
+        assert_eq!(
+            fields,
+            vec![
+                ("a".to_string(), 1),
+                ("b".to_string(), 2),
+                ("c".to_string(), 3),
+                ("d".to_string(), 4),
+                ("$10".to_string(), 5)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_field_id() -> IonResult<()> {
+        let mut cursor = text_cursor_for("{$10: 1, foo: 2}");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.field_id(), Some(10));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.field_id(), None);
+        assert_eq!(
+            cursor.raw_field_name(),
+            Some(RawSymbolTokenRef::Text("foo"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unquoted_keyword_field_name_is_illegal() {
+        let mut cursor = text_cursor_for("{null: 1}");
+        assert!(cursor.next().is_ok());
+        cursor.step_in().unwrap();
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_skip_containers() -> IonResult<()> {
+        // {a: [1, (2 3)], b: "}"} followed by a top-level value
+        let mut cursor = text_cursor_for(r#"{a: [1, (2 3)], b: "}", c: {{ "}" }}} 5"#);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_deeply_nested_containers() -> IonResult<()> {
+        // Skipping is iterative, so the nesting depth is not limited by the size of the stack.
+        let depth = 200_000;
+        let text = format!(
+            "{}{} 5 {}",
+            "[(".repeat(depth),
+            ")]".repeat(depth),
+            "[".repeat(depth)
+        );
+        let mut cursor = text_cursor_for(&text);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        // The last list is never closed.
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_out_early() -> IonResult<()> {
+        let mut cursor = text_cursor_for("{a: [1, 2, 3], b: 2} foo::[4]");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        cursor.step_out()?;
+        assert_eq!(cursor.raw_field_name(), Some(RawSymbolTokenRef::Text("a")));
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(
+            cursor.raw_annotations(),
+            vec![RawSymbolTokenRef::Text("foo")]
+        );
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(4));
+        cursor.step_out()?;
+        assert_eq!(
+            cursor.raw_annotations(),
+            vec![RawSymbolTokenRef::Text("foo")]
+        );
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_markers() -> IonResult<()> {
+        let mut cursor = text_cursor_for("$ion_1_0 '$ion_1_0' [$ion_1_0] foo::$ion_1_0");
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.ion_version(), (1, 0));
+        // A quoted symbol is not a version marker
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        // Nor is a symbol inside a container
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        cursor.step_out()?;
+        // Nor is an annotated symbol
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_version_marker() {
+        let mut cursor = text_cursor_for("$ion_2_0");
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_comments() -> IonResult<()> {
+        let mut cursor = text_cursor_for("// line comment\n1 /* block\ncomment */ 2 // trailing");
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(2));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unterminated_container_is_an_error() {
+        let mut cursor = text_cursor_for("[1, 2");
+        assert!(cursor.next().is_ok());
+        assert!(cursor.next().is_err());
+    }
+
+    #[test]
+    fn test_error_reports_offset() {
+        let mut cursor = text_cursor_for("1 2 ]");
+        cursor.next().unwrap();
+        cursor.next().unwrap();
+        let error = cursor.next().unwrap_err();
+        assert!(error.to_string().contains("byte offset 4"), "{}", error);
+    }
+}
@@ -1 +1,3 @@
+pub mod cursor;
+pub(crate) mod parsers;
 pub mod writer;
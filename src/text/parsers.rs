@@ -0,0 +1,583 @@
+//! Functions for interpreting the text of numeric and timestamp tokens found in a text Ion stream.
+//!
+//! By the time these functions are called, the text cursor has already isolated the token's text
+//! by reading up to the next stop character. Each function validates the token against the
+//! grammar in the [Ion Text Encoding](http://amzn.github.io/ion-docs/docs/text.html) spec before
+//! converting it to the corresponding Rust type.
+
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::result::{decoding_error, IonResult};
+use crate::types::{Decimal, Integer, Timestamp};
+
+/// The kinds of numeric values that a numeric token can represent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum NumericKind {
+    Integer,
+    Float,
+    Decimal,
+    Timestamp,
+}
+
+/// Examines the text of a token that begins with a digit or a `-` and determines which kind of
+/// value it represents. This function does not validate the token; that is left to the
+/// corresponding `parse_*` function.
+pub(crate) fn classify_numeric_token(text: &str) -> NumericKind {
+    let bytes = text.as_bytes();
+    // Timestamps always begin with a four digit year followed by either a '-' or a 'T'.
+    if bytes.len() > 4
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && (bytes[4] == b'-' || bytes[4] == b'T')
+    {
+        return NumericKind::Timestamp;
+    }
+    let unsigned = text.strip_prefix('-').unwrap_or(text);
+    if unsigned.starts_with("0x")
+        || unsigned.starts_with("0X")
+        || unsigned.starts_with("0b")
+        || unsigned.starts_with("0B")
+    {
+        return NumericKind::Integer;
+    }
+    if unsigned.contains(&['e', 'E'][..]) {
+        return NumericKind::Float;
+    }
+    if unsigned.contains(&['.', 'd', 'D'][..]) {
+        return NumericKind::Decimal;
+    }
+    NumericKind::Integer
+}
+
+/// Returns the length of the run of digits at the beginning of `bytes`. Underscores are permitted
+/// between digits but may not appear at the beginning or end of the run or next to each other.
+fn digit_run_length(bytes: &[u8], is_digit: fn(&u8) -> bool) -> IonResult<usize> {
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if is_digit(&byte) {
+            index += 1;
+            continue;
+        }
+        if byte == b'_' {
+            let previous_is_digit = index > 0 && is_digit(&bytes[index - 1]);
+            let next_is_digit = bytes.get(index + 1).map(is_digit).unwrap_or(false);
+            if previous_is_digit && next_is_digit {
+                index += 1;
+                continue;
+            }
+            return decoding_error("Found a misplaced underscore in a numeric value.");
+        }
+        break;
+    }
+    Ok(index)
+}
+
+fn is_binary_digit(byte: &u8) -> bool {
+    *byte == b'0' || *byte == b'1'
+}
+
+/// Splits `text` into a flag indicating whether it was negative and the remaining text.
+fn split_sign(text: &str) -> (bool, &str) {
+    match text.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, text),
+    }
+}
+
+/// Validates the integer portion of a base-10 number (`0` or a digit sequence without a leading
+/// zero) and returns its length.
+fn decimal_integer_length(bytes: &[u8]) -> IonResult<usize> {
+    let length = digit_run_length(bytes, u8::is_ascii_digit)?;
+    if length == 0 {
+        return decoding_error("Expected a digit at the beginning of the number.");
+    }
+    if length > 1 && bytes[0] == b'0' {
+        return decoding_error("Numeric values cannot have leading zeros.");
+    }
+    Ok(length)
+}
+
+/// The validated components of a base-10 integer, float, or decimal token.
+struct NumberParts {
+    is_negative: bool,
+    // The digits before the decimal point, with any underscores removed
+    integer_digits: String,
+    // The digits after the decimal point, with any underscores removed
+    fraction_digits: String,
+    // The exponent text (including its sign, if any) with any underscores removed
+    exponent: Option<String>,
+}
+
+/// Breaks a base-10 numeric token into its component parts, validating them along the way.
+/// `exponent_markers` indicates which characters may introduce an exponent.
+fn number_parts(text: &str, exponent_markers: &[u8]) -> IonResult<NumberParts> {
+    let (is_negative, unsigned) = split_sign(text);
+    let bytes = unsigned.as_bytes();
+    let integer_length = decimal_integer_length(bytes)?;
+    let mut index = integer_length;
+    let integer_digits = without_underscores(&unsigned[..integer_length]);
+
+    let mut fraction_digits = String::new();
+    if bytes.get(index) == Some(&b'.') {
+        index += 1;
+        let fraction_length = digit_run_length(&bytes[index..], u8::is_ascii_digit)?;
+        fraction_digits = without_underscores(&unsigned[index..index + fraction_length]);
+        index += fraction_length;
+    }
+
+    let mut exponent = None;
+    if let Some(marker) = bytes.get(index) {
+        if !exponent_markers.contains(marker) {
+            return decoding_error(format!("Found an invalid numeric value: '{}'", text));
+        }
+        index += 1;
+        let exponent_start = index;
+        if let Some(b'+') | Some(b'-') = bytes.get(index) {
+            index += 1;
+        }
+        let exponent_length = digit_run_length(&bytes[index..], u8::is_ascii_digit)?;
+        if exponent_length == 0 {
+            return decoding_error(format!(
+                "Found a numeric value with no exponent: '{}'",
+                text
+            ));
+        }
+        index += exponent_length;
+        exponent = Some(without_underscores(&unsigned[exponent_start..index]));
+    }
+
+    if index != bytes.len() {
+        return decoding_error(format!("Found an invalid numeric value: '{}'", text));
+    }
+
+    Ok(NumberParts {
+        is_negative,
+        integer_digits,
+        fraction_digits,
+        exponent,
+    })
+}
+
+fn without_underscores(text: &str) -> String {
+    text.chars().filter(|c| *c != '_').collect()
+}
+
+/// Parses the text of an integer token, which may be written in base 10, base 16 (`0x`), or
+/// base 2 (`0b`).
+pub(crate) fn parse_integer(text: &str) -> IonResult<Integer> {
+    let (is_negative, unsigned) = split_sign(text);
+    let (radix, digits, is_digit): (u32, &str, fn(&u8) -> bool) =
+        if unsigned.starts_with("0x") || unsigned.starts_with("0X") {
+            (16, &unsigned[2..], u8::is_ascii_hexdigit)
+        } else if unsigned.starts_with("0b") || unsigned.starts_with("0B") {
+            (2, &unsigned[2..], is_binary_digit)
+        } else {
+            decimal_integer_length(unsigned.as_bytes())?;
+            (10, unsigned, u8::is_ascii_digit)
+        };
+    let length = digit_run_length(digits.as_bytes(), is_digit)?;
+    if length == 0 || length != digits.len() {
+        return decoding_error(format!("Found an invalid integer: '{}'", text));
+    }
+    let mut digits = without_underscores(digits);
+    if is_negative {
+        digits.insert(0, '-');
+    }
+    if let Ok(value) = i64::from_str_radix(&digits, radix) {
+        return Ok(Integer::I64(value));
+    }
+    // The integer is too large for an i64.
+    match BigInt::parse_bytes(digits.as_bytes(), radix) {
+        Some(value) => Ok(Integer::BigInt(value)),
+        None => decoding_error(format!("Found an invalid integer: '{}'", text)),
+    }
+}
+
+/// Parses the text of a float token (e.g. `1.5e3`).
+pub(crate) fn parse_float(text: &str) -> IonResult<f64> {
+    let parts = number_parts(text, b"eE")?;
+    let exponent = match parts.exponent {
+        Some(exponent) => exponent,
+        None => return decoding_error(format!("Found a float with no exponent: '{}'", text)),
+    };
+    let normalized = format!(
+        "{}{}.{}e{}",
+        if parts.is_negative { "-" } else { "" },
+        parts.integer_digits,
+        parts.fraction_digits,
+        exponent
+    );
+    f64::from_str(&normalized)
+        .or_else(|_| decoding_error(format!("Found an invalid float: '{}'", text)))
+}
+
+/// Parses the text of a decimal token (e.g. `1.5`, `15d-1`). The decimal's precision and the
+/// sign of a zero coefficient are preserved.
+pub(crate) fn parse_decimal(text: &str) -> IonResult<Decimal> {
+    let parts = number_parts(text, b"dD")?;
+    let exponent: i64 = match parts.exponent {
+        Some(exponent) => i64::from_str(&exponent).or_else(|_| {
+            decoding_error(format!(
+                "Found a decimal with an invalid exponent: '{}'",
+                text
+            ))
+        })?,
+        None => 0,
+    };
+    // The coefficient is made up of all of the digits on both sides of the decimal point. Each
+    // digit after the decimal point reduces the exponent by one.
+    let exponent = match exponent.checked_sub(parts.fraction_digits.len() as i64) {
+        Some(exponent) => exponent,
+        None => {
+            return decoding_error(format!(
+                "Found a decimal with an invalid exponent: '{}'",
+                text
+            ))
+        }
+    };
+    let digits = format!("{}{}", parts.integer_digits, parts.fraction_digits);
+    let magnitude = BigUint::from_str(&digits)
+        .or_else(|_| decoding_error(format!("Found an invalid decimal: '{}'", text)))?;
+    if parts.is_negative && magnitude.bits() == 0 {
+        return Ok(Decimal::negative_zero_with_exponent(exponent));
+    }
+    let sign = if parts.is_negative {
+        Sign::Minus
+    } else {
+        Sign::Plus
+    };
+    Ok(Decimal::new(
+        BigInt::from_biguint(sign, magnitude),
+        exponent,
+    ))
+}
+
+/// A helper for reading the fixed-width fields of a timestamp token one at a time.
+struct TimestampScanner<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> TimestampScanner<'a> {
+    fn new(text: &'a str) -> Self {
+        TimestampScanner {
+            text,
+            bytes: text.as_bytes(),
+            index: 0,
+        }
+    }
+
+    fn error<T>(&self) -> IonResult<T> {
+        decoding_error(format!("Found an invalid timestamp: '{}'", self.text))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.index == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.index).copied()
+    }
+
+    // Consumes the next byte if it is `expected`.
+    fn consume_if(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.index += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, expected: u8) -> IonResult<()> {
+        if self.consume_if(expected) {
+            return Ok(());
+        }
+        self.error()
+    }
+
+    // Reads exactly `count` ASCII digits and returns their value.
+    fn digits(&mut self, count: usize) -> IonResult<u32> {
+        let end = self.index + count;
+        if end > self.bytes.len() || !self.bytes[self.index..end].iter().all(u8::is_ascii_digit) {
+            return self.error();
+        }
+        let value = self.text[self.index..end].parse().unwrap();
+        self.index = end;
+        Ok(value)
+    }
+}
+
+/// Parses the text of a timestamp token (e.g. `2020T`, `2020-06-15T10:30:00.5Z`).
+///
+/// The resulting Timestamp preserves the token's precision, the exact digits of its fractional
+/// seconds, and whether its offset was known. An offset of `-00:00` indicates an unknown offset.
+pub(crate) fn parse_timestamp(text: &str) -> IonResult<Timestamp> {
+    let mut scanner = TimestampScanner::new(text);
+    let year = scanner.digits(4)?;
+    if year == 0 {
+        return scanner.error();
+    }
+
+    // Year and month precision timestamps must end with a 'T'.
+    if scanner.consume_if(b'T') {
+        if !scanner.is_finished() {
+            return scanner.error();
+        }
+        return Timestamp::with_year(year).or_else(|_| scanner.error());
+    }
+    scanner.expect(b'-')?;
+    let month = scanner.digits(2)?;
+    if scanner.consume_if(b'T') {
+        if !scanner.is_finished() {
+            return scanner.error();
+        }
+        return Timestamp::with_year_and_month(year, month).or_else(|_| scanner.error());
+    }
+    scanner.expect(b'-')?;
+    let day = scanner.digits(2)?;
+    // Day precision timestamps may omit the trailing 'T'.
+    if !scanner.is_finished() {
+        scanner.expect(b'T')?;
+    }
+    if scanner.is_finished() {
+        return Timestamp::with_ymd(year, month, day).or_else(|_| scanner.error());
+    }
+
+    let hour = scanner.digits(2)?;
+    scanner.expect(b':')?;
+    let minute = scanner.digits(2)?;
+    let mut second = None;
+    let mut fractional_seconds = None;
+    if scanner.consume_if(b':') {
+        second = Some(scanner.digits(2)?);
+        if scanner.consume_if(b'.') {
+            let fraction_start = scanner.index;
+            while let Some(byte) = scanner.peek() {
+                if !byte.is_ascii_digit() {
+                    break;
+                }
+                scanner.index += 1;
+            }
+            let fraction = &text[fraction_start..scanner.index];
+            if fraction.is_empty() {
+                return scanner.error();
+            }
+            fractional_seconds = Some(BigDecimal::from_str(&format!("0.{}", fraction)).unwrap());
+        }
+    }
+
+    let offset_minutes = match scanner.peek() {
+        Some(b'Z') | Some(b'z') => {
+            scanner.index += 1;
+            Some(0)
+        }
+        Some(sign @ b'+') | Some(sign @ b'-') => {
+            scanner.index += 1;
+            let offset_hours = scanner.digits(2)? as i32;
+            scanner.expect(b':')?;
+            let offset_mins = scanner.digits(2)? as i32;
+            if offset_hours >= 24 || offset_mins >= 60 {
+                return scanner.error();
+            }
+            match (sign, offset_hours * 60 + offset_mins) {
+                // `-00:00` indicates that the offset is unknown.
+                (b'-', 0) => None,
+                (b'-', offset) => Some(-offset),
+                (_, offset) => Some(offset),
+            }
+        }
+        _ => return scanner.error(),
+    };
+    if !scanner.is_finished() {
+        return scanner.error();
+    }
+
+    let timestamp = match second {
+        Some(second) => Timestamp::with_ymd_hms(year, month, day, hour, minute, second),
+        None => Timestamp::with_ymd_hm(year, month, day, hour, minute),
+    };
+    let mut timestamp = timestamp.or_else(|_| scanner.error())?;
+    if let Some(offset_minutes) = offset_minutes {
+        timestamp = timestamp.with_offset(offset_minutes)?;
+    }
+    if let Some(fractional_seconds) = fractional_seconds {
+        timestamp = timestamp.with_fractional_seconds(fractional_seconds)?;
+    }
+    Ok(timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_numeric_token() {
+        use NumericKind::*;
+        assert_eq!(classify_numeric_token("0"), Integer);
+        assert_eq!(classify_numeric_token("-0x1F"), Integer);
+        assert_eq!(classify_numeric_token("0b101"), Integer);
+        assert_eq!(classify_numeric_token("1_000"), Integer);
+        assert_eq!(classify_numeric_token("1.5e3"), Float);
+        assert_eq!(classify_numeric_token("-1E-3"), Float);
+        assert_eq!(classify_numeric_token("1.5"), Decimal);
+        assert_eq!(classify_numeric_token("15d-1"), Decimal);
+        assert_eq!(classify_numeric_token("2020T"), Timestamp);
+        assert_eq!(classify_numeric_token("2020-01-01"), Timestamp);
+    }
+
+    #[test]
+    fn test_parse_integer() -> IonResult<()> {
+        assert_eq!(parse_integer("0")?, Integer::I64(0));
+        assert_eq!(parse_integer("-17")?, Integer::I64(-17));
+        assert_eq!(parse_integer("1_000_000")?, Integer::I64(1_000_000));
+        assert_eq!(parse_integer("0xFF")?, Integer::I64(255));
+        assert_eq!(parse_integer("-0x1_0")?, Integer::I64(-16));
+        assert_eq!(parse_integer("0b1010")?, Integer::I64(10));
+        assert_eq!(
+            parse_integer("-9223372036854775808")?,
+            Integer::I64(i64::MIN)
+        );
+        assert_eq!(
+            parse_integer("9223372036854775808")?,
+            Integer::BigInt(BigInt::from_str("9223372036854775808").unwrap())
+        );
+        assert_eq!(
+            parse_integer("-0x1_0000_0000_0000_0000")?,
+            Integer::BigInt(BigInt::from_str("-18446744073709551616").unwrap())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_integer_invalid() {
+        assert!(parse_integer("007").is_err());
+        assert!(parse_integer("1__0").is_err());
+        assert!(parse_integer("_1").is_err());
+        assert!(parse_integer("1_").is_err());
+        assert!(parse_integer("0x").is_err());
+        assert!(parse_integer("0b102").is_err());
+        assert!(parse_integer("12a").is_err());
+    }
+
+    #[test]
+    fn test_parse_float() -> IonResult<()> {
+        assert_eq!(parse_float("1.5e3")?, 1500f64);
+        assert_eq!(parse_float("-1e-3")?, -0.001f64);
+        assert_eq!(parse_float("1.e0")?, 1f64);
+        assert_eq!(parse_float("1_0.0_1e0")?, 10.01f64);
+        let negative_zero = parse_float("-0e0")?;
+        assert_eq!(negative_zero, 0f64);
+        assert!(negative_zero.is_sign_negative());
+        assert!(parse_float("1.5").is_err());
+        assert!(parse_float("1e").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_decimal() -> IonResult<()> {
+        assert_eq!(parse_decimal("1.5")?, Decimal::new(15, -1));
+        assert_eq!(parse_decimal("-15d-1")?, Decimal::new(-15, -1));
+        assert_eq!(parse_decimal("1.5d2")?, Decimal::new(15, 1));
+        assert_eq!(parse_decimal("0.")?, Decimal::new(0, 0));
+        assert_eq!(parse_decimal("1_2.3_4")?, Decimal::new(1234, -2));
+        assert_eq!(parse_decimal("0.00")?, Decimal::new(0, -2));
+        assert_eq!(parse_decimal("-0d0")?, Decimal::negative_zero());
+        assert_eq!(
+            parse_decimal("-0.0")?,
+            Decimal::negative_zero_with_exponent(-1)
+        );
+        assert!(parse_decimal("1.5d").is_err());
+        assert!(parse_decimal("01.5").is_err());
+        Ok(())
+    }
+
+    fn fraction(text: &str) -> BigDecimal {
+        BigDecimal::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_timestamp() -> IonResult<()> {
+        assert_eq!(parse_timestamp("2020T")?, Timestamp::with_year(2020)?);
+        assert_eq!(
+            parse_timestamp("2020-06T")?,
+            Timestamp::with_year_and_month(2020, 6)?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15")?,
+            Timestamp::with_ymd(2020, 6, 15)?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15T")?,
+            Timestamp::with_ymd(2020, 6, 15)?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15T10:30Z")?,
+            Timestamp::with_ymd_hm(2020, 6, 15, 10, 30)?.with_offset(0)?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15T10:30:45-07:00")?,
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?.with_offset(-420)?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15T10:30:45.25+01:30")?,
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_offset(90)?
+                .with_fractional_seconds(fraction("0.25"))?
+        );
+        assert_eq!(
+            parse_timestamp("2020-06-15T10:30:45.123456789123-00:00")?,
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_fractional_seconds(fraction("0.123456789123"))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_timestamp_preserves_precision_and_offset() -> IonResult<()> {
+        assert_ne!(
+            parse_timestamp("2020T")?,
+            parse_timestamp("2020-01-01T00:00Z")?
+        );
+        assert_ne!(
+            parse_timestamp("2020-01-01T00:00Z")?,
+            parse_timestamp("2020-01-01T00:00-00:00")?
+        );
+        assert_eq!(
+            parse_timestamp("2020-01-01T00:00Z")?,
+            parse_timestamp("2020-01-01T00:00+00:00")?
+        );
+        assert_ne!(
+            parse_timestamp("2020-01-01T00:00:00.5Z")?,
+            parse_timestamp("2020-01-01T00:00:00.50Z")?
+        );
+        for text in &[
+            "2020T",
+            "2020-06T",
+            "2020-06-15",
+            "2020-06-15T10:30-00:00",
+            "2020-06-15T10:30:45.000Z",
+            "2020-06-15T10:30:45.0012-23:59",
+        ] {
+            assert_eq!(&parse_timestamp(text)?.to_string(), text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        assert!(parse_timestamp("2020").is_err());
+        assert!(parse_timestamp("2020-06").is_err());
+        assert!(parse_timestamp("2020-13-01").is_err());
+        assert!(parse_timestamp("2020-02-30").is_err());
+        assert!(parse_timestamp("2020-06-15T10:30").is_err());
+        assert!(parse_timestamp("2020-06-15T10:30:45.Z").is_err());
+        assert!(parse_timestamp("2020-06-15T25:30Z").is_err());
+        assert!(parse_timestamp("0000T").is_err());
+        assert!(parse_timestamp("2020T-06").is_err());
+        assert!(parse_timestamp("2020-06-1510:30Z").is_err());
+    }
+}
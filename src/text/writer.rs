@@ -1,27 +1,127 @@
 use crate::result::{illegal_operation, IonResult};
+use crate::types::{Decimal, Integer, SymbolToken, Timestamp};
 use crate::IonType;
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
 use std::io::{BufWriter, Write};
 
-pub struct TextWriter<W: Write> {
-    output: BufWriter<W>,
-    annotations: Vec<String>,
-    field_name: Option<String>,
-    containers: Vec<IonType>,
+/// Controls how a [TextWriter] arranges whitespace in its output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TextLayout {
+    /// All values are written on a single line, with top-level values separated by a space.
+    Compact,
+    /// Each top-level value is written on its own line. Containers are written on a single line.
+    Lines,
+    /// Each top-level value is written on its own line, and each value nested inside a container
+    /// is written on its own indented line.
+    Pretty,
 }
 
-impl<W: Write> TextWriter<W> {
-    /// Constructs a new instance of TextWriter that writes values to the provided io::Write
-    /// implementation.
-    pub fn new(sink: W) -> TextWriter<W> {
+/// Configures and constructs new instances of [TextWriter].
+///
+/// ```
+/// use ion_rs::text::writer::{TextLayout, TextWriterBuilder};
+/// let mut output = vec![];
+/// let mut writer = TextWriterBuilder::new()
+///     .layout(TextLayout::Pretty)
+///     .indentation("    ")
+///     .build(&mut output);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextWriterBuilder {
+    layout: TextLayout,
+    indentation: String,
+    is_json: bool,
+}
+
+impl TextWriterBuilder {
+    /// Creates a TextWriterBuilder that uses the [TextLayout::Lines] layout and indents
+    /// pretty-printed values with two spaces.
+    pub fn new() -> TextWriterBuilder {
+        TextWriterBuilder {
+            layout: TextLayout::Lines,
+            indentation: "  ".to_string(),
+            is_json: false,
+        }
+    }
+
+    /// Sets the layout that the TextWriter will use.
+    pub fn layout(mut self, layout: TextLayout) -> TextWriterBuilder {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the text that the TextWriter will write once per level of nesting before each value
+    /// when using the [TextLayout::Pretty] layout. It is ignored by the other layouts.
+    pub fn indentation(mut self, indentation: &str) -> TextWriterBuilder {
+        self.indentation = indentation.to_string();
+        self
+    }
+
+    /// If `is_json` is true, the TextWriter will write JSON instead of Ion, following the Ion to
+    /// JSON [down-conversion rules][rules]:
+    ///
+    /// * Annotations are dropped.
+    /// * Nulls of any type are written as `null`.
+    /// * Integers, decimals, and finite floats are written as JSON numbers. `nan`, `+inf` and
+    ///   `-inf` are written as `null`.
+    /// * Symbols, timestamps, and field names are written as JSON strings.
+    /// * Blobs are written as strings containing their base64 encoding. Clobs are written as
+    ///   strings in which each byte is a code point in the range `U+0000..=U+00FF`.
+    /// * S-expressions are written as JSON arrays.
+    ///
+    /// [rules]: http://amzn.github.io/ion-docs/guides/cookbook.html#down-converting-to-json
+    pub fn json(mut self, is_json: bool) -> TextWriterBuilder {
+        self.is_json = is_json;
+        self
+    }
+
+    /// Constructs a TextWriter that writes values to the provided io::Write implementation.
+    pub fn build<W: Write>(self, sink: W) -> TextWriter<W> {
         TextWriter {
             output: BufWriter::new(sink),
+            layout: self.layout,
+            indentation: self.indentation,
+            is_json: self.is_json,
             annotations: vec![],
             field_name: None,
             containers: vec![],
         }
     }
+}
+
+impl Default for TextWriterBuilder {
+    fn default() -> Self {
+        TextWriterBuilder::new()
+    }
+}
+
+// A container that the TextWriter has stepped into but not yet stepped out of.
+struct Container {
+    ion_type: IonType,
+    // Whether any values have been written to the container. Empty containers are written on a
+    // single line (e.g. `[]`) even when pretty-printing.
+    is_empty: bool,
+}
+
+pub struct TextWriter<W: Write> {
+    output: BufWriter<W>,
+    layout: TextLayout,
+    indentation: String,
+    is_json: bool,
+    annotations: Vec<SymbolToken>,
+    field_name: Option<SymbolToken>,
+    containers: Vec<Container>,
+}
+
+impl<W: Write> TextWriter<W> {
+    /// Constructs a new instance of TextWriter that writes values to the provided io::Write
+    /// implementation using the [TextLayout::Lines] layout. Use [TextWriterBuilder] to choose a
+    /// different layout.
+    pub fn new(sink: W) -> TextWriter<W> {
+        TextWriterBuilder::new().build(sink)
+    }
 
     /// Returns a reference to the underlying io::Write implementation.
     pub fn output(&self) -> &W {
@@ -38,13 +138,25 @@ impl<W: Write> TextWriter<W> {
     /// of a struct, the field name will be written before the next value. Otherwise, it will be
     /// ignored.
     pub fn set_field_name(&mut self, name: &str) {
-        self.field_name = Some(name.to_string());
+        self.field_name = Some(SymbolToken::with_text(name));
+    }
+
+    /// Like [TextWriter::set_field_name], but accepts a SymbolToken. If the token's text is
+    /// unknown, the field name is written as its symbol ID (for example, `$10`).
+    pub fn set_field_name_token(&mut self, name: &SymbolToken) {
+        self.field_name = Some(name.clone());
     }
 
     /// Sets a list of annotations that will be applied to the next value that is written.
     pub fn set_annotations(&mut self, annotations: &[&str]) {
         self.annotations
-            .extend(annotations.iter().map(|s| s.to_string()));
+            .extend(annotations.iter().map(|s| SymbolToken::with_text(*s)));
+    }
+
+    /// Like [TextWriter::set_annotations], but accepts SymbolTokens. Annotations with unknown
+    /// text are written as their symbol IDs (for example, `$10`).
+    pub fn set_annotation_tokens(&mut self, annotations: &[SymbolToken]) {
+        self.annotations.extend_from_slice(annotations);
     }
 
     /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
@@ -55,16 +167,20 @@ impl<W: Write> TextWriter<W> {
         match ion_type {
             Struct => write!(self.output, "{{")?,
             List => write!(self.output, "[")?,
+            SExpression if self.is_json => write!(self.output, "[")?,
             SExpression => write!(self.output, "(")?,
             _ => return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type)),
         }
-        self.containers.push(ion_type);
+        self.containers.push(Container {
+            ion_type,
+            is_empty: true,
+        });
         Ok(())
     }
 
     /// Returns true if the TextWriter is currently positioned within a Struct.
     pub fn is_in_struct(&self) -> bool {
-        if let Some(IonType::Struct) = self.containers.last() {
+        if let Some(IonType::Struct) = self.containers.last().map(|c| c.ion_type) {
             return true;
         }
         false
@@ -74,13 +190,22 @@ impl<W: Write> TextWriter<W> {
     // container, `step_out` will return an Err(IllegalOperation).
     pub fn step_out(&mut self) -> IonResult<()> {
         use IonType::*;
-        let end_delimiter = match self.containers.pop() {
-            Some(Struct) => "}",
-            Some(List) => "]",
-            Some(SExpression) => ")",
-            Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
+        let container = match self.containers.pop() {
+            Some(container) => container,
             None => return illegal_operation("Cannot step out of the top level."),
         };
+        let end_delimiter = match container.ion_type {
+            Struct => "}",
+            List => "]",
+            SExpression if self.is_json => "]",
+            SExpression => ")",
+            scalar => unreachable!("Inside a non-container type: {:?}", scalar),
+        };
+        // A pretty-printed container's end delimiter goes on its own line, aligned with the
+        // container's first line.
+        if self.layout == TextLayout::Pretty && !container.is_empty {
+            self.write_indented_newline()?;
+        }
         write!(self.output, "{}", end_delimiter)?;
         self.write_value_delimiter()?;
         Ok(())
@@ -89,29 +214,68 @@ impl<W: Write> TextWriter<W> {
     // Called after each value is written to emit an appropriate delimiter before the next value.
     fn write_value_delimiter(&mut self) -> IonResult<()> {
         use IonType::*;
-        let delimiter = match self.containers.last() {
-            Some(Struct) | Some(List) => ",",
-            Some(SExpression) => " ",
-            Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
-            None => "\n", // Top-level values appear on their own line
+        let delimiter = match (self.containers.last().map(|c| c.ion_type), self.layout) {
+            // JSON does not allow trailing commas, so its values are separated by commas that
+            // are written before each value instead. See `write_value_metadata`.
+            (Some(_), _) if self.is_json => "",
+            (Some(Struct), _) | (Some(List), _) => ",",
+            // Pretty-printed s-expression values are already separated by newlines.
+            (Some(SExpression), TextLayout::Pretty) => "",
+            (Some(SExpression), _) => " ",
+            (Some(scalar), _) => unreachable!("Inside a non-container type: {:?}", scalar),
+            (None, TextLayout::Compact) => " ",
+            (None, _) => "\n", // Top-level values appear on their own line
         };
         write!(self.output, "{}", delimiter)?;
         Ok(())
     }
 
+    // Starts a new line indented to the current depth.
+    fn write_indented_newline(&mut self) -> IonResult<()> {
+        writeln!(self.output)?;
+        for _ in 0..self.containers.len() {
+            write!(self.output, "{}", self.indentation)?;
+        }
+        Ok(())
+    }
+
     // Write the field name and annotations if set
     fn write_value_metadata(&mut self) -> IonResult<()> {
+        // When pretty-printing, each value in a container starts on a new line.
+        if let Some(container) = self.containers.last_mut() {
+            if self.is_json && !container.is_empty {
+                write!(self.output, ",")?;
+            }
+            container.is_empty = false;
+            if self.layout == TextLayout::Pretty {
+                self.write_indented_newline()?;
+            }
+        }
         if let Some(field_name) = &self.field_name.take() {
-            write!(self.output, "{}:", field_name)?;
+            if self.is_json {
+                match field_name.text() {
+                    Some(text) => write_json_string(&mut self.output, text)?,
+                    None => write_json_string(&mut self.output, &field_name.to_string())?,
+                }
+            } else {
+                write_symbol_token(&mut self.output, field_name)?;
+            }
+            if self.layout == TextLayout::Pretty {
+                write!(self.output, ": ")?;
+            } else {
+                write!(self.output, ":")?;
+            }
         } else if self.is_in_struct() {
             return illegal_operation(format!("Values inside a struct must have a field name."));
         }
-        if !self.annotations.is_empty() {
+        // JSON has no equivalent to annotations, so they are discarded.
+        if !self.annotations.is_empty() && !self.is_json {
             for annotation in &self.annotations {
-                write!(self.output, "'{}'::", annotation)?;
+                write_symbol_token(&mut self.output, annotation)?;
+                write!(self.output, "::")?;
             }
-            self.annotations.clear();
         }
+        self.annotations.clear();
         Ok(())
     }
 
@@ -133,8 +297,10 @@ impl<W: Write> TextWriter<W> {
     /// Writes an Ion null of the specified type.
     pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
         use IonType::*;
+        let is_json = self.is_json;
         self.write_scalar(|output| {
             let null_text = match ion_type {
+                _ if is_json => "null",
                 Null => "null",
                 Boolean => "null.bool",
                 Integer => "null.int",
@@ -174,9 +340,39 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
+    /// Writes the provided Integer value as an Ion integer.
+    pub fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", value)?;
+            Ok(())
+        })
+    }
+
+    /// Writes the provided BigInt value as an Ion integer.
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        self.write_scalar(|output| {
+            write!(output, "{}", value)?;
+            Ok(())
+        })
+    }
+
+    /// Writes the provided f32 value as an Ion float. Ion text floats are 64 bits wide, so the
+    /// value is widened to the f64 that represents it exactly. This is the same Ion value that
+    /// the binary writer's 4-byte encoding represents.
+    pub fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        self.write_f64(f64::from(value))
+    }
+
     /// Writes the provided f64 value as an Ion float.
     pub fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
+            // JSON numbers cannot represent nan or infinity.
+            if is_json && !value.is_finite() {
+                write!(output, "null")?;
+                return Ok(());
+            }
+
             if value.is_nan() {
                 write!(output, "nan")?;
                 return Ok(());
@@ -191,8 +387,10 @@ impl<W: Write> TextWriter<W> {
                 return Ok(());
             }
 
-            // The {:e} formatter provided by the Display trait writes floats using scientific
-            // notation. It works for all floating point values except -0.0 (it drops the sign).
+            // The {:e} formatter provided by the LowerExp trait writes floats using scientific
+            // notation with the fewest digits needed to read back the same value, so the float
+            // will round-trip exactly. It works for all floating point values except -0.0 (it
+            // drops the sign).
             // See: https://github.com/rust-lang/rust/issues/20596
             if value == 0.0f64 && value.is_sign_negative() {
                 write!(output, "-0e0")?;
@@ -204,41 +402,106 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
-    /// Writes the provided BigDecimal value as an Ion decimal.
-    pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+    /// Writes the provided Decimal value as an Ion decimal, preserving its precision and the
+    /// sign of a zero coefficient.
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
-            write!(output, "{}", &value)?;
+            if is_json {
+                // JSON numbers use `e` rather than `d` to introduce an exponent, and cannot end
+                // with a decimal point.
+                let text = value.to_string().replace('d', "e");
+                write!(output, "{}", text.trim_end_matches('.'))?;
+                return Ok(());
+            }
+            write!(output, "{}", value)?;
             Ok(())
         })
     }
 
+    /// Writes the provided BigDecimal value as an Ion decimal. Like [TextWriter::write_decimal],
+    /// this preserves the value's precision and always writes text that will be read back as a
+    /// decimal (e.g. `100.` rather than `100`).
+    pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        self.write_decimal(&Decimal::from(value.clone()))
+    }
+
     /// Writes the provided DateTime value as an Ion timestamp.
     pub fn write_datetime(&mut self, value: &DateTime<FixedOffset>) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
+            if is_json {
+                return write_json_string(output, &value.to_rfc3339());
+            }
             write!(output, "{}", value.to_rfc3339())?;
             Ok(())
         })
     }
 
-    /// Writes the provided &str value as an Ion symbol.
-    pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+    /// Writes the provided Timestamp value as an Ion timestamp, preserving its precision,
+    /// fractional seconds, and offset.
+    pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
-            write!(output, "'{}'", value.as_ref())?;
+            if is_json {
+                return write_json_string(output, &value.to_string());
+            }
+            write!(output, "{}", value)?;
             Ok(())
         })
     }
 
-    /// Writes the provided &str value as an Ion string.
+    /// Writes the provided &str value as an Ion symbol. The symbol is written without quotes if
+    /// its text is a legal identifier; otherwise, it is quoted and escaped.
+    pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let is_json = self.is_json;
+        self.write_scalar(|output| {
+            if is_json {
+                return write_json_string(output, value.as_ref());
+            }
+            write_symbol_text(output, value.as_ref())
+        })
+    }
+
+    /// Writes the provided SymbolToken as an Ion symbol. If the token's text is unknown, the
+    /// symbol is written as its symbol ID (for example, `$10`); a token with neither text nor a
+    /// symbol ID is written as `$0`.
+    pub fn write_symbol_token(&mut self, value: &SymbolToken) -> IonResult<()> {
+        if let Some(text) = value.text() {
+            return self.write_symbol(text);
+        }
+        let is_json = self.is_json;
+        self.write_scalar(|output| {
+            if is_json {
+                return write_json_string(output, &value.to_string());
+            }
+            write_symbol_token(output, value)
+        })
+    }
+
+    /// Writes the provided &str value as an Ion string, escaping any characters that cannot
+    /// appear in a string literal as-is.
     pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
-            write!(output, "\"{}\"", value.as_ref())?;
+            if is_json {
+                return write_json_string(output, value.as_ref());
+            }
+            write!(output, "\"")?;
+            write_escaped_text(output, value.as_ref(), '"')?;
+            write!(output, "\"")?;
             Ok(())
         })
     }
 
     /// Writes the provided byte array slice as an Ion blob.
     pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        let is_json = self.is_json;
         self.write_scalar(|output| {
+            if is_json {
+                write!(output, "\"{}\"", base64::encode(value))?;
+                return Ok(());
+            }
             // Rust format strings escape curly braces by doubling them. The following string is:
             // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
             // * A {} pair used by the format string to indicate where the base64-encoded bytes
@@ -250,19 +513,147 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
-    /// Writes the provided byte array slice as an Ion clob.
-    pub fn write_clob(&mut self, _value: &[u8]) -> IonResult<()> {
-        todo!()
+    /// Writes the provided byte array slice as an Ion clob. Bytes outside of the printable ASCII
+    /// range are written as `\xHH` escapes.
+    pub fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        let is_json = self.is_json;
+        self.write_scalar(|output| {
+            if is_json {
+                let text: String = value.iter().map(|&byte| byte as char).collect();
+                return write_json_string(output, &text);
+            }
+            write!(output, "{{{{\"")?;
+            for &byte in value {
+                match byte {
+                    b'"' | b'\\' => write!(output, "\\{}", byte as char)?,
+                    0x20..=0x7E => output.write_all(&[byte])?,
+                    _ => write!(output, "\\x{:02x}", byte)?,
+                }
+            }
+            write!(output, "\"}}}}")?;
+            Ok(())
+        })
+    }
+}
+
+// Ion keywords that would not be read as a symbol if they were written without quotes.
+const KEYWORDS: &[&str] = &["null", "true", "false", "nan"];
+
+// Returns true if `text` can be written as a symbol without quotes. An identifier consists of
+// ASCII letters, digits, `_` and `$`, cannot begin with a digit, and cannot be a keyword. Text
+// like `$10` must also be quoted, since it would otherwise be read as a symbol ID, as must text
+// like `$ion_1_0`, which would be read as an Ion version marker at the top level.
+fn is_identifier(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let is_identifier_byte =
+        |byte: &u8| byte.is_ascii_alphanumeric() || *byte == b'_' || *byte == b'$';
+    let is_symbol_id =
+        bytes.len() > 1 && bytes[0] == b'$' && bytes[1..].iter().all(u8::is_ascii_digit);
+    match bytes.first() {
+        Some(first) if !first.is_ascii_digit() => {
+            bytes.iter().all(is_identifier_byte)
+                && !is_symbol_id
+                && !is_version_marker(text)
+                && !KEYWORDS.contains(&text)
+        }
+        _ => false,
+    }
+}
+
+// Returns true if `text` has the form of an Ion version marker: `$ion_<digits>_<digits>`.
+fn is_version_marker(text: &str) -> bool {
+    let is_number = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+    let version = text.strip_prefix("$ion_").unwrap_or_default();
+    match version.split_once('_') {
+        Some((major, minor)) => is_number(major) && is_number(minor),
+        None => false,
+    }
+}
+
+// Writes `text` as a symbol, adding quotes if it is not a legal identifier.
+fn write_symbol_text<O: Write>(output: &mut O, text: &str) -> IonResult<()> {
+    if is_identifier(text) {
+        write!(output, "{}", text)?;
+        return Ok(());
+    }
+    write!(output, "'")?;
+    write_escaped_text(output, text, '\'')?;
+    write!(output, "'")?;
+    Ok(())
+}
+
+// Writes `token` as a symbol. Tokens with unknown text are written as their symbol ID.
+fn write_symbol_token<O: Write>(output: &mut O, token: &SymbolToken) -> IonResult<()> {
+    match token.text() {
+        Some(text) => write_symbol_text(output, text),
+        None => {
+            write!(output, "${}", token.local_sid().unwrap_or(0))?;
+            Ok(())
+        }
+    }
+}
+
+// Writes `text` as a JSON string. JSON has no `\xHH` escape, so control characters are written
+// using `\uHHHH` escapes.
+fn write_json_string<O: Write>(output: &mut O, text: &str) -> IonResult<()> {
+    write!(output, "\"")?;
+    let mut run_start = 0;
+    for (index, c) in text.char_indices() {
+        if c != '"' && c != '\\' && c >= ' ' {
+            continue;
+        }
+        output.write_all(&text.as_bytes()[run_start..index])?;
+        match c {
+            '\n' => write!(output, "\\n")?,
+            '\r' => write!(output, "\\r")?,
+            '\t' => write!(output, "\\t")?,
+            '"' | '\\' => write!(output, "\\{}", c)?,
+            c => write!(output, "\\u{:04x}", c as u32)?,
+        }
+        run_start = index + c.len_utf8();
     }
+    output.write_all(&text.as_bytes()[run_start..])?;
+    write!(output, "\"")?;
+    Ok(())
+}
+
+// Writes the body of a string or quoted symbol, escaping backslashes, control characters and
+// the `delimiter` character that marks the end of the literal.
+fn write_escaped_text<O: Write>(output: &mut O, text: &str, delimiter: char) -> IonResult<()> {
+    // Characters that don't need to be escaped are written in runs rather than one at a time.
+    let mut run_start = 0;
+    for (index, c) in text.char_indices() {
+        let needs_escape = c == delimiter || c == '\\' || c < ' ' || c == '\u{7F}';
+        if !needs_escape {
+            continue;
+        }
+        output.write_all(&text.as_bytes()[run_start..index])?;
+        match c {
+            '\n' => write!(output, "\\n")?,
+            '\r' => write!(output, "\\r")?,
+            '\t' => write!(output, "\\t")?,
+            '\\' => write!(output, "\\\\")?,
+            c if c == delimiter => write!(output, "\\{}", c)?,
+            c => write!(output, "\\x{:02x}", c as u32)?,
+        }
+        run_start = index + c.len_utf8();
+    }
+    output.write_all(&text.as_bytes()[run_start..])?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::cursor::{Cursor, RawSymbolTokenRef, StreamItem::Value};
     use crate::result::IonResult;
-    use crate::text::writer::TextWriter;
+    use crate::text::writer::{TextLayout, TextWriter, TextWriterBuilder};
+    use crate::types::{Decimal, Integer, Timestamp};
     use crate::IonType;
+    use crate::{Reader, TextIonCursor};
     use bigdecimal::BigDecimal;
     use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
+    use std::io;
     use std::str;
     use std::str::FromStr;
 
@@ -302,6 +693,15 @@ mod tests {
         writer_test(|w| w.write_i64(7), "7\n");
     }
 
+    #[test]
+    fn write_integer() {
+        let big = BigInt::from_str("123456789012345678901234567890").unwrap();
+        writer_test(
+            |w| w.write_integer(&Integer::from(big.clone())),
+            "123456789012345678901234567890\n",
+        );
+    }
+
     #[test]
     fn write_f64() {
         writer_test(|w| w.write_f64(700f64), "7e2\n");
@@ -314,7 +714,7 @@ mod tests {
                 w.set_annotations(&["foo", "bar", "baz"]);
                 w.write_i64(7)
             },
-            "'foo'::'bar'::'baz'::7\n",
+            "foo::bar::baz::7\n",
         );
     }
 
@@ -327,6 +727,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_big_decimal_as_decimal() {
+        writer_test(
+            |w| {
+                w.write_big_decimal(&BigDecimal::from(100))?;
+                w.write_big_decimal(&BigDecimal::from_str("1.50").unwrap())?;
+                w.write_big_decimal(&BigDecimal::new(BigInt::from(1), -2))
+            },
+            "100.\n1.50\n1d2\n",
+        );
+    }
+
+    #[test]
+    fn write_big_int() {
+        let big_int = BigInt::from_str("-123456789012345678901234567890").unwrap();
+        writer_test(
+            |w| {
+                w.write_big_int(&big_int)?;
+                w.write_integer(&Integer::BigInt(big_int.clone()))
+            },
+            "-123456789012345678901234567890\n-123456789012345678901234567890\n",
+        );
+    }
+
+    #[test]
+    fn write_f32() {
+        writer_test(
+            |w| {
+                w.write_f32(0.1f32)?;
+                w.write_f32(-0.0f32)?;
+                w.write_f32(f32::INFINITY)
+            },
+            "1.0000000149011612e-1\n-0e0\n+inf\n",
+        );
+    }
+
+    #[test]
+    fn numbers_round_trip() -> IonResult<()> {
+        let floats = [
+            0.1f64,
+            1.0 / 3.0,
+            -2.5e-8,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -0.0,
+        ];
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        for value in &floats {
+            writer.write_f64(*value)?;
+        }
+        writer.write_f32(0.1f32)?;
+        writer.write_big_decimal(&BigDecimal::from(100))?;
+        drop(writer);
+
+        let mut cursor = TextIonCursor::new(io::Cursor::new(output));
+        for value in &floats {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+            assert_eq!(cursor.read_f64()?.map(f64::to_bits), Some(value.to_bits()));
+        }
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        let value = cursor.read_f64()?.unwrap();
+        assert_eq!(value.to_bits(), f64::from(0.1f32).to_bits());
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert_eq!(cursor.read_decimal()?, Some(Decimal::new(100, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn write_decimal_preserves_sign_and_exponent() {
+        writer_test(
+            |w| {
+                w.write_decimal(&Decimal::negative_zero())?;
+                w.write_decimal(&Decimal::new(0, -2))?;
+                w.write_decimal(&Decimal::new(150, -2))
+            },
+            "-0.\n0.00\n1.50\n",
+        );
+    }
+
     #[test]
     fn write_datetime_epoch() {
         let naive_datetime = NaiveDate::from_ymd(2000 as i32, 1 as u32, 1 as u32)
@@ -339,6 +820,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_timestamps() -> IonResult<()> {
+        let year = Timestamp::with_year(2020)?;
+        let minute = Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?.with_offset(0)?;
+        let fractional = Timestamp::with_ymd_hms(2020, 1, 1, 12, 30, 15)?
+            .with_fractional_seconds(BigDecimal::from_str("0.0100").unwrap())?;
+        writer_test(
+            |w| {
+                w.write_timestamp(&year)?;
+                w.write_timestamp(&minute)?;
+                w.write_timestamp(&fractional)
+            },
+            "2020T\n2020-01-01T00:00Z\n2020-01-01T12:30:15.0100-00:00\n",
+        );
+        Ok(())
+    }
+
     #[test]
     fn write_stream() {
         writer_test(
@@ -347,7 +845,7 @@ mod tests {
                 w.write_i64(21)?;
                 w.write_symbol("bar")
             },
-            "\"foo\"\n21\n'bar'\n",
+            "\"foo\"\n21\nbar\n",
         );
     }
 
@@ -357,9 +855,101 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn write_clob() {
-        todo!();
+        writer_test(
+            |w| w.write_clob(b"a\"b\\c\x00\n\xFF"),
+            "{{\"a\\\"b\\\\c\\x00\\x0a\\xff\"}}\n",
+        );
+    }
+
+    #[test]
+    fn write_escaped_string() {
+        writer_test(
+            |w| w.write_string("a\"b\\c'd\ne\tf\x07 é"),
+            "\"a\\\"b\\\\c'd\\ne\\tf\\x07 é\"\n",
+        );
+    }
+
+    #[test]
+    fn write_symbols() {
+        writer_test(
+            |w| {
+                w.write_symbol("foo_$1")?;
+                w.write_symbol("$")?;
+                w.write_symbol("true")?;
+                w.write_symbol("nan")?;
+                w.write_symbol("$10")?;
+                w.write_symbol("1a")?;
+                w.write_symbol("")?;
+                w.write_symbol("it's")?;
+                w.write_symbol("$ion_1_0")?;
+                w.write_symbol("$ion_12_345")?;
+                w.write_symbol("$ion_1")?;
+                w.write_symbol("$ion_1_0_0")?;
+                w.write_symbol("$ion_symbol_table")
+            },
+            "foo_$1\n$\n'true'\n'nan'\n'$10'\n'1a'\n''\n'it\\'s'\n'$ion_1_0'\n'$ion_12_345'\n\
+             $ion_1\n$ion_1_0_0\n$ion_symbol_table\n",
+        );
+    }
+
+    #[test]
+    fn version_marker_symbols_round_trip() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        writer.write_symbol("$ion_1_0")?;
+        writer.write_i64(5)?;
+        drop(writer);
+
+        let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(output)));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(
+            reader
+                .read_symbol()?
+                .and_then(|token| token.text().map(String::from)),
+            Some("$ion_1_0".to_string())
+        );
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn write_quoted_field_names_and_annotations() {
+        writer_test(
+            |w| {
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("a b");
+                w.set_annotations(&["null", "c"]);
+                w.write_i64(1)?;
+                w.step_out()
+            },
+            "{'a b':'null'::c::1,}\n",
+        );
+    }
+
+    #[test]
+    fn escaped_text_round_trips() -> IonResult<()> {
+        let text = "quote\" apostrophe' backslash\\ newline\n bell\x07 del\x7F é";
+        let clob = [b'"', b'\\', 0x00, 0x7F, 0xFF, b'a'];
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        writer.write_string(text)?;
+        writer.write_symbol(text)?;
+        writer.write_clob(&clob)?;
+        drop(writer);
+
+        let mut cursor = TextIonCursor::new(io::Cursor::new(output));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some(text.to_string()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Symbol, false)));
+        assert_eq!(
+            cursor.read_raw_symbol()?,
+            Some(RawSymbolTokenRef::Text(text))
+        );
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_clob_bytes()?, Some(clob.to_vec()));
+        Ok(())
     }
 
     #[test]
@@ -372,7 +962,7 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "[\"foo\",21,'bar',]\n",
+            "[\"foo\",21,bar,]\n",
         );
     }
 
@@ -388,7 +978,7 @@ mod tests {
                 w.step_out()?;
                 w.step_out()
             },
-            "[\"foo\",21,['bar',],]\n",
+            "[\"foo\",21,[bar,],]\n",
         );
     }
 
@@ -402,7 +992,7 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "(\"foo\" 21 'bar' )\n",
+            "(\"foo\" 21 bar )\n",
         );
     }
 
@@ -420,7 +1010,137 @@ mod tests {
                 w.write_symbol("bar")?;
                 w.step_out()
             },
-            "{a:\"foo\",b:21,c:'qux'::'bar',}\n",
+            "{a:\"foo\",b:21,c:qux::bar,}\n",
+        );
+    }
+
+    fn layout_test<F>(builder: TextWriterBuilder, mut commands: F, expected: &str)
+    where
+        F: FnMut(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = builder.build(&mut output);
+        commands(&mut writer).expect("Invalid TextWriter test commands.");
+        drop(writer);
+        assert_eq!(str::from_utf8(&output).unwrap(), expected);
+    }
+
+    // {a: [1, (foo 2)], b: {}} 3
+    fn write_nested_values(w: &mut TextWriter<&mut Vec<u8>>) -> IonResult<()> {
+        w.step_in(IonType::Struct)?;
+        w.set_field_name("a");
+        w.step_in(IonType::List)?;
+        w.write_i64(1)?;
+        w.set_annotations(&["x"]);
+        w.step_in(IonType::SExpression)?;
+        w.write_symbol("foo")?;
+        w.write_i64(2)?;
+        w.step_out()?;
+        w.step_out()?;
+        w.set_field_name("b");
+        w.step_in(IonType::Struct)?;
+        w.step_out()?;
+        w.step_out()?;
+        w.write_i64(3)
+    }
+
+    #[test]
+    fn write_compact() {
+        layout_test(
+            TextWriterBuilder::new().layout(TextLayout::Compact),
+            write_nested_values,
+            "{a:[1,x::(foo 2 ),],b:{},} 3 ",
+        );
+    }
+
+    #[test]
+    fn write_lines() {
+        layout_test(
+            TextWriterBuilder::new().layout(TextLayout::Lines),
+            write_nested_values,
+            "{a:[1,x::(foo 2 ),],b:{},}\n3\n",
+        );
+    }
+
+    #[test]
+    fn write_pretty() {
+        layout_test(
+            TextWriterBuilder::new().layout(TextLayout::Pretty),
+            write_nested_values,
+            "{\n  a: [\n    1,\n    x::(\n      foo\n      2\n    ),\n  ],\n  b: {},\n}\n3\n",
+        );
+    }
+
+    #[test]
+    fn write_pretty_with_custom_indentation() {
+        layout_test(
+            TextWriterBuilder::new()
+                .layout(TextLayout::Pretty)
+                .indentation("\t"),
+            |w| {
+                w.step_in(IonType::List)?;
+                w.step_in(IonType::List)?;
+                w.write_i64(1)?;
+                w.step_out()?;
+                w.step_out()
+            },
+            "[\n\t[\n\t\t1,\n\t],\n]\n",
+        );
+    }
+
+    #[test]
+    fn write_json() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2020, 6, 15)?;
+        layout_test(
+            TextWriterBuilder::new().json(true),
+            |w| {
+                w.set_annotations(&["dropped"]);
+                w.step_in(IonType::Struct)?;
+                w.set_field_name("symbol");
+                w.write_symbol("foo")?;
+                w.set_field_name("sexp");
+                w.step_in(IonType::SExpression)?;
+                w.write_i64(1)?;
+                w.write_f64(f64::NAN)?;
+                w.write_f64(f64::NEG_INFINITY)?;
+                w.write_f64(1.5)?;
+                w.step_out()?;
+                w.set_field_name("decimals");
+                w.step_in(IonType::List)?;
+                w.write_decimal(&Decimal::new(150, -2))?;
+                w.write_decimal(&Decimal::new(5, 0))?;
+                w.write_decimal(&Decimal::new(5, 2))?;
+                w.write_decimal(&Decimal::negative_zero())?;
+                w.step_out()?;
+                w.set_field_name("timestamp");
+                w.write_timestamp(&timestamp)?;
+                w.set_field_name("null int");
+                w.write_null(IonType::Integer)?;
+                w.set_field_name("empty");
+                w.step_in(IonType::List)?;
+                w.step_out()?;
+                w.step_out()?;
+                w.write_blob(b"hello")?;
+                w.write_clob(b"a\"\x00\xFF")?;
+                w.write_string("tab\there\x07")
+            },
+            "{\"symbol\":\"foo\",\"sexp\":[1,null,null,1.5e0],\
+             \"decimals\":[1.50,5,5e2,-0],\"timestamp\":\"2020-06-15\",\
+             \"null int\":null,\"empty\":[]}\n\
+             \"aGVsbG8=\"\n\"a\\\"\\u0000\u{FF}\"\n\"tab\\there\\u0007\"\n",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_pretty_json() {
+        layout_test(
+            TextWriterBuilder::new()
+                .layout(TextLayout::Pretty)
+                .json(true),
+            write_nested_values,
+            "{\n  \"a\": [\n    1,\n    [\n      \"foo\",\n      2\n    ]\n  ],\n  \"b\": {}\n}\n\
+             3\n",
         );
     }
 }
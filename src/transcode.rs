@@ -0,0 +1,275 @@
+use crate::cursor::Cursor;
+use crate::element::{non_null, Element};
+use crate::result::IonResult;
+use crate::types::IonType;
+use crate::writer::Writer;
+use crate::Reader;
+
+/// Describes what [transcode_with] should do with the value over which the Reader is currently
+/// positioned.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TranscodeAction {
+    /// Write the value to the output. If the value is a container, each of its child values
+    /// will be passed to the hook in turn.
+    Write,
+    /// Leave the value (including any child values) out of the output.
+    Skip,
+    /// Write the provided Element in place of the value. If the value is a struct field, the
+    /// Element will be written using the same field name.
+    Replace(Element),
+}
+
+/// Reads every remaining value at the Reader's current depth and writes it to the provided
+/// Writer, then flushes the Writer. Field names, annotations, typed nulls, and the precision of
+/// each scalar are preserved. Values are streamed from the Reader to the Writer one at a time
+/// rather than being loaded into memory first.
+///
+/// Symbols, field names and annotations whose text is unknown are written using
+/// [Writer::write_symbol_token] and its counterparts, so they are read back with unknown text.
+/// Floats are written using [Writer::write_f64]: Ion floats are 64-bit values, so a 4-byte
+/// binary float is written with the same value but may be encoded with 8 bytes.
+/// ```
+/// use ion_rs::result::IonResult;
+/// use ion_rs::text::writer::TextWriter;
+/// use ion_rs::{transcode, Reader, TextIonCursor};
+///
+/// # fn main() -> IonResult<()> {
+/// let input = "foo::{bar: null.int, baz: [1.50, 2e0]}";
+/// let mut reader = Reader::new(TextIonCursor::new(std::io::Cursor::new(input.as_bytes())));
+/// let mut output = Vec::new();
+/// transcode(&mut reader, &mut TextWriter::new(&mut output))?;
+/// assert_eq!(output, b"foo::{bar:null.int,baz:[1.50,2e0,],}\n");
+/// # Ok(())
+/// # }
+/// ```
+pub fn transcode<C, W>(reader: &mut Reader<C>, writer: &mut W) -> IonResult<()>
+where
+    C: Cursor,
+    W: Writer,
+{
+    transcode_with(reader, writer, |_reader| Ok(TranscodeAction::Write))
+}
+
+/// Like [transcode], but passes the Reader to `hook` each time it is positioned over a value,
+/// including values nested inside of containers. The [TranscodeAction] returned by `hook`
+/// determines whether the value is written, skipped, or replaced.
+///
+/// The hook may inspect the current value's type, field name, annotations, and depth, and may
+/// read the value itself. It must not advance the Reader or step into or out of containers.
+pub fn transcode_with<C, W, F>(reader: &mut Reader<C>, writer: &mut W, mut hook: F) -> IonResult<()>
+where
+    C: Cursor,
+    W: Writer,
+    F: FnMut(&mut Reader<C>) -> IonResult<TranscodeAction>,
+{
+    transcode_all(reader, writer, &mut hook)?;
+    writer.flush()
+}
+
+// Transcodes every remaining value at the Reader's current depth.
+fn transcode_all<C, W, F>(reader: &mut Reader<C>, writer: &mut W, hook: &mut F) -> IonResult<()>
+where
+    C: Cursor,
+    W: Writer,
+    F: FnMut(&mut Reader<C>) -> IonResult<TranscodeAction>,
+{
+    while let Some((ion_type, is_null)) = reader.next()? {
+        match hook(reader)? {
+            TranscodeAction::Write => transcode_current(reader, writer, hook, ion_type, is_null)?,
+            TranscodeAction::Skip => {}
+            TranscodeAction::Replace(element) => {
+                write_field_name(reader, writer)?;
+                element.write_to(writer)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Writes the value over which the Reader is currently positioned, including any child values.
+fn transcode_current<C, W, F>(
+    reader: &mut Reader<C>,
+    writer: &mut W,
+    hook: &mut F,
+    ion_type: IonType,
+    is_null: bool,
+) -> IonResult<()>
+where
+    C: Cursor,
+    W: Writer,
+    F: FnMut(&mut Reader<C>) -> IonResult<TranscodeAction>,
+{
+    write_field_name(reader, writer)?;
+    let annotations = reader.annotation_tokens()?;
+    if !annotations.is_empty() {
+        writer.set_annotation_tokens(&annotations);
+    }
+    if is_null {
+        return writer.write_null(ion_type);
+    }
+    use IonType::*;
+    match ion_type {
+        Null => unreachable!("Value with IonType::Null returned is_null=false."),
+        Boolean => writer.write_bool(non_null(reader.read_bool()?)?),
+        Integer => writer.write_integer(&non_null(reader.read_integer()?)?),
+        Float => writer.write_f64(non_null(reader.read_f64()?)?),
+        Decimal => writer.write_decimal(&non_null(reader.read_decimal()?)?),
+        Timestamp => writer.write_timestamp(&non_null(reader.read_timestamp()?)?),
+        Symbol => writer.write_symbol_token(&non_null(reader.read_symbol()?)?),
+        String => non_null(reader.string_ref_map(|text| writer.write_string(text))?)?,
+        Clob => writer.write_clob(&non_null(reader.read_clob_bytes()?)?),
+        Blob => writer.write_blob(&non_null(reader.read_blob_bytes()?)?),
+        List | SExpression | Struct => {
+            reader.step_in()?;
+            writer.step_in(ion_type)?;
+            transcode_all(reader, writer, hook)?;
+            writer.step_out()?;
+            reader.step_out()
+        }
+    }
+}
+
+// If the Reader is positioned over a struct field, sets the Writer's field name to match.
+fn write_field_name<C: Cursor, W: Writer>(reader: &Reader<C>, writer: &mut W) -> IonResult<()> {
+    if let Some(name) = reader.field_name_token()? {
+        writer.set_field_name_token(&name);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::binary::writer::BinaryWriter;
+    use crate::element::{load_all, Element, Value};
+    use crate::result::IonResult;
+    use crate::text::writer::TextWriter;
+    use crate::types::IonType;
+    use crate::{transcode, transcode_with, BinaryIonCursor, Reader, TextIonCursor};
+
+    use super::TranscodeAction;
+
+    const TEXT: &str = r#"
+        null null.int null.struct true -0 12345678901234567890123 -0e0 nan 1.50 -0d3
+        2020-06-15T10:30:00.250-00:00 2020T foo::'bar baz'::"qux" {{"clob"}} {{aGVsbG8=}}
+        annotated::[1, (a b c), annotated::{x: 1, y: [], x: null.symbol}]
+    "#;
+
+    fn text_reader(text: &[u8]) -> Reader<TextIonCursor<io::Cursor<Vec<u8>>>> {
+        Reader::new(TextIonCursor::new(io::Cursor::new(text.to_vec())))
+    }
+
+    fn load_text(text: &[u8]) -> IonResult<Vec<Element>> {
+        load_all(&mut text_reader(text))
+    }
+
+    fn load_binary(bytes: Vec<u8>) -> IonResult<Vec<Element>> {
+        load_all(&mut Reader::new(BinaryIonCursor::new(io::Cursor::new(
+            bytes,
+        ))))
+    }
+
+    #[test]
+    fn text_to_binary_to_text() -> IonResult<()> {
+        let expected = load_text(TEXT.as_bytes())?;
+        assert_eq!(expected.len(), 16);
+
+        let mut binary = Vec::new();
+        transcode(
+            &mut text_reader(TEXT.as_bytes()),
+            &mut BinaryWriter::new(&mut binary),
+        )?;
+        assert_eq!(load_binary(binary.clone())?, expected);
+
+        let mut text = Vec::new();
+        let mut reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(binary)));
+        transcode(&mut reader, &mut TextWriter::new(&mut text))?;
+        assert_eq!(load_text(&text)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn hook_can_skip_and_replace_values() -> IonResult<()> {
+        let text = r#"{name: "widget", secret: "hunter2", sizes: [1, 2, 3]} 4"#;
+        let mut output = Vec::new();
+        transcode_with(
+            &mut text_reader(text.as_bytes()),
+            &mut TextWriter::new(&mut output),
+            |reader| {
+                if reader.field_name() == Some("secret") {
+                    return Ok(TranscodeAction::Skip);
+                }
+                if reader.ion_type() == Some(IonType::Integer) {
+                    // The hook can read the value before deciding what to do with it.
+                    let doubled = reader.read_i64()?.unwrap() * 2;
+                    return Ok(TranscodeAction::Replace(Value::from(doubled).into()));
+                }
+                Ok(TranscodeAction::Write)
+            },
+        )?;
+        assert_eq!(
+            load_text(&output)?,
+            load_text(br#"{name: "widget", sizes: [2, 4, 6]} 8"#)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn symbols_with_unknown_text() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{imports: [{name: "missing", version: 1, max_id: 2}]}
+            $0 $11::$10 {$10: $0, $11: $11}
+        "#;
+        let mut output = Vec::new();
+        transcode(
+            &mut text_reader(text.as_bytes()),
+            &mut TextWriter::new(&mut output),
+        )?;
+        assert_eq!(output, b"$0\n$11::$10\n{$10:$0,$11:$11,}\n");
+
+        let mut binary = Vec::new();
+        transcode(
+            &mut text_reader(text.as_bytes()),
+            &mut BinaryWriter::new(&mut binary),
+        )?;
+        let mut reader = Reader::new(BinaryIonCursor::new(io::Cursor::new(binary)));
+        let read_unknown_symbol = |reader: &mut Reader<_>| -> IonResult<_> {
+            assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+            let token = reader.read_symbol()?.unwrap();
+            assert_eq!(token.text(), None);
+            Ok(token.local_sid())
+        };
+        assert_eq!(read_unknown_symbol(&mut reader)?, Some(0));
+        let ten = read_unknown_symbol(&mut reader)?;
+        let annotations = reader.annotation_tokens()?;
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].text(), None);
+        let eleven = annotations[0].local_sid();
+        assert_ne!(ten, eleven);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(read_unknown_symbol(&mut reader)?, Some(0));
+        assert_eq!(reader.field_name_token()?.unwrap().local_sid(), ten);
+        assert_eq!(read_unknown_symbol(&mut reader)?, eleven);
+        assert_eq!(reader.field_name_token()?.unwrap().local_sid(), eleven);
+        Ok(())
+    }
+
+    #[test]
+    fn hook_can_write_values_it_has_read() -> IonResult<()> {
+        let mut output = Vec::new();
+        transcode_with(
+            &mut text_reader(TEXT.as_bytes()),
+            &mut TextWriter::new(&mut output),
+            |reader| {
+                if reader.ion_type() == Some(IonType::String) {
+                    assert!(reader.read_string()?.is_some());
+                }
+                Ok(TranscodeAction::Write)
+            },
+        )?;
+        assert_eq!(load_text(&output)?, load_text(TEXT.as_bytes())?);
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use bigdecimal::{BigDecimal, Zero};
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// An Ion decimal. Unlike [BigDecimal], a `Decimal` preserves everything that the Ion data model
+/// considers significant: the sign of the coefficient (including negative zero) and the exact
+/// exponent (so `0.00` and `0` are distinct values).
+///
+/// Two `Decimal`s are equal (`==`) if they are equivalent in the Ion data model, which requires
+/// their signs, coefficients and exponents to match. Use [Decimal::numeric_eq] to test whether
+/// two decimals represent the same number.
+///
+/// The exponent is stored as an i64. Ion places no limit on a decimal's exponent, but readers
+/// report a decoding error for any exponent that does not fit rather than misreading it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    // BigInt cannot represent negative zero, so the sign is stored separately from the magnitude.
+    is_negative: bool,
+    magnitude: BigUint,
+    exponent: i64,
+}
+
+impl Decimal {
+    /// Constructs a Decimal with the value `coefficient * 10^exponent`.
+    pub fn new<I: Into<BigInt>>(coefficient: I, exponent: i64) -> Decimal {
+        let coefficient = coefficient.into();
+        Decimal {
+            is_negative: coefficient.sign() == Sign::Minus,
+            magnitude: coefficient.magnitude().clone(),
+            exponent,
+        }
+    }
+
+    /// Constructs a Decimal with a coefficient of negative zero and the provided exponent.
+    pub fn negative_zero_with_exponent(exponent: i64) -> Decimal {
+        Decimal {
+            is_negative: true,
+            magnitude: BigUint::zero(),
+            exponent,
+        }
+    }
+
+    /// Constructs a Decimal with a coefficient of negative zero and an exponent of zero (`-0d0`).
+    pub fn negative_zero() -> Decimal {
+        Decimal::negative_zero_with_exponent(0)
+    }
+
+    /// Returns the decimal's coefficient. If the coefficient is negative zero, the returned
+    /// BigInt will be zero; use [Decimal::is_negative] to detect this case.
+    pub fn coefficient(&self) -> BigInt {
+        let sign = if self.is_negative {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        BigInt::from_biguint(sign, self.magnitude.clone())
+    }
+
+    /// Returns the magnitude of the decimal's coefficient.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns true if the coefficient's sign is negative. This is the case for negative zero.
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Returns true if the coefficient is zero or negative zero.
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns true if `self` and `other` represent the same number, regardless of their
+    /// precision. Positive and negative zero are numerically equal.
+    pub fn numeric_eq(&self, other: &Decimal) -> bool {
+        if self.is_zero() || other.is_zero() {
+            return self.is_zero() && other.is_zero();
+        }
+        BigDecimal::from(self.clone()) == BigDecimal::from(other.clone())
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal::new(value, 0)
+    }
+}
+
+impl From<BigDecimal> for Decimal {
+    fn from(value: BigDecimal) -> Self {
+        // BigDecimal uses 'scale' rather than 'exponent' in its API, which is a count of the
+        // number of decimal places. It's effectively `exponent * -1`.
+        let (coefficient, scale) = value.into_bigint_and_exponent();
+        Decimal::new(coefficient, -scale)
+    }
+}
+
+/// BigDecimal cannot represent negative zero; it will be converted to zero.
+impl From<Decimal> for BigDecimal {
+    fn from(value: Decimal) -> Self {
+        BigDecimal::new(value.coefficient(), -value.exponent)
+    }
+}
+
+// The largest number of zeros that Display will write between the decimal point and the
+// coefficient's digits. Decimals that would need more are written with an exponent instead, so
+// that the length of the text is proportional to the coefficient rather than the exponent.
+const MAX_LEADING_ZEROS: usize = 6;
+
+/// Writes the decimal using Ion's text syntax, which preserves its sign, coefficient and exponent.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative {
+            write!(f, "-")?;
+        }
+        let digits = self.magnitude.to_string();
+        if self.exponent > 0 {
+            return write!(f, "{}d{}", digits, self.exponent);
+        }
+        // Each digit after the decimal point reduces the exponent by one.
+        let fraction_length = self.exponent.unsigned_abs() as usize;
+        if fraction_length < digits.len() {
+            let (integer_digits, fraction_digits) = digits.split_at(digits.len() - fraction_length);
+            write!(f, "{}.{}", integer_digits, fraction_digits)
+        } else if fraction_length - digits.len() <= MAX_LEADING_ZEROS {
+            let leading_zeros = fraction_length - digits.len();
+            write!(f, "0.{}{}", "0".repeat(leading_zeros), digits)
+        } else {
+            write!(f, "{}d{}", digits, self.exponent)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use num_bigint::BigInt;
+
+    use crate::types::Decimal;
+
+    #[test]
+    fn negative_zero_is_preserved() {
+        let negative_zero = Decimal::negative_zero();
+        assert!(negative_zero.is_negative());
+        assert!(negative_zero.is_zero());
+        assert_ne!(negative_zero, Decimal::new(0, 0));
+        assert!(negative_zero.numeric_eq(&Decimal::new(0, 0)));
+        assert_eq!(BigDecimal::from(negative_zero), BigDecimal::from(0));
+    }
+
+    #[test]
+    fn precision_is_significant() {
+        let one = Decimal::new(1, 0);
+        let one_point_zero = Decimal::new(10, -1);
+        assert_ne!(one, one_point_zero);
+        assert!(one.numeric_eq(&one_point_zero));
+        assert_ne!(Decimal::new(0, -2), Decimal::new(0, 0));
+        assert!(Decimal::new(0, -2).numeric_eq(&Decimal::new(0, 0)));
+        assert!(!Decimal::new(-1, 0).numeric_eq(&Decimal::new(1, 0)));
+    }
+
+    #[test]
+    fn big_decimal_round_trip() {
+        let big_decimal = BigDecimal::from_str("-731221.9948").unwrap();
+        let decimal = Decimal::from(big_decimal.clone());
+        assert_eq!(decimal.coefficient(), BigInt::from(-7312219948i64));
+        assert_eq!(decimal.exponent(), -4);
+        assert_eq!(BigDecimal::from(decimal), big_decimal);
+
+        let with_trailing_zeros = Decimal::from(BigDecimal::from_str("1.500").unwrap());
+        assert_eq!(with_trailing_zeros, Decimal::new(1500, -3));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Decimal::new(15, -1).to_string(), "1.5");
+        assert_eq!(Decimal::new(-1500, -3).to_string(), "-1.500");
+        assert_eq!(Decimal::new(5, -3).to_string(), "0.005");
+        assert_eq!(Decimal::new(0, -2).to_string(), "0.00");
+        assert_eq!(Decimal::new(5, 0).to_string(), "5.");
+        assert_eq!(Decimal::new(5, 2).to_string(), "5d2");
+        assert_eq!(Decimal::negative_zero().to_string(), "-0.");
+        assert_eq!(Decimal::negative_zero_with_exponent(3).to_string(), "-0d3");
+        assert_eq!(Decimal::new(5, -7).to_string(), "0.0000005");
+        assert_eq!(Decimal::new(5, -8).to_string(), "5d-8");
+        assert_eq!(Decimal::new(-12, -750_000).to_string(), "-12d-750000");
+        assert_eq!(
+            Decimal::negative_zero_with_exponent(i64::MIN).to_string(),
+            "-0d-9223372036854775808"
+        );
+    }
+}
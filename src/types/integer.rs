@@ -0,0 +1,129 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use num_bigint::BigInt;
+
+/// An Ion integer. Ion integers have arbitrary precision; values that fit in an i64 are stored
+/// as one, while larger values are stored as a BigInt.
+///
+/// Two `Integer`s are equal if they represent the same numeric value, regardless of which
+/// variant is being used to store them.
+#[derive(Debug, Clone)]
+pub enum Integer {
+    I64(i64),
+    BigInt(BigInt),
+}
+
+impl Integer {
+    /// Returns the value as an i64 if it is in the range `i64::MIN..=i64::MAX`; otherwise,
+    /// returns None.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Integer::I64(value) => Some(*value),
+            Integer::BigInt(value) => i64::try_from(value).ok(),
+        }
+    }
+
+    /// Returns the value as a BigInt.
+    pub fn to_big_int(&self) -> BigInt {
+        match self {
+            Integer::I64(value) => BigInt::from(*value),
+            Integer::BigInt(value) => value.clone(),
+        }
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Integer::I64(value)
+    }
+}
+
+/// Stores the provided BigInt as an i64 if it is small enough to fit in one.
+impl From<BigInt> for Integer {
+    fn from(value: BigInt) -> Self {
+        match i64::try_from(&value) {
+            Ok(small_value) => Integer::I64(small_value),
+            Err(_) => Integer::BigInt(value),
+        }
+    }
+}
+
+impl From<Integer> for BigInt {
+    fn from(value: Integer) -> Self {
+        match value {
+            Integer::I64(value) => BigInt::from(value),
+            Integer::BigInt(value) => value,
+        }
+    }
+}
+
+impl PartialEq for Integer {
+    fn eq(&self, other: &Self) -> bool {
+        use Integer::*;
+        match (self, other) {
+            (I64(a), I64(b)) => a == b,
+            (BigInt(a), BigInt(b)) => a == b,
+            // An i64 can only equal a BigInt if the BigInt's value fits in an i64.
+            (I64(_), BigInt(_)) | (BigInt(_), I64(_)) => self.as_i64() == other.as_i64(),
+        }
+    }
+}
+
+impl Eq for Integer {}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Integer::I64(value) => write!(f, "{}", value),
+            Integer::BigInt(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use num_bigint::BigInt;
+
+    use crate::types::Integer;
+
+    #[test]
+    fn big_int_that_fits_is_stored_as_i64() {
+        assert_eq!(Integer::from(BigInt::from(-42)), Integer::I64(-42));
+        assert!(matches!(
+            Integer::from(BigInt::from(-42)),
+            Integer::I64(-42)
+        ));
+    }
+
+    #[test]
+    fn big_int_that_does_not_fit_is_stored_as_big_int() {
+        let big = BigInt::from_str("9223372036854775808").unwrap();
+        let integer = Integer::from(big.clone());
+        assert!(matches!(integer, Integer::BigInt(_)));
+        assert_eq!(integer.as_i64(), None);
+        assert_eq!(integer.to_big_int(), big);
+    }
+
+    #[test]
+    fn equality_ignores_representation() {
+        assert_eq!(Integer::BigInt(BigInt::from(7)), Integer::I64(7));
+        assert_eq!(Integer::I64(7), Integer::BigInt(BigInt::from(7)));
+        assert_ne!(Integer::I64(7), Integer::BigInt(BigInt::from(8)));
+        let big = BigInt::from_str("-9223372036854775809").unwrap();
+        assert_ne!(Integer::I64(i64::MIN), Integer::BigInt(big.clone()));
+        assert_eq!(Integer::BigInt(big.clone()), Integer::BigInt(big));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Integer::I64(-5).to_string(), "-5");
+        let big = BigInt::from_str("123456789012345678901234567890").unwrap();
+        assert_eq!(
+            Integer::BigInt(big).to_string(),
+            "123456789012345678901234567890"
+        );
+    }
+}
@@ -4,6 +4,14 @@
 
 pub type SymbolId = usize;
 
+mod decimal;
+mod integer;
+mod symbol_token;
+mod timestamp;
 mod r#type;
 
+pub use decimal::Decimal;
+pub use integer::Integer;
 pub use r#type::IonType;
+pub use symbol_token::SymbolToken;
+pub use timestamp::{Precision, Timestamp};
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::types::SymbolId;
+
+/// A symbol that has been resolved using the active symbol table.
+///
+/// A token's text may be unknown, either because it refers to symbol zero (`$0`) or because its
+/// symbol ID was imported from a shared symbol table that could not be found. Symbols that were
+/// encoded as inline text (as is typical in text Ion) do not have a local symbol ID.
+///
+/// Following the Ion data model, two tokens with known text are equal if their text is equal,
+/// regardless of their symbol IDs. Two tokens with unknown text are equal if their symbol IDs are.
+#[derive(Debug, Clone)]
+pub struct SymbolToken {
+    text: Option<String>,
+    local_sid: Option<SymbolId>,
+}
+
+impl SymbolToken {
+    pub fn new(text: Option<String>, local_sid: Option<SymbolId>) -> SymbolToken {
+        SymbolToken { text, local_sid }
+    }
+
+    /// Constructs a token with the provided text and no local symbol ID.
+    pub fn with_text<T: Into<String>>(text: T) -> SymbolToken {
+        SymbolToken::new(Some(text.into()), None)
+    }
+
+    /// Returns the token's text, if it is known.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Returns the symbol ID that was used to encode this token, if any.
+    pub fn local_sid(&self) -> Option<SymbolId> {
+        self.local_sid
+    }
+}
+
+impl From<&str> for SymbolToken {
+    fn from(text: &str) -> Self {
+        SymbolToken::with_text(text)
+    }
+}
+
+impl From<String> for SymbolToken {
+    fn from(text: String) -> Self {
+        SymbolToken::with_text(text)
+    }
+}
+
+impl PartialEq for SymbolToken {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.text(), other.text()) {
+            (Some(text), Some(other_text)) => text == other_text,
+            (None, None) => self.local_sid == other.local_sid,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SymbolToken {}
+
+// Consistent with PartialEq: the symbol ID only contributes to the hash if the text is unknown.
+impl Hash for SymbolToken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.text() {
+            Some(text) => text.hash(state),
+            None => self.local_sid.hash(state),
+        }
+    }
+}
+
+/// Writes the token's text if it is known; otherwise, writes its symbol ID using the `$ID` syntax.
+impl fmt::Display for SymbolToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.text(), self.local_sid) {
+            (Some(text), _) => write!(f, "{}", text),
+            (None, Some(sid)) => write!(f, "${}", sid),
+            (None, None) => write!(f, "$0"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::SymbolToken;
+
+    #[test]
+    fn display() {
+        assert_eq!(SymbolToken::with_text("foo").to_string(), "foo");
+        assert_eq!(
+            SymbolToken::new(Some("foo".into()), Some(10)).to_string(),
+            "foo"
+        );
+        assert_eq!(SymbolToken::new(None, Some(10)).to_string(), "$10");
+        assert_eq!(SymbolToken::new(None, Some(0)).to_string(), "$0");
+    }
+
+    #[test]
+    fn equality() {
+        let foo = SymbolToken::with_text("foo");
+        assert_eq!(foo, SymbolToken::new(Some("foo".into()), Some(10)));
+        assert_ne!(foo, SymbolToken::with_text("bar"));
+        assert_ne!(foo, SymbolToken::new(None, Some(10)));
+        assert_eq!(
+            SymbolToken::new(None, Some(0)),
+            SymbolToken::new(None, Some(0))
+        );
+        assert_ne!(
+            SymbolToken::new(None, Some(0)),
+            SymbolToken::new(None, Some(10))
+        );
+    }
+}
@@ -0,0 +1,483 @@
+use std::fmt;
+
+use bigdecimal::{BigDecimal, Signed, ToPrimitive, Zero};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+};
+
+use crate::result::{illegal_operation, IonResult};
+
+const MINUTES_PER_DAY: i32 = 24 * 60;
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Indicates the most granular field that was specified when a [Timestamp] was created.
+/// Any fields that are more granular than the timestamp's precision are considered unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+    Year,
+    Month,
+    Day,
+    HourAndMinute,
+    /// Second precision. The timestamp may also have fractional seconds; see
+    /// [Timestamp::fractional_seconds].
+    Second,
+}
+
+/// An Ion timestamp. Unlike a chrono `DateTime`, a Timestamp remembers its precision (`2020T` is
+/// not the same as `2020-01-01T00:00Z`), stores its fractional seconds with arbitrary precision,
+/// and distinguishes between a known UTC offset (`+00:00`) and an unknown offset (`-00:00`).
+///
+/// Timestamps with `Year`, `Month`, or `Day` precision always have an unknown offset.
+///
+/// Two Timestamps are equal if they have the same precision, offset, and fields, including the
+/// number of digits in their fractional seconds. This matches the equivalence rules in the Ion
+/// data model. To compare the instants that two Timestamps represent, use
+/// [Timestamp::to_datetime].
+#[derive(Debug, Clone)]
+pub struct Timestamp {
+    // The timestamp's fields in its local offset. Fields that are more granular than
+    // `precision` are set to their lowest legal value.
+    date_time: NaiveDateTime,
+    // The offset from UTC in minutes, or None if the offset is unknown.
+    offset_minutes: Option<i32>,
+    precision: Precision,
+    // The fractional seconds exactly as they were specified. This is always a value in the range
+    // [0, 1) with at least one digit after the decimal point.
+    fractional_seconds: Option<BigDecimal>,
+}
+
+impl Timestamp {
+    fn new(date_time: NaiveDateTime, precision: Precision) -> Timestamp {
+        Timestamp {
+            date_time,
+            offset_minutes: None,
+            precision,
+            fractional_seconds: None,
+        }
+    }
+
+    fn date(year: u32, month: u32, day: u32) -> IonResult<NaiveDate> {
+        if !(1..=9999).contains(&year) {
+            return illegal_operation(format!(
+                "Timestamp years must be in the range 1-9999; found {}.",
+                year
+            ));
+        }
+        match NaiveDate::from_ymd_opt(year as i32, month, day) {
+            Some(date) => Ok(date),
+            None => illegal_operation(format!(
+                "Invalid timestamp date: {:04}-{:02}-{:02}",
+                year, month, day
+            )),
+        }
+    }
+
+    fn date_and_time(
+        date: NaiveDate,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> IonResult<NaiveDateTime> {
+        match date.and_hms_opt(hour, minute, second) {
+            Some(date_time) => Ok(date_time),
+            None => illegal_operation(format!(
+                "Invalid timestamp time: {:02}:{:02}:{:02}",
+                hour, minute, second
+            )),
+        }
+    }
+
+    /// Creates a Timestamp with `Year` precision.
+    pub fn with_year(year: u32) -> IonResult<Timestamp> {
+        let date = Timestamp::date(year, 1, 1)?;
+        let date_time = Timestamp::date_and_time(date, 0, 0, 0)?;
+        Ok(Timestamp::new(date_time, Precision::Year))
+    }
+
+    /// Creates a Timestamp with `Month` precision.
+    pub fn with_year_and_month(year: u32, month: u32) -> IonResult<Timestamp> {
+        let date = Timestamp::date(year, month, 1)?;
+        let date_time = Timestamp::date_and_time(date, 0, 0, 0)?;
+        Ok(Timestamp::new(date_time, Precision::Month))
+    }
+
+    /// Creates a Timestamp with `Day` precision.
+    pub fn with_ymd(year: u32, month: u32, day: u32) -> IonResult<Timestamp> {
+        let date = Timestamp::date(year, month, day)?;
+        let date_time = Timestamp::date_and_time(date, 0, 0, 0)?;
+        Ok(Timestamp::new(date_time, Precision::Day))
+    }
+
+    /// Creates a Timestamp with `HourAndMinute` precision and an unknown offset. The offset can
+    /// be set using [Timestamp::with_offset].
+    pub fn with_ymd_hm(
+        year: u32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+    ) -> IonResult<Timestamp> {
+        let date = Timestamp::date(year, month, day)?;
+        let date_time = Timestamp::date_and_time(date, hour, minute, 0)?;
+        Ok(Timestamp::new(date_time, Precision::HourAndMinute))
+    }
+
+    /// Creates a Timestamp with `Second` precision and an unknown offset. The offset and
+    /// fractional seconds can be set using [Timestamp::with_offset] and
+    /// [Timestamp::with_fractional_seconds].
+    pub fn with_ymd_hms(
+        year: u32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> IonResult<Timestamp> {
+        let date = Timestamp::date(year, month, day)?;
+        let date_time = Timestamp::date_and_time(date, hour, minute, second)?;
+        Ok(Timestamp::new(date_time, Precision::Second))
+    }
+
+    /// Sets the timestamp's offset from UTC in minutes. The timestamp's fields are interpreted as
+    /// local time in that offset. Timestamps with `Year`, `Month`, or `Day` precision cannot have
+    /// a known offset.
+    pub fn with_offset(mut self, offset_minutes: i32) -> IonResult<Timestamp> {
+        if self.precision < Precision::HourAndMinute {
+            return illegal_operation(format!(
+                "A timestamp with {:?} precision cannot have a known offset.",
+                self.precision
+            ));
+        }
+        if offset_minutes.abs() >= MINUTES_PER_DAY {
+            return illegal_operation(format!(
+                "Timestamp offsets must be less than 24 hours; found {} minutes.",
+                offset_minutes
+            ));
+        }
+        self.offset_minutes = Some(offset_minutes);
+        Ok(self)
+    }
+
+    /// Sets the timestamp's fractional seconds, which must be in the range [0, 1). The number of
+    /// digits after the decimal point is preserved, so `0.5` and `0.500` produce different
+    /// Timestamps. A zero with no digits after the decimal point (e.g. `0d1`) indicates that the
+    /// timestamp has no fractional seconds.
+    ///
+    /// Only timestamps with `Second` precision can have fractional seconds.
+    pub fn with_fractional_seconds(
+        mut self,
+        fractional_seconds: BigDecimal,
+    ) -> IonResult<Timestamp> {
+        if self.precision != Precision::Second {
+            return illegal_operation(format!(
+                "A timestamp with {:?} precision cannot have fractional seconds.",
+                self.precision
+            ));
+        }
+        if fractional_seconds.is_negative() || fractional_seconds >= BigDecimal::from(1) {
+            return illegal_operation(format!(
+                "Fractional seconds must be in the range [0, 1); found {}.",
+                fractional_seconds
+            ));
+        }
+        let (_, scale) = fractional_seconds.as_bigint_and_exponent();
+        if scale <= 0 {
+            // The only value in range with no digits after the decimal point is zero.
+            self.date_time = self.date_time.with_nanosecond(0).unwrap();
+            self.fractional_seconds = None;
+            return Ok(self);
+        }
+        // Digits beyond the ninth are retained in `fractional_seconds` but not in `date_time`.
+        let nanoseconds = (&fractional_seconds * BigDecimal::from(NANOSECONDS_PER_SECOND))
+            .with_scale(0)
+            .to_u32()
+            .unwrap();
+        self.date_time = self.date_time.with_nanosecond(nanoseconds).unwrap();
+        self.fractional_seconds = Some(fractional_seconds);
+        Ok(self)
+    }
+
+    /// Returns the timestamp's precision.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Returns the timestamp's offset from UTC in minutes, or None if the offset is unknown.
+    pub fn offset(&self) -> Option<i32> {
+        self.offset_minutes
+    }
+
+    /// Returns the year in the timestamp's local offset.
+    pub fn year(&self) -> u32 {
+        self.date_time.year() as u32
+    }
+
+    /// Returns the month in the timestamp's local offset, or 1 if the timestamp has `Year`
+    /// precision.
+    pub fn month(&self) -> u32 {
+        self.date_time.month()
+    }
+
+    /// Returns the day in the timestamp's local offset, or 1 if the timestamp's precision is
+    /// coarser than `Day`.
+    pub fn day(&self) -> u32 {
+        self.date_time.day()
+    }
+
+    /// Returns the hour in the timestamp's local offset, or 0 if the timestamp's precision is
+    /// coarser than `HourAndMinute`.
+    pub fn hour(&self) -> u32 {
+        self.date_time.hour()
+    }
+
+    /// Returns the minute in the timestamp's local offset, or 0 if the timestamp's precision is
+    /// coarser than `HourAndMinute`.
+    pub fn minute(&self) -> u32 {
+        self.date_time.minute()
+    }
+
+    /// Returns the second, or 0 if the timestamp's precision is coarser than `Second`.
+    pub fn second(&self) -> u32 {
+        self.date_time.second()
+    }
+
+    /// Returns the timestamp's fractional seconds exactly as they were specified, if any.
+    pub fn fractional_seconds(&self) -> Option<&BigDecimal> {
+        self.fractional_seconds.as_ref()
+    }
+
+    /// Returns the timestamp's fractional seconds as a number of nanoseconds. Any digits beyond
+    /// nanosecond precision are truncated.
+    pub fn nanoseconds(&self) -> u32 {
+        self.date_time.nanosecond()
+    }
+
+    /// Returns the timestamp's fields converted to UTC.
+    pub(crate) fn utc_date_time(&self) -> NaiveDateTime {
+        self.date_time - Duration::minutes(self.offset_minutes.unwrap_or(0) as i64)
+    }
+
+    /// Converts the timestamp to a DateTime. Any fields beyond the timestamp's precision are set
+    /// to their lowest legal value, an unknown offset is treated as UTC, and fractional seconds
+    /// beyond nanosecond precision are truncated.
+    pub fn to_datetime(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east(self.offset_minutes.unwrap_or(0) * 60);
+        offset.from_local_datetime(&self.date_time).unwrap()
+    }
+}
+
+/// Creates a Timestamp with `Second` precision and the DateTime's offset. If the DateTime has a
+/// non-zero nanoseconds field, the Timestamp will have as many digits of fractional seconds as
+/// are needed to represent it.
+impl From<DateTime<FixedOffset>> for Timestamp {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        let fractional_seconds = match value.nanosecond() {
+            0 => None,
+            nanoseconds => Some(BigDecimal::new(nanoseconds.into(), 9).normalized()),
+        };
+        Timestamp {
+            date_time: value.naive_local(),
+            offset_minutes: Some(value.offset().local_minus_utc() / 60),
+            precision: Precision::Second,
+            fractional_seconds,
+        }
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        // BigDecimal's PartialEq considers `0.5` and `0.50` to be equal; the Ion data model
+        // does not.
+        let fractions_match = match (&self.fractional_seconds, &other.fractional_seconds) {
+            (Some(a), Some(b)) => a.as_bigint_and_exponent() == b.as_bigint_and_exponent(),
+            (None, None) => true,
+            _ => false,
+        };
+        fractions_match
+            && self.precision == other.precision
+            && self.offset_minutes == other.offset_minutes
+            && self.date_time == other.date_time
+    }
+}
+
+impl Eq for Timestamp {}
+
+/// Formats the timestamp as Ion text.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year())?;
+        if self.precision == Precision::Year {
+            return write!(f, "T");
+        }
+        write!(f, "-{:02}", self.month())?;
+        if self.precision == Precision::Month {
+            return write!(f, "T");
+        }
+        write!(f, "-{:02}", self.day())?;
+        if self.precision == Precision::Day {
+            return Ok(());
+        }
+        write!(f, "T{:02}:{:02}", self.hour(), self.minute())?;
+        if self.precision == Precision::Second {
+            write!(f, ":{:02}", self.second())?;
+            if let Some(fractional_seconds) = &self.fractional_seconds {
+                let (coefficient, scale) = fractional_seconds.as_bigint_and_exponent();
+                let digits = if coefficient.is_zero() {
+                    String::new()
+                } else {
+                    coefficient.to_string()
+                };
+                write!(f, ".{:0>width$}", digits, width = scale as usize)?;
+            }
+        }
+        match self.offset_minutes {
+            None => write!(f, "-00:00"),
+            Some(0) => write!(f, "Z"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                write!(f, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    use crate::result::IonResult;
+    use crate::types::{Precision, Timestamp};
+
+    #[test]
+    fn display() -> IonResult<()> {
+        assert_eq!(Timestamp::with_year(2020)?.to_string(), "2020T");
+        assert_eq!(
+            Timestamp::with_year_and_month(2020, 6)?.to_string(),
+            "2020-06T"
+        );
+        assert_eq!(Timestamp::with_ymd(2020, 6, 15)?.to_string(), "2020-06-15");
+        assert_eq!(
+            Timestamp::with_ymd_hm(2020, 6, 15, 10, 30)?.to_string(),
+            "2020-06-15T10:30-00:00"
+        );
+        assert_eq!(
+            Timestamp::with_ymd_hm(2020, 6, 15, 10, 30)?
+                .with_offset(0)?
+                .to_string(),
+            "2020-06-15T10:30Z"
+        );
+        assert_eq!(
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_offset(-420)?
+                .to_string(),
+            "2020-06-15T10:30:45-07:00"
+        );
+        assert_eq!(
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_offset(90)?
+                .with_fractional_seconds(BigDecimal::from_str("0.00250").unwrap())?
+                .to_string(),
+            "2020-06-15T10:30:45.00250+01:30"
+        );
+        assert_eq!(
+            Timestamp::with_ymd_hms(2020, 6, 15, 10, 30, 45)?
+                .with_fractional_seconds(BigDecimal::from_str("0.000").unwrap())?
+                .to_string(),
+            "2020-06-15T10:30:45.000-00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn equality_respects_precision_offset_and_fraction_digits() -> IonResult<()> {
+        let year = Timestamp::with_year(2020)?;
+        let minute = Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?.with_offset(0)?;
+        assert_ne!(year, minute);
+        assert_eq!(year.to_datetime(), minute.to_datetime());
+
+        let unknown_offset = Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?;
+        assert_ne!(unknown_offset, minute);
+
+        let second = Timestamp::with_ymd_hms(2020, 1, 1, 0, 0, 0)?;
+        let half = second
+            .clone()
+            .with_fractional_seconds(BigDecimal::from_str("0.5").unwrap())?;
+        let half_with_more_digits =
+            second.with_fractional_seconds(BigDecimal::from_str("0.500").unwrap())?;
+        assert_ne!(half, half_with_more_digits);
+        assert_eq!(half.nanoseconds(), half_with_more_digits.nanoseconds());
+        assert_eq!(
+            half,
+            Timestamp::with_ymd_hms(2020, 1, 1, 0, 0, 0)?
+                .with_fractional_seconds(BigDecimal::new(5.into(), 1))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_seconds_beyond_nanoseconds_are_preserved() -> IonResult<()> {
+        let fraction = BigDecimal::from_str("0.123456789123").unwrap();
+        let timestamp = Timestamp::with_ymd_hms(2020, 1, 1, 0, 0, 0)?
+            .with_fractional_seconds(fraction.clone())?;
+        assert_eq!(timestamp.nanoseconds(), 123_456_789);
+        assert_eq!(timestamp.fractional_seconds(), Some(&fraction));
+        assert_eq!(
+            timestamp.to_string(),
+            "2020-01-01T00:00:00.123456789123-00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_timestamps() -> IonResult<()> {
+        assert!(Timestamp::with_year(0).is_err());
+        assert!(Timestamp::with_year(10_000).is_err());
+        assert!(Timestamp::with_year_and_month(2020, 13).is_err());
+        assert!(Timestamp::with_ymd(2021, 2, 29).is_err());
+        assert!(Timestamp::with_ymd_hm(2020, 1, 1, 24, 0).is_err());
+        assert!(Timestamp::with_ymd(2020, 1, 1)?.with_offset(0).is_err());
+        assert!(Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?
+            .with_offset(24 * 60)
+            .is_err());
+        let second = Timestamp::with_ymd_hms(2020, 1, 1, 0, 0, 0)?;
+        assert!(second
+            .clone()
+            .with_fractional_seconds(BigDecimal::from(1))
+            .is_err());
+        assert!(second
+            .with_fractional_seconds(BigDecimal::from_str("-0.5").unwrap())
+            .is_err());
+        assert!(Timestamp::with_ymd_hm(2020, 1, 1, 0, 0)?
+            .with_fractional_seconds(BigDecimal::from_str("0.5").unwrap())
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn from_datetime() -> IonResult<()> {
+        let naive = NaiveDate::from_ymd(2020, 6, 15).and_hms_nano(10, 30, 45, 250_000_000);
+        let datetime = FixedOffset::east(-7 * 3600)
+            .from_local_datetime(&naive)
+            .unwrap();
+        let timestamp = Timestamp::from(datetime);
+        assert_eq!(timestamp.precision(), Precision::Second);
+        assert_eq!(timestamp.offset(), Some(-420));
+        assert_eq!(timestamp.to_string(), "2020-06-15T10:30:45.25-07:00");
+        assert_eq!(timestamp.to_datetime(), datetime);
+        Ok(())
+    }
+
+    #[test]
+    fn utc_date_time() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd_hm(2020, 1, 1, 0, 30)?.with_offset(60)?;
+        assert_eq!(
+            timestamp.utc_date_time(),
+            NaiveDate::from_ymd(2019, 12, 31).and_hms(23, 30, 0)
+        );
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+use std::fmt;
+
 /// Represents the Ion data type of a given value. To learn more about each data type,
 /// read [the Ion Data Model](http://amzn.github.io/ion-docs/docs/spec.html#the-ion-data-model)
 /// section of the spec.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Copy, Clone, Hash)]
 pub enum IonType {
     Null,
     Boolean,
@@ -17,3 +19,26 @@ pub enum IonType {
     SExpression,
     Struct,
 }
+
+/// Writes the keyword used for the type in Ion text, as in `null.sexp`.
+impl fmt::Display for IonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use IonType::*;
+        let name = match self {
+            Null => "null",
+            Boolean => "bool",
+            Integer => "int",
+            Float => "float",
+            Decimal => "decimal",
+            Timestamp => "timestamp",
+            Symbol => "symbol",
+            String => "string",
+            Clob => "clob",
+            Blob => "blob",
+            List => "list",
+            SExpression => "sexp",
+            Struct => "struct",
+        };
+        f.pad(name)
+    }
+}
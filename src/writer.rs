@@ -0,0 +1,179 @@
+use std::io::Write;
+
+use crate::binary::writer::BinaryWriter;
+use crate::result::IonResult;
+use crate::text::writer::TextWriter;
+use crate::types::{Decimal, Integer, IonType, SymbolToken, Timestamp};
+
+/**
+ * This trait captures the format-agnostic functionality needed to write a stream of Ion values.
+ * It allows code like [Element::write_to](crate::Element::write_to) and
+ * [transcode](crate::transcode) to produce either text or binary Ion.
+ *
+ * Each method behaves like the inherent method of the same name on [TextWriter] and
+ * [BinaryWriter].
+ */
+pub trait Writer {
+    /// Sets the current field name to `name`. If the Writer is currently positioned inside
+    /// of a struct, the field name will be written before the next value. Otherwise, it will be
+    /// ignored.
+    fn set_field_name(&mut self, name: &str);
+
+    /// Like [set_field_name](Writer::set_field_name), but accepts a SymbolToken whose text may
+    /// be unknown. See [write_symbol_token](Writer::write_symbol_token).
+    fn set_field_name_token(&mut self, name: &SymbolToken);
+
+    /// Sets a list of annotations that will be applied to the next value that is written.
+    fn set_annotations(&mut self, annotations: &[&str]);
+
+    /// Like [set_annotations](Writer::set_annotations), but accepts SymbolTokens whose text may
+    /// be unknown. See [write_symbol_token](Writer::write_symbol_token).
+    fn set_annotation_tokens(&mut self, annotations: &[SymbolToken]);
+
+    /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
+    /// `step_in` will return an Err(IllegalOperation).
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()>;
+
+    /// Completes the current container. If the Writer is not currently positioned inside a
+    /// container, `step_out` will return an Err(IllegalOperation).
+    fn step_out(&mut self) -> IonResult<()>;
+
+    /// Writes an Ion null of the specified type.
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
+
+    /// Writes an Ion boolean with the specified value.
+    fn write_bool(&mut self, value: bool) -> IonResult<()>;
+
+    /// Writes an Ion integer with the specified value.
+    fn write_i64(&mut self, value: i64) -> IonResult<()>;
+
+    /// Writes the provided Integer value as an Ion integer.
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()>;
+
+    /// Writes the provided f32 value as an Ion float.
+    fn write_f32(&mut self, value: f32) -> IonResult<()>;
+
+    /// Writes the provided f64 value as an Ion float.
+    fn write_f64(&mut self, value: f64) -> IonResult<()>;
+
+    /// Writes the provided Decimal value as an Ion decimal, preserving its precision and the
+    /// sign of a zero coefficient.
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
+
+    /// Writes the provided Timestamp value as an Ion timestamp, preserving its precision,
+    /// fractional seconds, and offset.
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
+
+    /// Writes the provided &str value as an Ion symbol.
+    fn write_symbol(&mut self, value: &str) -> IonResult<()>;
+
+    /// Writes the provided SymbolToken as an Ion symbol. Tokens with known text are written like
+    /// [write_symbol](Writer::write_symbol). A TextWriter writes a token with unknown text as its
+    /// symbol ID (e.g. `$10`), while a BinaryWriter declares a symbol with unknown text for it in
+    /// the local symbol table. Either way, the symbol is read back with unknown text.
+    fn write_symbol_token(&mut self, value: &SymbolToken) -> IonResult<()>;
+
+    /// Writes the provided &str value as an Ion string.
+    fn write_string(&mut self, value: &str) -> IonResult<()>;
+
+    /// Writes the provided byte array slice as an Ion blob.
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()>;
+
+    /// Writes the provided byte array slice as an Ion clob.
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()>;
+
+    /// Writes any completed values to the underlying io::Write implementation. Writers also
+    /// flush when they are dropped, but only `flush` reports whether writing succeeded.
+    fn flush(&mut self) -> IonResult<()>;
+}
+
+// Implements Writer for a writer type by forwarding each method to the inherent method of the
+// same name. Inherent methods take precedence over trait methods, so these calls do not recurse.
+macro_rules! forward_to_inherent_methods {
+    ($writer:ident) => {
+        impl<W: Write> Writer for $writer<W> {
+            fn set_field_name(&mut self, name: &str) {
+                self.set_field_name(name)
+            }
+
+            fn set_field_name_token(&mut self, name: &SymbolToken) {
+                self.set_field_name_token(name)
+            }
+
+            fn set_annotations(&mut self, annotations: &[&str]) {
+                self.set_annotations(annotations)
+            }
+
+            fn set_annotation_tokens(&mut self, annotations: &[SymbolToken]) {
+                self.set_annotation_tokens(annotations)
+            }
+
+            fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+                self.step_in(ion_type)
+            }
+
+            fn step_out(&mut self) -> IonResult<()> {
+                self.step_out()
+            }
+
+            fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+                self.write_null(ion_type)
+            }
+
+            fn write_bool(&mut self, value: bool) -> IonResult<()> {
+                self.write_bool(value)
+            }
+
+            fn write_i64(&mut self, value: i64) -> IonResult<()> {
+                self.write_i64(value)
+            }
+
+            fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+                self.write_integer(value)
+            }
+
+            fn write_f32(&mut self, value: f32) -> IonResult<()> {
+                self.write_f32(value)
+            }
+
+            fn write_f64(&mut self, value: f64) -> IonResult<()> {
+                self.write_f64(value)
+            }
+
+            fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+                self.write_decimal(value)
+            }
+
+            fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+                self.write_timestamp(value)
+            }
+
+            fn write_symbol(&mut self, value: &str) -> IonResult<()> {
+                self.write_symbol(value)
+            }
+
+            fn write_symbol_token(&mut self, value: &SymbolToken) -> IonResult<()> {
+                self.write_symbol_token(value)
+            }
+
+            fn write_string(&mut self, value: &str) -> IonResult<()> {
+                self.write_string(value)
+            }
+
+            fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+                self.write_blob(value)
+            }
+
+            fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+                self.write_clob(value)
+            }
+
+            fn flush(&mut self) -> IonResult<()> {
+                self.flush()
+            }
+        }
+    };
+}
+
+forward_to_inherent_methods!(TextWriter);
+forward_to_inherent_methods!(BinaryWriter);
@@ -0,0 +1,49 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use ion_rs::result::{decoding_error, IonResult};
+
+mod ion_tests;
+
+use ion_tests::{all_files_in, read_file, skip_list_as_set};
+
+const BAD_TEST_FILES_PATH: &str = "ion-tests/iontestdata/bad/";
+
+const BAD_TEST_FILES_SKIP_LIST: &[&str] = &[];
+
+// Iterates over all of the Ion files in BAD_TEST_FILES_PATH and tries reading each in full.
+// If reading every file fails with an error, the test succeeds.
+#[test]
+fn read_bad_files() -> IonResult<()> {
+    let binary_file_extension: &OsStr = OsStr::new("10n");
+    let text_file_extension: &OsStr = OsStr::new("ion");
+    let bad_files = all_files_in(BAD_TEST_FILES_PATH);
+    let paths_to_skip = skip_list_as_set(BAD_TEST_FILES_SKIP_LIST);
+    let bad_files_to_read: Vec<_> = bad_files
+        .iter()
+        .filter(|f| {
+            f.extension() == Some(binary_file_extension)
+                || f.extension() == Some(text_file_extension)
+        })
+        .filter(|f| !paths_to_skip.contains(<&&PathBuf as AsRef<Path>>::as_ref(&f)))
+        .collect();
+    let mut failure_count: usize = 0;
+    println!();
+    for entry in &bad_files_to_read {
+        print!("Reading {}... ", entry.display());
+        if let Err(error) = read_file(entry.as_ref()) {
+            print!("OK: {:?}", error);
+        } else {
+            print!("ERROR: the file was read successfully");
+            failure_count += 1;
+        }
+        println!();
+    }
+    if failure_count > 0 {
+        return decoding_error(format!(
+            "{} bad test files were read without an error.",
+            failure_count
+        ));
+    }
+    Ok(())
+}
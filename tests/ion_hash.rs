@@ -0,0 +1,220 @@
+#![cfg(feature = "ion-hash")]
+
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::rc::Rc;
+
+use md5::{Digest, Md5};
+
+use ion_rs::element::{load_all, Element};
+use ion_rs::ion_hash::{hash_value_with, IonHasher, Sha256Hasher};
+use ion_rs::result::{decoding_error, IonResult};
+use ion_rs::{BinaryIonCursor, Cursor, Reader, TextIonCursor};
+
+const ION_HASH_TESTS_PATH: &str = "ion-hash-test/ion_hash_tests.ion";
+
+const IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
+// Returns the bytes it was given as its digest.
+#[derive(Default)]
+struct IdentityHasher(Vec<u8>);
+
+impl IonHasher for IdentityHasher {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[derive(Default)]
+struct Md5Hasher(Md5);
+
+impl IonHasher for Md5Hasher {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+// Every update and digest made while hashing a test case's input, across all of the IonHashers
+// that were created to hash it.
+#[derive(Debug, Default, PartialEq)]
+struct Trace {
+    updates: Vec<u8>,
+    digests: Vec<Vec<u8>>,
+}
+
+// Wraps an IonHasher, adding each of its updates and digests to a shared Trace.
+struct TracingHasher<H> {
+    hasher: H,
+    trace: Rc<RefCell<Trace>>,
+}
+
+impl<H: IonHasher> IonHasher for TracingHasher<H> {
+    fn update(&mut self, bytes: &[u8]) {
+        self.trace.borrow_mut().updates.extend_from_slice(bytes);
+        self.hasher.update(bytes);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let digest = self.hasher.finish();
+        self.trace.borrow_mut().digests.push(digest.clone());
+        digest
+    }
+}
+
+// Reads a test case's `10n` field, an s-expression of the bytes in a binary Ion stream.
+fn read_bytes<C: Cursor>(reader: &mut Reader<C>) -> IonResult<Vec<u8>> {
+    let mut bytes = vec![];
+    reader.step_in()?;
+    while reader.next()?.is_some() {
+        match reader.read_i64()? {
+            Some(byte) if (0..=0xFF).contains(&byte) => bytes.push(byte as u8),
+            _ => return decoding_error("Expected an s-expression of bytes."),
+        }
+    }
+    reader.step_out()?;
+    Ok(bytes)
+}
+
+// Hashes the input of each test case in the file using the IonHashers returned by `new_hasher`,
+// returning a Trace for each one. Text inputs are hashed straight from the test file's Reader.
+fn hash_inputs<H, F>(test_file: &[u8], new_hasher: F) -> IonResult<Vec<Trace>>
+where
+    H: IonHasher,
+    F: Fn() -> H,
+{
+    let trace = Rc::new(RefCell::new(Trace::default()));
+    let new_tracing_hasher = || TracingHasher {
+        hasher: new_hasher(),
+        trace: Rc::clone(&trace),
+    };
+    let mut traces = vec![];
+    let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(test_file)));
+    while reader.next()?.is_some() {
+        reader.step_in()?;
+        while reader.next()?.is_some() {
+            match reader.field_name() {
+                Some("ion") => {
+                    hash_value_with(&mut reader, new_tracing_hasher)?;
+                }
+                Some("10n") => {
+                    let mut bytes = read_bytes(&mut reader)?;
+                    if !bytes.starts_with(&IVM) {
+                        bytes.splice(0..0, IVM.iter().copied());
+                    }
+                    let mut binary_reader =
+                        Reader::new(BinaryIonCursor::new(io::Cursor::new(bytes)));
+                    binary_reader.next()?;
+                    hash_value_with(&mut binary_reader, new_tracing_hasher)?;
+                }
+                _ => {}
+            }
+        }
+        reader.step_out()?;
+        traces.push(trace.replace(Trace::default()));
+    }
+    Ok(traces)
+}
+
+// Returns the Trace that a test case's expectations for one hash function describe. Each
+// expectation is an s-expression of bytes annotated with `update`, `digest`, or `final_digest`.
+fn expected_trace(expectations: &Element) -> IonResult<Trace> {
+    let mut trace = Trace::default();
+    let expectations = match expectations.as_sequence() {
+        Some(expectations) => expectations,
+        None => return decoding_error("Expected an s-expression of expectations."),
+    };
+    for expectation in expectations {
+        let mut bytes = vec![];
+        for byte in expectation.as_sequence().unwrap_or(&[]) {
+            match byte.as_i64() {
+                Some(byte) if (0..=0xFF).contains(&byte) => bytes.push(byte as u8),
+                _ => return decoding_error("Expected an s-expression of bytes."),
+            }
+        }
+        let kind = expectation.annotations().first().and_then(|a| a.text());
+        match kind {
+            Some("update") => trace.updates.extend(bytes),
+            // The final digest is the last one computed, so it is checked like any other.
+            Some("digest") | Some("final_digest") => trace.digests.push(bytes),
+            _ => return decoding_error(format!("Unknown expectation kind: {:?}", kind)),
+        }
+    }
+    Ok(trace)
+}
+
+// Returns the name of the test case, which is either its annotation or its input.
+fn test_name(test_case: &Element) -> String {
+    if let Some(name) = test_case.annotations().first().and_then(|a| a.text()) {
+        return name.to_string();
+    }
+    let test_case = test_case.as_struct();
+    let input = test_case.and_then(|t| t.get("ion").or_else(|| t.get("10n")));
+    format!("{:?}", input)
+}
+
+// Hashes the input of every test case in ION_HASH_TESTS_PATH using each of the hash functions
+// that the test suite has expectations for. The identity hash function, whose digests are the
+// bytes that were hashed, shows whether values were serialized as the specification describes.
+// For identity, every update and every digest (including those of struct fields) must match the
+// expectations. For md5 and sha256, the final digest must match.
+#[test]
+fn ion_hash_tests() -> IonResult<()> {
+    let test_file = fs::read(ION_HASH_TESTS_PATH)
+        .unwrap_or_else(|error| panic!("Failed to read {}: {:?}", ION_HASH_TESTS_PATH, error));
+    let mut reader = Reader::new(TextIonCursor::new(io::Cursor::new(test_file.as_slice())));
+    let test_cases = load_all(&mut reader)?;
+    let identity_traces = hash_inputs(&test_file, IdentityHasher::default)?;
+    let md5_traces = hash_inputs(&test_file, Md5Hasher::default)?;
+    let sha256_traces = hash_inputs(&test_file, Sha256Hasher::default)?;
+
+    let mut failure_count: usize = 0;
+    println!();
+    for (index, test_case) in test_cases.iter().enumerate() {
+        let expect = test_case
+            .as_struct()
+            .and_then(|t| t.get("expect"))
+            .and_then(|e| e.as_struct());
+        let expect = match expect {
+            Some(expect) => expect,
+            None => return decoding_error("Found a test case without expectations."),
+        };
+        for (algorithm, expectations) in expect.iter() {
+            let algorithm = algorithm.text().unwrap_or("");
+            let expected = expected_trace(expectations)?;
+            let passed = match algorithm {
+                "identity" => {
+                    let actual = &identity_traces[index];
+                    (expected.updates.is_empty() || expected.updates == actual.updates)
+                        && expected.digests == actual.digests
+                }
+                "md5" => expected.digests.last() == md5_traces[index].digests.last(),
+                "sha256" => expected.digests.last() == sha256_traces[index].digests.last(),
+                _ => continue,
+            };
+            print!("{} ({})... ", test_name(test_case), algorithm);
+            if passed {
+                print!("OK");
+            } else {
+                print!("FAILED");
+                failure_count += 1;
+            }
+            println!();
+        }
+    }
+    if failure_count > 0 {
+        return decoding_error(format!(
+            "{} Ion Hash test expectations were not met.",
+            failure_count
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::BufReader;
+use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use walkdir::WalkDir;
+
+use ion_rs::element::load_all;
+use ion_rs::result::IonResult;
+use ion_rs::{BinaryIonCursor, Reader, TextIonCursor};
+
+// Converts the provided slice of strings to a HashSet of paths
+pub fn skip_list_as_set(files_to_skip: &[&str]) -> BTreeSet<PathBuf> {
+    let mut skip_set = BTreeSet::new();
+    for file in files_to_skip {
+        skip_set.insert(PathBuf::from_str(file).unwrap());
+    }
+    skip_set
+}
+
+// Collects all of the files in the provided path into a BTreeSet for easy iteration/filtering.
+pub fn all_files_in(path: &str) -> BTreeSet<PathBuf> {
+    let binary_file_iterator = WalkDir::new(path)
+        .into_iter()
+        .map(|entry| {
+            entry.unwrap_or_else(|error| panic!("Failure during dir traversal: {:?}", error))
+        })
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.path().to_owned());
+    BTreeSet::from_iter(binary_file_iterator)
+}
+
+// Reads all of the Ion values found in the provided file, reporting any errors.
+pub fn read_file(path: &Path) -> IonResult<()> {
+    let file = File::open(path).unwrap_or_else(|error| panic!("Failed to open file: {:?}", error));
+    let file_reader = BufReader::new(file);
+    if path.extension() == Some(OsStr::new("ion")) {
+        let mut reader = Reader::new(TextIonCursor::new(file_reader));
+        let _elements = load_all(&mut reader)?;
+    } else {
+        let mut reader = Reader::new(BinaryIonCursor::new(file_reader));
+        let _elements = load_all(&mut reader)?;
+    }
+    Ok(())
+}